@@ -1,4 +1,4 @@
-use super::{ECGroup, Ford, Fq};
+use super::{precomp, ECGroup, Ford, Fq, Secp, SecpOrd};
 use crypto::digest::Digest;
 use crypto::sha2::Sha256;
 ///
@@ -6,6 +6,7 @@ use crypto::sha2::Sha256;
 ///
 /// aas, neucrypt
 use rand::Rng;
+use std::collections::HashMap;
 
 fn hash<'a>(msg: &[u8], res: &mut [u8; 32]) {
     let mut hasher = Sha256::new();
@@ -13,6 +14,230 @@ fn hash<'a>(msg: &[u8], res: &mut [u8; 32]) {
     hasher.result(&mut res[0..32]);
 }
 
+/// an ecdsa signature, along with the recovery id that identifies R among the points with x coordinate r:
+/// bit 0 is the parity of R.y, and bit 1 is set if R.x was reduced by the group order
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature<T: Ford> {
+    pub r: T,
+    pub s: T,
+    pub recid: u8,
+}
+
+impl<T: Ford> Signature<T> {
+    pub fn is_low_s(&self) -> bool {
+        is_low_s(&self.s)
+    }
+
+    /// returns the equivalent signature with s in the lower half of the group order.
+    /// negating s negates R, so the parity bit of the recovery id flips with it
+    pub fn normalize_s(&self) -> Signature<T> {
+        if self.is_low_s() {
+            *self
+        } else {
+            Signature {
+                r: self.r,
+                s: self.s.neg(),
+                recid: self.recid ^ 1,
+            }
+        }
+    }
+
+    /// strict DER encoding, as in BIP-66
+    pub fn to_der(&self) -> Vec<u8> {
+        let mut der = Vec::with_capacity(72);
+        der.push(0x30);
+        der.push(0);
+        der_write_integer(&self.r, &mut der);
+        der_write_integer(&self.s, &mut der);
+        der[1] = (der.len() - 2) as u8;
+        der
+    }
+
+    /// decodes a strict DER signature. the recovery id is not encoded, so it is set to 0
+    pub fn from_der(der: &[u8]) -> Result<Signature<T>, &'static str> {
+        if der.len() < 8 || der[0] != 0x30 || der[1] as usize != der.len() - 2 {
+            return Err("DER signature has an invalid sequence header");
+        }
+        let (r, rest) = der_read_integer(&der[2..])?;
+        let (s, rest) = der_read_integer(rest)?;
+        if rest.len() > 0 {
+            return Err("DER signature has trailing data");
+        }
+        Ok(Signature { r, s, recid: 0 })
+    }
+
+    /// 64-byte r||s encoding
+    pub fn to_compact(&self) -> [u8; 64] {
+        let mut b = [0; 64];
+        self.r.to_bytes(&mut b[0..32]);
+        self.s.to_bytes(&mut b[32..64]);
+        b
+    }
+
+    /// decodes a 64-byte r||s signature. the recovery id is not encoded, so it is set to 0
+    pub fn from_compact(b: &[u8]) -> Result<Signature<T>, &'static str> {
+        if b.len() != 64 {
+            return Err("Compact signature must be 64 bytes");
+        }
+        Ok(Signature {
+            r: scalar_from_bytes(&b[0..32])?,
+            s: scalar_from_bytes(&b[32..64])?,
+            recid: 0,
+        })
+    }
+
+    /// 65-byte r||s||v encoding, where v is the recovery id (0-3). note that ethereum's legacy
+    /// transaction format expects v + 27 instead
+    pub fn to_compact_recoverable(&self) -> [u8; 65] {
+        let mut b = [0; 65];
+        b[0..64].copy_from_slice(&self.to_compact());
+        b[64] = self.recid;
+        b
+    }
+
+    pub fn from_compact_recoverable(b: &[u8]) -> Result<Signature<T>, &'static str> {
+        if b.len() != 65 {
+            return Err("Recoverable compact signature must be 65 bytes");
+        }
+        if b[64] > 3 {
+            return Err("Recovery id must be between 0 and 3");
+        }
+        let mut sig = Signature::from_compact(&b[0..64])?;
+        sig.recid = b[64];
+        Ok(sig)
+    }
+}
+
+// parses a big-endian scalar, which must be nonzero and less than the group order
+fn scalar_from_bytes<T: Ford>(b: &[u8]) -> Result<T, &'static str> {
+    let x = T::from_bytes(b);
+    let mut xb = [0; 32];
+    x.to_bytes(&mut xb);
+    if x.is_zero() || xb[..] != b[..] {
+        return Err("Signature scalar is zero or not less than the group order");
+    }
+    Ok(x)
+}
+
+fn der_write_integer<T: Ford>(x: &T, der: &mut Vec<u8>) {
+    let mut xb = [0; 32];
+    x.to_bytes(&mut xb);
+    let start = xb.iter().position(|&v| v != 0).unwrap_or(31);
+    der.push(0x02);
+    // a leading zero byte keeps the integer positive
+    if xb[start] & 0x80 != 0 {
+        der.push((33 - start) as u8);
+        der.push(0);
+    } else {
+        der.push((32 - start) as u8);
+    }
+    der.extend_from_slice(&xb[start..]);
+}
+
+// reads one DER integer, returning it along with the remaining input
+fn der_read_integer<T: Ford>(der: &[u8]) -> Result<(T, &[u8]), &'static str> {
+    if der.len() < 2 || der[0] != 0x02 {
+        return Err("DER signature is missing an integer");
+    }
+    let len = der[1] as usize;
+    if len == 0 || len > 33 || der.len() < 2 + len {
+        return Err("DER signature has an invalid integer length");
+    }
+    let int = &der[2..(2 + len)];
+    if int[0] & 0x80 != 0 {
+        return Err("DER signature has a negative integer");
+    }
+    if len > 1 && int[0] == 0 && int[1] & 0x80 == 0 {
+        return Err("DER signature has an integer with excess padding");
+    }
+    let int = if len > 1 && int[0] == 0 {
+        &int[1..]
+    } else {
+        int
+    };
+    if int.len() > 32 {
+        return Err("DER signature has an integer that is too large");
+    }
+    let mut xb = [0; 32];
+    xb[(32 - int.len())..].copy_from_slice(int);
+    Ok((scalar_from_bytes(&xb)?, &der[(2 + len)..]))
+}
+
+/// checks that s <= order/2, as required by BIP-62 and BIP-146
+pub fn is_low_s<T: Ford>(s: &T) -> bool {
+    let mut sb = [0; 32];
+    s.to_bytes(&mut sb);
+    let mut nsb = [0; 32];
+    s.neg().to_bytes(&mut nsb);
+    sb <= nsb
+}
+
+/// computes the recovery id for a signature with instance point R
+pub fn recovery_id<F: Fq, T: Ford, E: ECGroup<F, T>>(rpt: &E) -> u8 {
+    let rpt = rpt.affine();
+    let mut xb = [0; 32];
+    rpt.x().to_bytes(&mut xb);
+    let mut rb = [0; 32];
+    T::from_bytes(&xb).to_bytes(&mut rb);
+    (rpt.y().bit(0) as u8) | (((rb != xb) as u8) << 1)
+}
+
+/// recovers the public key under which sig is valid for msg, if there is one
+pub fn recover_public_key<F: Fq, T: Ford, E: ECGroup<F, T>>(
+    msg: &[u8],
+    sig: &Signature<T>,
+) -> Option<E> {
+    let mut z = [0; 32];
+    hash(msg, &mut z);
+    recover_public_key_prehashed(&z, sig)
+}
+
+/// as recover_public_key, for a message that the caller has already hashed
+pub fn recover_public_key_prehashed<F: Fq, T: Ford, E: ECGroup<F, T>>(
+    digest: &[u8; 32],
+    sig: &Signature<T>,
+) -> Option<E> {
+    if sig.s.is_zero() {
+        return None;
+    }
+    let rpt = instance_point::<F, T, E>(sig)?;
+    let z = T::from_bytes(&digest[..]);
+
+    // pk = r^{-1} (s*R - z*G)
+    let pk = E::op(&rpt.scalar(&sig.s), &E::scalar_gen(&z).neg())
+        .scalar(&sig.r.inv())
+        .affine();
+    if pk.is_infinity() {
+        None
+    } else {
+        Some(pk)
+    }
+}
+
+// the instance point R identified by the signature's r and recovery id, if there is one
+fn instance_point<F: Fq, T: Ford, E: ECGroup<F, T>>(sig: &Signature<T>) -> Option<E> {
+    if sig.r.is_zero() || sig.recid > 3 {
+        return None;
+    }
+
+    // R.x = r, or r + order if it overflowed
+    let mut xb = [0; 32];
+    sig.r.to_bytes(&mut xb);
+    let mut x = F::from_bytes(&xb);
+    if sig.recid & 2 != 0 {
+        let mut nb = [0; 32];
+        T::ZERO.sub(&T::ONE).to_bytes(&mut nb);
+        x = x.add(&F::from_bytes(&nb)).add(&F::ONE);
+        x.normalize();
+        // r + order must not wrap around the field modulus
+        x.to_bytes(&mut xb);
+        if xb <= nb {
+            return None;
+        }
+    }
+    E::from_x(&x, sig.recid & 1 != 0).ok()
+}
+
 pub fn ecdsa_keygen<F: Fq, T: Ford, E: ECGroup<F, T>>(rng: &mut dyn Rng) -> (T, E) {
     let (sk, pk): (T, E) = E::rand(rng);
     debug_assert!(E::gen().scalar(&sk).affine() == pk);
@@ -25,58 +250,260 @@ pub fn ecdsa_verify_with_tables<F: Fq, T: Ford, E: ECGroup<F, T>>(
     gentable: &[E],
     pktable: &[E],
 ) -> bool {
+    let mut z = [0; 32];
+    hash(msg, &mut z);
+    ecdsa_verify_prehashed_with_tables(&z, sig, gentable, pktable)
+}
+
+/// verifies a signature over a 32-byte digest computed by the caller, for instance with keccak-256
+/// or double sha-256, rather than over the sha-256 hash of a message
+pub fn ecdsa_verify_prehashed_with_tables<F: Fq, T: Ford, E: ECGroup<F, T>>(
+    digest: &[u8; 32],
+    sig: (&T, &T),
+    gentable: &[E],
+    pktable: &[E],
+) -> bool {
+    match verify_scalars(digest, sig) {
+        Some((u1, u2)) => {
+            let p = E::op(
+                &E::scalar_table_multi(pktable, &u2),
+                &E::scalar_table_multi(gentable, &u1),
+            );
+            verify_point(&p, sig.0)
+        }
+        None => false,
+    }
+}
+
+// r,s are in range [1,order-1]
+// z = digest
+// w = s^{-1}
+// u1 = zw mod n
+// u2 = rw mod n
+fn verify_scalars<T: Ford>(digest: &[u8; 32], sig: (&T, &T)) -> Option<(T, T)> {
     let r = sig.0;
     let s = sig.1;
 
     if r.is_zero() || s.is_zero() {
-        return false;
+        return None;
     }
 
-    // r,s are in range [1,order-1]
-    // z = hash(msg)
-    let mut z = [0; 32];
-    hash(msg, &mut z);
-    let z = T::from_bytes(&z[0..32]);
-
-    // w = s^{-1}
-    // u1 = zw mod n
-    // u2 = rw mod n
+    let z = T::from_bytes(&digest[..]);
     let w = s.inv();
-    let u1 = z.mul(&w);
-    let u2 = r.mul(&w);
-
-    // p = g^u1 * pk^u2
-    // check p.x == r
-    let p = E::op(
-        &E::scalar_table_multi(pktable, &u2),
-        &E::scalar_table_multi(gentable, &u1),
-    )
-    .affine();
+    Some((z.mul(&w), r.mul(&w)))
+}
+
+// p = g^u1 * pk^u2
+// check p.x == r
+fn verify_point<F: Fq, T: Ford, E: ECGroup<F, T>>(p: &E, r: &T) -> bool {
+    let p = p.affine();
+    if p.is_infinity() {
+        return false;
+    }
     let mut pb = [0; 32];
     p.x().to_bytes(&mut pb);
     let px = T::from_bytes(&pb[..]);
-    if px == *r {
-        return true;
-    }
-
-    return false;
+    px == *r
 }
 
 pub fn ecdsa_verify<F: Fq, T: Ford, E: ECGroup<F, T>>(msg: &[u8], sig: (&T, &T), pk: &E) -> bool {
+    let mut z = [0; 32];
+    hash(msg, &mut z);
+    ecdsa_verify_prehashed(&z, sig, pk)
+}
+
+pub fn ecdsa_verify_prehashed<F: Fq, T: Ford, E: ECGroup<F, T>>(
+    digest: &[u8; 32],
+    sig: (&T, &T),
+    pk: &E,
+) -> bool {
     if pk.is_infinity() {
         return false;
     }
     // pk lies on curve
     // pk has order n
-    let pktable = E::precomp_table(pk);
-    let gentable = E::precomp_table(&E::gen());
-    ecdsa_verify_with_tables(msg, sig, &gentable[..], &pktable[..])
+    // without tables, one multi-scalar multiplication is cheaper than building two of them
+    match verify_scalars(digest, sig) {
+        Some((u1, u2)) => verify_point(
+            &E::multi_scalar_mul(&[E::gen(), pk.affine()], &[u1, u2]),
+            sig.0,
+        ),
+        None => false,
+    }
+}
+
+/// as ecdsa_verify, but also rejects signatures that are not in low-s form
+pub fn ecdsa_verify_strict<F: Fq, T: Ford, E: ECGroup<F, T>>(
+    msg: &[u8],
+    sig: (&T, &T),
+    pk: &E,
+) -> bool {
+    is_low_s(sig.1) && ecdsa_verify(msg, sig, pk)
+}
+
+/// verifies a batch of (message, signature, public key) entries, and on failure reports the
+/// indices of the entries that are invalid. see batch_verify_with_table
+pub fn batch_verify(entries: &[(&[u8], &Signature<SecpOrd>, &Secp)]) -> Result<(), Vec<usize>> {
+    batch_verify_with_table(entries, &precomp::P256_TABLE[..])
+}
+
+/// as batch_verify, with a table for the generator from precomp_table.
+///
+/// Entries whose recovery id identifies their instance point R are first checked together:
+/// a valid signature satisfies u1*G + u2*pk - R = 0, and a random linear combination of these
+/// equations is checked with a single multi-scalar multiplication. The coefficients are derived
+/// by hashing the whole batch. If that check fails, the batch is split in half and each half
+/// is checked again, down to single entries. Entries with an unusable recovery id (such as
+/// signatures decoded from DER) and entries that fail on their own are verified individually,
+/// with one table per distinct public key.
+pub fn batch_verify_with_table<F: Fq, T: Ford, E: ECGroup<F, T>>(
+    entries: &[(&[u8], &Signature<T>, &E)],
+    gentable: &[E],
+) -> Result<(), Vec<usize>> {
+    let mut digests = Vec::with_capacity(entries.len());
+    let mut seed = Sha256::new();
+    for (msg, sig, pk) in entries.iter() {
+        let mut z = [0; 32];
+        hash(msg, &mut z);
+        let mut b = [0; 32];
+        seed.input(&z);
+        sig.r.to_bytes(&mut b);
+        seed.input(&b);
+        sig.s.to_bytes(&mut b);
+        seed.input(&b);
+        // the recovery id selects the instance point, so it must bind the coefficients too
+        seed.input(&[sig.recid]);
+        seed.input(&point_key(*pk));
+        digests.push(z);
+    }
+    let mut seedb = [0; 32];
+    seed.result(&mut seedb);
+
+    let mut batched = Vec::with_capacity(entries.len());
+    let mut unbatched = Vec::new();
+    for (ii, (_, sig, pk)) in entries.iter().enumerate() {
+        let rpt = instance_point::<F, T, E>(sig);
+        match (verify_scalars(&digests[ii], (&sig.r, &sig.s)), rpt) {
+            (Some((u1, u2)), Some(rpt)) if !pk.is_infinity() => {
+                let mut ab = [0; 32];
+                let mut hasher = Sha256::new();
+                hasher.input(&seedb);
+                hasher.input(&(ii as u64).to_le_bytes());
+                hasher.result(&mut ab);
+                batched.push(BatchEntry {
+                    index: ii,
+                    a: T::from_bytes(&ab),
+                    u1,
+                    u2,
+                    pk: pk.affine(),
+                    rneg: rpt.neg(),
+                });
+            }
+            _ => unbatched.push(ii),
+        }
+    }
+    batch_bisect(&batched[..], gentable, &mut unbatched);
+    unbatched.sort();
+
+    let mut tables: HashMap<Vec<u8>, Vec<E>> = HashMap::new();
+    let mut failed = Vec::new();
+    for ii in unbatched {
+        let (_, sig, pk) = entries[ii];
+        let valid = !pk.is_infinity() && {
+            let pktable = tables
+                .entry(point_key(pk))
+                .or_insert_with(|| E::precomp_table(pk));
+            ecdsa_verify_prehashed_with_tables(&digests[ii], (&sig.r, &sig.s), gentable, pktable)
+        };
+        if !valid {
+            failed.push(ii);
+        }
+    }
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(failed)
+    }
+}
+
+struct BatchEntry<T, E> {
+    index: usize,
+    a: T,
+    u1: T,
+    u2: T,
+    pk: E,
+    rneg: E,
+}
+
+// checks the batch as a whole, and then each half of any batch that fails. a single entry
+// that fails is left to be verified on its own
+fn batch_bisect<F: Fq, T: Ford, E: ECGroup<F, T>>(
+    batch: &[BatchEntry<T, E>],
+    gentable: &[E],
+    unbatched: &mut Vec<usize>,
+) {
+    if batch.len() == 0 || batch_holds(batch, gentable) {
+        return;
+    }
+    if batch.len() == 1 {
+        unbatched.push(batch[0].index);
+        return;
+    }
+    let (lo, hi) = batch.split_at(batch.len() / 2);
+    batch_bisect(lo, gentable, unbatched);
+    batch_bisect(hi, gentable, unbatched);
+}
+
+// checks that sum(a*u1)*G + sum(a*u2*pk) + sum(a*(-R)) is the point at infinity
+fn batch_holds<F: Fq, T: Ford, E: ECGroup<F, T>>(
+    batch: &[BatchEntry<T, E>],
+    gentable: &[E],
+) -> bool {
+    let mut gscalar = T::ZERO;
+    let mut keys: HashMap<Vec<u8>, usize> = HashMap::new();
+    let mut points = Vec::with_capacity(2 * batch.len());
+    let mut scalars = Vec::with_capacity(2 * batch.len());
+    for entry in batch.iter() {
+        gscalar = gscalar.add(&entry.a.mul(&entry.u1));
+        points.push(entry.rneg.affine());
+        scalars.push(entry.a);
+        // coefficients for the same key are summed, so that each key is multiplied only once
+        let k = *keys.entry(point_key(&entry.pk)).or_insert_with(|| {
+            points.push(entry.pk.affine());
+            scalars.push(T::ZERO);
+            points.len() - 1
+        });
+        scalars[k] = scalars[k].add(&entry.a.mul(&entry.u2));
+    }
+    E::op(
+        &E::scalar_table_multi(gentable, &gscalar),
+        &E::multi_scalar_mul(&points[..], &scalars[..]),
+    )
+    .affine()
+    .is_infinity()
+}
+
+fn point_key<F: Fq, T: Ford, E: ECGroup<F, T>>(p: &E) -> Vec<u8> {
+    let mut b = vec![0; E::NBYTES];
+    p.affine().to_bytes(&mut b);
+    b
 }
 
 pub fn ecdsa_sign<F: Fq, T: Ford, E: ECGroup<F, T>>(
     msg: &[u8],
     sk: &T,
     rng: &mut dyn Rng,
+) -> (T, T) {
+    let mut z = [0; 32];
+    hash(msg, &mut z);
+    ecdsa_sign_prehashed::<F, T, E>(&z, sk, rng)
+}
+
+pub fn ecdsa_sign_prehashed<F: Fq, T: Ford, E: ECGroup<F, T>>(
+    digest: &[u8; 32],
+    sk: &T,
+    rng: &mut dyn Rng,
 ) -> (T, T) {
     // Calculate e = HASH ( m )
     // Let z be the L_{n} leftmost bits of e where L_{n} is the bit len of the grp order n.
@@ -95,9 +522,7 @@ pub fn ecdsa_sign<F: Fq, T: Ford, E: ECGroup<F, T>>(
 
     let kinv = k.inv();
 
-    let mut z = [0; 32];
-    hash(msg, &mut z);
-    let z = T::from_bytes(&z[0..32]);
+    let z = T::from_bytes(&digest[..]);
 
     // s = k^{-1} * ( z + r * sk ) mod order
     let s = kinv.mul(&z.add(&r.mul(&sk)));
@@ -140,10 +565,228 @@ mod tests {
         assert!(ecdsa_verify(&"other msg".as_bytes(), (&rx, &s), &pk) == false);
     }
 
+    #[test]
+    fn test_low_s() {
+        let mut rng = rand::os::OsRng::new().unwrap();
+        let (sk, pk): (FSecp256Ord, P256<FSecp256, FSecp256Ord>) = ecdsa_keygen(&mut rng);
+        let msg = &"this is a random test message that is long".as_bytes();
+
+        for _ in 0..8 {
+            let (r, s) = ecdsa_sign::<FSecp256, FSecp256Ord, P256<FSecp256, FSecp256Ord>>(
+                msg, &sk, &mut rng,
+            );
+            let sig = (0..4)
+                .map(|recid| Signature { r, s, recid })
+                .find(|sig| recover_public_key(msg, sig) == Some(pk))
+                .unwrap();
+
+            // both forms verify, but only the low one is accepted in strict mode
+            let low = sig.normalize_s();
+            let high = Signature {
+                r,
+                s: low.s.neg(),
+                recid: low.recid ^ 1,
+            };
+            assert!(low.is_low_s() && !high.is_low_s());
+            assert_eq!(high.normalize_s(), low);
+            assert!(ecdsa_verify(msg, (&high.r, &high.s), &pk));
+            assert!(ecdsa_verify_strict(msg, (&low.r, &low.s), &pk));
+            assert!(!ecdsa_verify_strict(msg, (&high.r, &high.s), &pk));
+            assert_eq!(recover_public_key(msg, &low), Some(pk));
+        }
+    }
+
+    #[test]
+    fn test_signature_encodings() {
+        let mut rng = rand::os::OsRng::new().unwrap();
+        let (sk, _): (FSecp256Ord, P256<FSecp256, FSecp256Ord>) = ecdsa_keygen(&mut rng);
+        let msg = &"this is a random test message that is long".as_bytes();
+
+        for _ in 0..16 {
+            let (r, s) = ecdsa_sign::<FSecp256, FSecp256Ord, P256<FSecp256, FSecp256Ord>>(
+                msg, &sk, &mut rng,
+            );
+            let sig = Signature { r, s, recid: 0 };
+            let der = sig.to_der();
+            assert!(der.len() <= 72);
+            assert_eq!(Signature::from_der(&der), Ok(sig));
+            assert_eq!(Signature::from_compact(&sig.to_compact()[..]), Ok(sig));
+
+            let sig = Signature { recid: 3, ..sig };
+            assert_eq!(
+                Signature::from_compact_recoverable(&sig.to_compact_recoverable()[..]),
+                Ok(sig)
+            );
+        }
+
+        // small values need no padding, and values with the high bit set need one zero byte
+        let sig = Signature {
+            r: FSecp256Ord::from_native(1),
+            s: FSecp256Ord::from_native(0x80),
+            recid: 0,
+        };
+        let der = sig.to_der();
+        assert_eq!(
+            der,
+            vec![0x30, 0x07, 0x02, 0x01, 0x01, 0x02, 0x02, 0x00, 0x80]
+        );
+        assert_eq!(Signature::from_der(&der), Ok(sig));
+    }
+
+    #[test]
+    fn test_signature_decoding_rejects_malformed() {
+        let sig = Signature {
+            r: FSecp256Ord::from_native(1),
+            s: FSecp256Ord::from_native(0x80),
+            recid: 0,
+        };
+        let der = sig.to_der();
+        let reject = |der: &[u8]| Signature::<FSecp256Ord>::from_der(der).is_err();
+
+        let mut bad = der.clone();
+        bad.push(0);
+        assert!(reject(&bad)); // trailing data
+        bad[1] += 1;
+        assert!(reject(&bad)); // trailing data covered by the header
+        assert!(reject(&der[..(der.len() - 1)])); // truncated
+        assert!(reject(&[
+            0x30, 0x07, 0x02, 0x01, 0x01, 0x02, 0x02, 0x00, 0x00
+        ])); // zero s
+        assert!(reject(&[
+            0x30, 0x07, 0x02, 0x02, 0x00, 0x01, 0x02, 0x01, 0x01
+        ])); // padded r
+        assert!(reject(&[0x30, 0x06, 0x02, 0x01, 0x81, 0x02, 0x01, 0x01])); // negative r
+        assert!(reject(&[0x31, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01])); // not a sequence
+        assert!(reject(&[0x30, 0x06, 0x03, 0x01, 0x01, 0x02, 0x01, 0x01])); // not an integer
+
+        // r equal to the group order
+        let mut order = [0u8; 32];
+        FSecp256Ord::ZERO
+            .sub(&FSecp256Ord::ONE)
+            .to_bytes(&mut order);
+        order[31] += 1;
+        let mut bad = vec![0x30, 0x26, 0x02, 0x21, 0x00];
+        bad.extend_from_slice(&order);
+        bad.extend_from_slice(&[0x02, 0x01, 0x01]);
+        assert!(reject(&bad));
+
+        let mut compact = sig.to_compact();
+        assert!(Signature::<FSecp256Ord>::from_compact(&compact[..63]).is_err());
+        compact[0..32].copy_from_slice(&order);
+        assert!(Signature::<FSecp256Ord>::from_compact(&compact[..]).is_err());
+
+        let mut recoverable = sig.to_compact_recoverable();
+        recoverable[64] = 4;
+        assert!(Signature::<FSecp256Ord>::from_compact_recoverable(&recoverable[..]).is_err());
+    }
+
+    #[test]
+    fn test_prehashed() {
+        let mut rng = rand::os::OsRng::new().unwrap();
+        let (sk, pk): (FSecp256Ord, P256<FSecp256, FSecp256Ord>) = ecdsa_keygen(&mut rng);
+        let msg = &"this is a random test message that is long".as_bytes();
+
+        // signing a message is signing its sha-256 digest
+        let mut sha = [0; 32];
+        hash(msg, &mut sha);
+        let (r, s) =
+            ecdsa_sign::<FSecp256, FSecp256Ord, P256<FSecp256, FSecp256Ord>>(msg, &sk, &mut rng);
+        assert!(ecdsa_verify_prehashed(&sha, (&r, &s), &pk));
+
+        let mut keccak = [0; 32];
+        let mut hasher = crypto::sha3::Sha3::keccak256();
+        hasher.input(msg);
+        hasher.result(&mut keccak);
+        assert!(!ecdsa_verify_prehashed(&keccak, (&r, &s), &pk));
+
+        let (r, s) = ecdsa_sign_prehashed::<FSecp256, FSecp256Ord, P256<FSecp256, FSecp256Ord>>(
+            &keccak, &sk, &mut rng,
+        );
+        assert!(ecdsa_verify_prehashed(&keccak, (&r, &s), &pk));
+        assert!(!ecdsa_verify(msg, (&r, &s), &pk));
+        let recovered = (0..4).find(|&recid| {
+            let sig = Signature { r, s, recid };
+            recover_public_key_prehashed(&keccak, &sig) == Some(pk)
+        });
+        assert!(recovered.is_some());
+    }
+
+    #[test]
+    fn test_recover_public_key() {
+        let mut rng = rand::os::OsRng::new().unwrap();
+        let (sk, pk): (FSecp256Ord, P256<FSecp256, FSecp256Ord>) = ecdsa_keygen(&mut rng);
+        let msg = &"this is a random test message that is long".as_bytes();
+
+        let (r, s) =
+            ecdsa_sign::<FSecp256, FSecp256Ord, P256<FSecp256, FSecp256Ord>>(msg, &sk, &mut rng);
+
+        // exactly one of the candidate points yields the signer's key
+        let matches = (0..4)
+            .filter(|&recid| {
+                let sig = Signature { r, s, recid };
+                recover_public_key::<FSecp256, FSecp256Ord, P256<FSecp256, FSecp256Ord>>(msg, &sig)
+                    == Some(pk)
+            })
+            .count();
+        assert_eq!(matches, 1);
+
+        let sig = Signature { r, s, recid: 4 };
+        assert!(
+            recover_public_key::<FSecp256, FSecp256Ord, P256<FSecp256, FSecp256Ord>>(msg, &sig)
+                .is_none()
+        );
+
+        // recovery ids computed from a known point are consistent with the curve
+        let (_, rpt): (FSecp256Ord, P256<FSecp256, FSecp256Ord>) = P256::rand(&mut rng);
+        let recid = recovery_id(&rpt);
+        assert_eq!(
+            P256::<FSecp256, FSecp256Ord>::from_x(&rpt.x(), recid & 1 != 0).unwrap(),
+            rpt
+        );
+    }
+
+    #[test]
+    fn test_batch_verify() {
+        let mut rng = rand::os::OsRng::new().unwrap();
+        let keys: Vec<(FSecp256Ord, Secp)> = (0..3).map(|_| ecdsa_keygen(&mut rng)).collect();
+        let msgs: Vec<Vec<u8>> = (0..12)
+            .map(|ii| format!("batch message number {}", ii).into_bytes())
+            .collect();
+        let mut sigs = Vec::with_capacity(msgs.len());
+        for (ii, msg) in msgs.iter().enumerate() {
+            let (sk, pk) = &keys[ii % keys.len()];
+            let (r, s) = ecdsa_sign::<FSecp256, FSecp256Ord, Secp>(msg, sk, &mut rng);
+            // the last signature comes from DER, and so has no usable recovery id
+            let sig = if ii == msgs.len() - 1 {
+                Signature::from_der(&Signature { r, s, recid: 0 }.to_der()).unwrap()
+            } else {
+                (0..4)
+                    .map(|recid| Signature { r, s, recid })
+                    .find(|sig| recover_public_key(msg, sig) == Some(*pk))
+                    .unwrap()
+            };
+            sigs.push(sig);
+        }
+
+        let entries: Vec<(&[u8], &Signature<FSecp256Ord>, &Secp)> = (0..msgs.len())
+            .map(|ii| (&msgs[ii][..], &sigs[ii], &keys[ii % keys.len()].1))
+            .collect();
+        assert_eq!(batch_verify(&entries[..]), Ok(()));
+        assert_eq!(batch_verify(&[]), Ok(()));
+
+        // a wrong message, a wrong key, and the point at infinity are each reported
+        let inf = Secp::INF;
+        let mut bad = entries.clone();
+        bad[2].0 = &msgs[3][..];
+        bad[5].2 = &keys[0].1;
+        bad[11].2 = &inf;
+        assert_eq!(batch_verify(&bad[..]), Err(vec![2, 5, 11]));
+    }
+
     #[test]
     fn test_sign() {
         let mut rng = rand::os::OsRng::new().unwrap();
-        let pk = P256::from_xy(
+        let pk = Secp::from_xy(
             &FSecp256::from_slice(&[
                 0x7af580e1c312a,
                 0xd6836084d8c4e,
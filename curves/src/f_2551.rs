@@ -0,0 +1,270 @@
+use super::f_ffff::{
+    add_mod, limbs_from_bytes, limbs_to_bytes, mont_mul, mont_pow, reduce_once, sub_borrow, sub_mod,
+};
+use super::Ford;
+use rand::Rng;
+///
+///
+// FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551
+// order of the P-256 group is 115792089210356248762697446949407573529996955224135760342422259061068512044369
+//
+// elements are stored as plain integers less than the order, like FSecp256Ord. Products are
+// reduced with two Montgomery multiplications
+//
+/// aas, neucrypt
+use std::fmt;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct FP256Ord {
+    pub v: [u64; 4],
+}
+
+pub const ORD: FP256Ord = FP256Ord {
+    v: [
+        0xF3B9CAC2FC632551,
+        0xBCE6FAADA7179E84,
+        0xFFFFFFFFFFFFFFFF,
+        0xFFFFFFFF00000000,
+    ],
+};
+
+// -n^-1 mod 2^64
+const N_INV: u64 = 0xCCD1C8AAEE00BC4F;
+// 2^512 mod n
+const R2: [u64; 4] = [
+    0x83244C95BE79EEA2,
+    0x4699799C49BD6FA6,
+    0x2845B2392B6BEC59,
+    0x66E12D94F3D95620,
+];
+// n - 2
+const N_MINUS_2: [u64; 4] = [
+    0xF3B9CAC2FC63254F,
+    0xBCE6FAADA7179E84,
+    0xFFFFFFFFFFFFFFFF,
+    0xFFFFFFFF00000000,
+];
+
+impl fmt::Debug for FP256Ord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "[0x{:0x}, 0x{:0x}, 0x{:0x}, 0x{:0x}]",
+            self.v[0], self.v[1], self.v[2], self.v[3]
+        )
+    }
+}
+
+impl fmt::Display for FP256Ord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "0x{:0x}{:0x}{:0x}{:0x}",
+            self.v[3], self.v[2], self.v[1], self.v[0]
+        )
+    }
+}
+
+impl Ford for FP256Ord {
+    const ONE: FP256Ord = FP256Ord { v: [1, 0, 0, 0] };
+    const ZERO: FP256Ord = FP256Ord { v: [0, 0, 0, 0] };
+    const NBITS: usize = 256;
+    const NBYTES: usize = (256 + 7) / 8;
+
+    fn from_slice(v: &[u64]) -> FP256Ord {
+        FP256Ord {
+            v: [v[0], v[1], v[2], v[3]],
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        (self.v[0] | self.v[1] | self.v[2] | self.v[3]) == 0
+    }
+
+    fn get_window(&self, i: usize) -> u8 {
+        if i >= 64 {
+            return 0;
+        }
+        let ni = i / 16;
+        let nj = (i % 16) * 4;
+        ((self.v[ni] >> (nj)) & 0xf) as u8
+    }
+
+    /// unlike for secp256k1, a random 256 bit value is not less than the order with
+    /// overwhelming probability, so values that are too large are rejected
+    fn rand(rng: &mut dyn Rng) -> Self {
+        loop {
+            let v = [
+                rng.next_u64(),
+                rng.next_u64(),
+                rng.next_u64(),
+                rng.next_u64(),
+            ];
+            if sub_borrow(&v, &ORD.v).1 == 1 {
+                return FP256Ord { v };
+            }
+        }
+    }
+
+    fn bit(&self, i: usize) -> bool {
+        let j = i % 64;
+        let k = (i / 64) as usize;
+        assert!(k < 4);
+
+        return ((self.v[k] >> j) & 0x1) > 0;
+    }
+
+    fn add(&self, b: &FP256Ord) -> FP256Ord {
+        FP256Ord {
+            v: add_mod(&self.v, &b.v, &ORD.v),
+        }
+    }
+
+    fn sub(&self, b: &FP256Ord) -> FP256Ord {
+        FP256Ord {
+            v: sub_mod(&self.v, &b.v, &ORD.v),
+        }
+    }
+
+    fn mul(&self, b: &FP256Ord) -> FP256Ord {
+        // (a*b/2^256) * 2^512 / 2^256 = a*b
+        let ab = mont_mul(&self.v, &b.v, &ORD.v, N_INV);
+        FP256Ord {
+            v: mont_mul(&ab, &R2, &ORD.v, N_INV),
+        }
+    }
+
+    fn pow_native(&self, n: u64) -> FP256Ord {
+        if n == 0 {
+            return FP256Ord::ONE;
+        }
+
+        let mut n = n;
+        let mut x = self.clone();
+        let mut y = FP256Ord::ONE;
+
+        while n > 1 {
+            if n % 2 == 0 {
+                x = x.sqr();
+                n = n / 2;
+            } else {
+                y = x.mul(&y);
+                x = x.sqr();
+                n = (n - 1) / 2;
+            }
+        }
+
+        x.mul(&y)
+    }
+
+    fn inv(&self) -> FP256Ord {
+        // a^(n-2) in Montgomery form, since mont_pow multiplies with mont_mul
+        let am = mont_mul(&self.v, &R2, &ORD.v, N_INV);
+        let onem = mont_mul(&[1, 0, 0, 0], &R2, &ORD.v, N_INV);
+        let r = mont_pow(&am, &N_MINUS_2, &onem, &ORD.v, N_INV);
+        FP256Ord {
+            v: mont_mul(&r, &[1, 0, 0, 0], &ORD.v, N_INV),
+        }
+    }
+
+    fn sqr(&self) -> FP256Ord {
+        self.mul(self)
+    }
+
+    fn neg(&self) -> FP256Ord {
+        FP256Ord {
+            v: sub_mod(&[0, 0, 0, 0], &self.v, &ORD.v),
+        }
+    }
+
+    fn to_bytes(&self, b: &mut [u8]) {
+        if b.len() < 32 {
+            panic!("using to_bytes on a small slice");
+        }
+        limbs_to_bytes(&self.v, b);
+    }
+
+    /// values that are not less than the order are reduced
+    fn from_bytes(b: &[u8]) -> Self {
+        if b.len() < 32 {
+            panic!("calling from_bytes on a small slice");
+        }
+        FP256Ord {
+            v: reduce_once(&limbs_from_bytes(b), &ORD.v),
+        }
+    }
+
+    fn from_native(b: u64) -> Self {
+        FP256Ord { v: [b, 0, 0, 0] }
+    }
+}
+
+#[test]
+fn p256_ord_mul_tests() {
+    let a = FP256Ord {
+        v: [
+            0x7cf1bb69abb65af4,
+            0x895226b5e95d05a4,
+            0xfda3e96dd6e46282,
+            0xe8f4a37d6822745c,
+        ],
+    };
+    let b = FP256Ord {
+        v: [
+            0xe2da678a3bd9f587,
+            0x4bac621d4ea8a910,
+            0xc1ef8744f93ea5bf,
+            0x35b4f67cc1d4bdbb,
+        ],
+    };
+    let c = FP256Ord {
+        v: [
+            0x69b9d3e166ef7bc4,
+            0x1946339c25cbd601,
+            0xbaf2756e8ca80e82,
+            0x48e9b3b726b66313,
+        ],
+    };
+    assert_eq!(a.mul(&b), c);
+    assert_eq!(a.mul(&FP256Ord::ONE), a);
+    assert_eq!(a.pow_native(3), a.mul(&a).mul(&a));
+}
+
+#[test]
+fn p256_ord_add_sub_tests() {
+    let a = FP256Ord {
+        v: [
+            0x7cf1bb69abb65af4,
+            0x895226b5e95d05a4,
+            0xfda3e96dd6e46282,
+            0xe8f4a37d6822745c,
+        ],
+    };
+    assert!(a.add(&a.neg()).is_zero());
+    assert_eq!(a.sub(&FP256Ord::ONE).add(&FP256Ord::ONE), a);
+    assert!(FP256Ord::ZERO.neg().is_zero());
+
+    // the order itself reduces to zero
+    let mut nb = [0u8; 32];
+    ORD.to_bytes(&mut nb);
+    assert!(FP256Ord::from_bytes(&nb).is_zero());
+}
+
+#[test]
+fn p256_ord_inv_tests() {
+    let a = FP256Ord {
+        v: [
+            0x7cf1bb69abb65af4,
+            0x895226b5e95d05a4,
+            0xfda3e96dd6e46282,
+            0xe8f4a37d6822745c,
+        ],
+    };
+    assert_eq!(a.mul(&a.inv()), FP256Ord::ONE);
+    assert_eq!(
+        FP256Ord::from_native(2)
+            .inv()
+            .add(&FP256Ord::from_native(2).inv()),
+        FP256Ord::ONE
+    );
+}
@@ -570,8 +570,13 @@ impl Fq for FSecp256 {
         // *  As (p+1)/4 is an even number, it will have the same result for a and for
         // *  (-a). Only one of these two numbers actually has a square root however,
         // *  so we test at the end by squaring and comparing to the input.
+        // both sides must be normalized, since equals compares limbs
 
-        if self.equals(&t1.sqr()) {
+        let mut lhs = *self;
+        let mut rhs = t1.sqr();
+        lhs.normalize();
+        rhs.normalize();
+        if lhs.equals(&rhs) {
             Ok(t1)
         } else {
             Err("does not have a root")
@@ -0,0 +1,381 @@
+// This module implements field operations modulo
+// 115792089210356248762697446949407573530086143415290314195533631308867097853951
+// or 0xFFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFF, the base field of P-256.
+// elements are stored as 4 uint64_t's in Montgomery form, x*2^256 mod p, and are always
+// fully reduced, so normalize and normalize_weak have nothing to do.
+//
+/// aas, neucrypt
+use super::Fq;
+use rand::Rng;
+use std::fmt;
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct FP256 {
+    pub v: [u64; 4],
+}
+
+const P: [u64; 4] = [
+    0xFFFFFFFFFFFFFFFF,
+    0x00000000FFFFFFFF,
+    0x0000000000000000,
+    0xFFFFFFFF00000001,
+];
+// -p^-1 mod 2^64
+const P_INV: u64 = 1;
+// 2^512 mod p, used to move into Montgomery form
+const R2: [u64; 4] = [
+    0x0000000000000003,
+    0xFFFFFFFBFFFFFFFF,
+    0xFFFFFFFFFFFFFFFE,
+    0x00000004FFFFFFFD,
+];
+// p - 2
+const P_MINUS_2: [u64; 4] = [
+    0xFFFFFFFFFFFFFFFD,
+    0x00000000FFFFFFFF,
+    0x0000000000000000,
+    0xFFFFFFFF00000001,
+];
+// (p + 1) / 4
+const P_PLUS_1_DIV_4: [u64; 4] = [
+    0x0000000000000000,
+    0x0000000040000000,
+    0x4000000000000000,
+    0x3FFFFFFFC0000000,
+];
+
+impl fmt::Debug for FP256 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "[{:0x} {:0x} {:0x} {:0x}]",
+            self.v[0], self.v[1], self.v[2], self.v[3]
+        )
+    }
+}
+
+impl fmt::Display for FP256 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let v = self.from_mont();
+        write!(f, "0x{:016x}{:016x}{:016x}{:016x}", v[3], v[2], v[1], v[0])
+    }
+}
+
+impl FP256 {
+    fn from_mont(&self) -> [u64; 4] {
+        mont_mul(&self.v, &[1, 0, 0, 0], &P, P_INV)
+    }
+
+    fn pow(&self, e: &[u64; 4]) -> FP256 {
+        FP256 {
+            v: mont_pow(&self.v, e, &FP256::ONE.v, &P, P_INV),
+        }
+    }
+}
+
+// arithmetic on 4x64-bit little endian integers modulo a 256 bit m. It is shared by the
+// P-256 base field and the P-256 order, and none of it branches on its inputs
+
+// returns a + b - m if that does not borrow, else a + b. a and b must be less than m
+pub(crate) fn add_mod(a: &[u64; 4], b: &[u64; 4], m: &[u64; 4]) -> [u64; 4] {
+    let mut r = [0u64; 4];
+    let mut carry = 0u128;
+    for i in 0..4 {
+        let t = a[i] as u128 + b[i] as u128 + carry;
+        r[i] = t as u64;
+        carry = t >> 64;
+    }
+    let (d, borrow) = sub_borrow(&r, m);
+    // keep d if the sum overflowed 2^256, or if subtracting m did not borrow
+    let keep = (carry as u64) | (1 - borrow);
+    select(&r, &d, keep)
+}
+
+pub(crate) fn sub_mod(a: &[u64; 4], b: &[u64; 4], m: &[u64; 4]) -> [u64; 4] {
+    let (d, borrow) = sub_borrow(a, b);
+    let mut r = [0u64; 4];
+    let mask = 0u64.wrapping_sub(borrow);
+    let mut carry = 0u128;
+    for i in 0..4 {
+        let t = d[i] as u128 + (m[i] & mask) as u128 + carry;
+        r[i] = t as u64;
+        carry = t >> 64;
+    }
+    r
+}
+
+// returns a - b and the final borrow
+pub(crate) fn sub_borrow(a: &[u64; 4], b: &[u64; 4]) -> ([u64; 4], u64) {
+    let mut r = [0u64; 4];
+    let mut borrow = 0u64;
+    for i in 0..4 {
+        let (t, b0) = a[i].overflowing_sub(b[i]);
+        let (t, b1) = t.overflowing_sub(borrow);
+        r[i] = t;
+        borrow = (b0 | b1) as u64;
+    }
+    (r, borrow)
+}
+
+// returns b if sel is 1 and a if sel is 0
+pub(crate) fn select(a: &[u64; 4], b: &[u64; 4], sel: u64) -> [u64; 4] {
+    let mask = 0u64.wrapping_sub(sel);
+    [
+        a[0] ^ ((a[0] ^ b[0]) & mask),
+        a[1] ^ ((a[1] ^ b[1]) & mask),
+        a[2] ^ ((a[2] ^ b[2]) & mask),
+        a[3] ^ ((a[3] ^ b[3]) & mask),
+    ]
+}
+
+// reduces an integer less than 2m into [0, m)
+pub(crate) fn reduce_once(a: &[u64; 4], m: &[u64; 4]) -> [u64; 4] {
+    let (d, borrow) = sub_borrow(a, m);
+    select(a, &d, 1 - borrow)
+}
+
+// computes a*b/2^256 mod m with the CIOS method, where minv = -m^-1 mod 2^64
+pub(crate) fn mont_mul(a: &[u64; 4], b: &[u64; 4], m: &[u64; 4], minv: u64) -> [u64; 4] {
+    let mut t = [0u64; 6];
+    for i in 0..4 {
+        let mut carry = 0u128;
+        for j in 0..4 {
+            let s = t[j] as u128 + (a[j] as u128) * (b[i] as u128) + carry;
+            t[j] = s as u64;
+            carry = s >> 64;
+        }
+        let s = t[4] as u128 + carry;
+        t[4] = s as u64;
+        t[5] = (s >> 64) as u64;
+
+        let q = t[0].wrapping_mul(minv);
+        let mut carry = (t[0] as u128 + (q as u128) * (m[0] as u128)) >> 64;
+        for j in 1..4 {
+            let s = t[j] as u128 + (q as u128) * (m[j] as u128) + carry;
+            t[j - 1] = s as u64;
+            carry = s >> 64;
+        }
+        let s = t[4] as u128 + carry;
+        t[3] = s as u64;
+        t[4] = t[5] + (s >> 64) as u64;
+    }
+
+    let r = [t[0], t[1], t[2], t[3]];
+    let (d, borrow) = sub_borrow(&r, m);
+    select(&r, &d, t[4] | (1 - borrow))
+}
+
+// left to right square and multiply. the exponent is public, so this may branch on it
+pub(crate) fn mont_pow(
+    a: &[u64; 4],
+    e: &[u64; 4],
+    one: &[u64; 4],
+    m: &[u64; 4],
+    minv: u64,
+) -> [u64; 4] {
+    let mut r = *one;
+    for i in (0..256).rev() {
+        r = mont_mul(&r, &r, m, minv);
+        if (e[i / 64] >> (i % 64)) & 1 == 1 {
+            r = mont_mul(&r, a, m, minv);
+        }
+    }
+    r
+}
+
+pub(crate) fn limbs_from_bytes(b: &[u8]) -> [u64; 4] {
+    let mut r = [0u64; 4];
+    for i in 0..32 {
+        r[3 - i / 8] |= (b[i] as u64) << (56 - 8 * (i % 8));
+    }
+    r
+}
+
+pub(crate) fn limbs_to_bytes(v: &[u64; 4], b: &mut [u8]) {
+    for i in 0..32 {
+        b[i] = (v[3 - i / 8] >> (56 - 8 * (i % 8))) as u8;
+    }
+}
+
+impl Fq for FP256 {
+    // 2^256 mod p
+    const ONE: FP256 = FP256 {
+        v: [
+            0x0000000000000001,
+            0xFFFFFFFF00000000,
+            0xFFFFFFFFFFFFFFFF,
+            0x00000000FFFFFFFE,
+        ],
+    };
+    const ZERO: FP256 = FP256 { v: [0, 0, 0, 0] };
+    const NBITS: usize = 256;
+    const NBYTES: usize = (256 + 7) / 8;
+
+    fn is_zero(&self) -> bool {
+        (self.v[0] | self.v[1] | self.v[2] | self.v[3]) == 0
+    }
+
+    fn is_one(&self) -> bool {
+        self.v == FP256::ONE.v
+    }
+
+    /// takes the value as 4 little endian 64-bit limbs, which must be less than p
+    fn from_slice(r: &[u64]) -> FP256 {
+        FP256 {
+            v: mont_mul(&[r[0], r[1], r[2], r[3]], &R2, &P, P_INV),
+        }
+    }
+
+    fn add(&self, b: &FP256) -> FP256 {
+        FP256 {
+            v: add_mod(&self.v, &b.v, &P),
+        }
+    }
+
+    fn sub(&self, b: &FP256) -> FP256 {
+        FP256 {
+            v: sub_mod(&self.v, &b.v, &P),
+        }
+    }
+
+    fn sqr(&self) -> FP256 {
+        self.mul(self)
+    }
+
+    fn sqrt(&self) -> Result<FP256, &'static str> {
+        // p = 3 mod 4, so a root is a^((p+1)/4) if there is one
+        let r = self.pow(&P_PLUS_1_DIV_4);
+        if r.sqr().equals(self) {
+            Ok(r)
+        } else {
+            Err("does not have a root")
+        }
+    }
+
+    /// elements are always reduced, so the magnitude is ignored
+    fn neg(&self, _m: u64) -> FP256 {
+        FP256 {
+            v: sub_mod(&[0, 0, 0, 0], &self.v, &P),
+        }
+    }
+
+    fn inv(&self) -> FP256 {
+        self.pow(&P_MINUS_2)
+    }
+
+    fn to_bytes(&self, b: &mut [u8]) {
+        if b.len() < 32 {
+            panic!("writing into a small buffer")
+        }
+        limbs_to_bytes(&self.from_mont(), b);
+    }
+
+    /// values that are not less than p are reduced
+    fn from_bytes(b: &[u8]) -> FP256 {
+        let v = reduce_once(&limbs_from_bytes(b), &P);
+        FP256 {
+            v: mont_mul(&v, &R2, &P, P_INV),
+        }
+    }
+
+    fn rand(rng: &mut dyn Rng) -> Self {
+        let mut b = [0u8; 32];
+        rng.fill_bytes(&mut b);
+        FP256::from_bytes(&b)
+    }
+
+    fn mul(&self, b: &FP256) -> FP256 {
+        FP256 {
+            v: mont_mul(&self.v, &b.v, &P, P_INV),
+        }
+    }
+
+    fn muli(&self, b: u64) -> FP256 {
+        self.mul(&FP256::from_slice(&[b, 0, 0, 0]))
+    }
+
+    fn equals(&self, b: &FP256) -> bool {
+        self.v == b.v
+    }
+
+    fn bit(&self, i: u32) -> bool {
+        let v = self.from_mont();
+        (v[(i / 64) as usize] >> (i % 64)) & 1 == 1
+    }
+
+    fn normalize(&mut self) {}
+
+    fn normalize_weak(&mut self) {}
+
+    fn mux(y: &mut FP256, a: &FP256, b: &FP256, sel: u32) {
+        y.v = select(&a.v, &b.v, sel as u64);
+    }
+
+    fn swap(a: &mut FP256, b: &mut FP256, sel: u32) {
+        let c = 0u64.wrapping_sub(sel as u64);
+        for i in 0..4 {
+            let t = c & (a.v[i] ^ b.v[i]);
+            a.v[i] ^= t;
+            b.v[i] ^= t;
+        }
+    }
+
+    fn mov(&mut self, a: &FP256, sel: bool) {
+        self.v = select(&self.v, &a.v, sel as u64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_hex(s: &str) -> FP256 {
+        let mut b = [0u8; 32];
+        for i in 0..32 {
+            b[i] = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+        }
+        FP256::from_bytes(&b)
+    }
+
+    #[test]
+    fn ffff_mul_tests() {
+        let a = from_hex("6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296");
+        let b = from_hex("4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5");
+        let c = from_hex("823cd15f6dd3c71933565064513a6b2bd183e554c6a08622f713ebbbface98be");
+        assert_eq!(a.mul(&b), c);
+        assert_eq!(a.mul(&FP256::ONE), a);
+        assert!(a.mul(&FP256::ZERO).is_zero());
+
+        let mut ab = [0u8; 32];
+        a.to_bytes(&mut ab);
+        assert_eq!(FP256::from_bytes(&ab), a);
+
+        // p itself reduces to zero
+        let mut pb = [0u8; 32];
+        limbs_to_bytes(&P, &mut pb);
+        assert!(FP256::from_bytes(&pb).is_zero());
+        assert!(FP256::from_slice(&[1, 0, 0, 0]).is_one());
+    }
+
+    #[test]
+    fn ffff_add_sub_tests() {
+        let a = from_hex("6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296");
+        let b = from_hex("fffffffe00000002000000000000000000000001fffffffffffffffffffffff0");
+        assert_eq!(a.add(&b).sub(&b), a);
+        assert_eq!(a.sub(&b).add(&b), a);
+        assert!(a.add(&a.neg(1)).is_zero());
+        assert_eq!(a.muli(3), a.add(&a).add(&a));
+        assert!(FP256::ZERO.neg(1).is_zero());
+    }
+
+    #[test]
+    fn ffff_inv_sqrt_tests() {
+        let a = from_hex("6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296");
+        assert!(a.mul(&a.inv()).is_one());
+
+        let r = a.sqr().sqrt().unwrap();
+        assert!(r == a || r == a.neg(1));
+        // -1 is not a square, as p = 3 mod 4
+        assert!(FP256::ONE.neg(1).sqrt().is_err());
+    }
+}
@@ -12,14 +12,22 @@
 extern crate test;
 
 pub mod ecdsa;
+pub mod f_2551;
 pub mod f_4141;
 pub mod f_fc2f;
+pub mod f_ffff;
 pub mod precomp;
+pub mod schnorr;
 pub mod secp256k1;
+pub mod secp256r1;
 
 pub type SecpOrd = f_4141::FSecp256Ord;
 pub type Secp = secp256k1::P256<f_fc2f::FSecp256, SecpOrd>;
 
+/// NIST P-256
+pub type SecpR1Ord = f_2551::FP256Ord;
+pub type SecpR1 = secp256k1::P256<f_ffff::FP256, SecpR1Ord, secp256r1::Secp256r1Params>;
+
 use std::fmt::{Debug, Display};
 
 pub trait Fq: Copy + Debug + Display + Eq {
@@ -114,6 +122,9 @@ where
     /// checks that the point is actually on the curve, else returns Error
     fn from_xy(x: &F, y: &F) -> Result<Self, &'static str>;
 
+    /// constructs a point from an x coordinate and the parity of its y coordinate
+    fn from_x(x: &F, odd: bool) -> Result<Self, &'static str>;
+
     fn rand(rng: &mut dyn rand::Rng) -> (T, Self);
     // fn hash_to_curve(message: &[u8]) -> Self;
 
@@ -132,7 +143,39 @@ where
     fn scalar_gen(x: &T) -> Self;
     fn scalar_table_multi(table: &[Self], x: &T) -> Self;
     fn precomp_table(x: &Self) -> Vec<Self>;
+    /// Constant time counterparts of scalar and scalar_table_multi, for use with secret scalars.
+    /// They branch on no bit of the scalar and access memory in a fixed pattern
+    fn scalar_ct(&self, x: &T) -> Self;
+    fn scalar_table_multi_ct(table: &[Self], x: &T) -> Self;
+    /// Computes the sum of points[i]*scalars[i]. Like scalar_table_multi, this is not constant time
+    fn multi_scalar_mul(points: &[Self], scalars: &[T]) -> Self;
 
     fn to_bytes(&self, b: &mut [u8]);
     fn from_bytes(b: &[u8]) -> Self;
+    /// Like from_bytes, but returns an error if the point is not on the curve.
+    /// The point at infinity is encoded as NBYTES zero bytes
+    fn try_from_bytes(b: &[u8]) -> Result<Self, &'static str>;
+
+    /// SEC1 encodings: 0x02 or 0x03 (for even or odd y) || x, and 0x04 || x || y.
+    /// SEC1 encodes the point at infinity as a single zero byte; here it is padded with zeros
+    /// to the length of the other encodings
+    const NBYTES_COMPRESSED: usize;
+    const NBYTES_UNCOMPRESSED: usize;
+    fn to_bytes_compressed(&self, b: &mut [u8]);
+    fn from_bytes_compressed(b: &[u8]) -> Result<Self, &'static str>;
+    fn to_bytes_uncompressed(&self, b: &mut [u8]);
+    fn from_bytes_uncompressed(b: &[u8]) -> Result<Self, &'static str>;
+}
+
+/// The coefficients of a short Weierstrass curve y^2 = x^3 + ax + b, used by secp256k1::P256.
+/// The group law has complete formulas for a = 0 and for a = -3 only. The a = 0 formulas are
+/// specialized to secp256k1, with b = 7
+pub trait CurveParams<F: Fq>: Copy + Eq {
+    /// false for a = 0, true for a = -3
+    const A_IS_MINUS_3: bool;
+    const NOT_ON_CURVE: &'static str;
+
+    fn b() -> F;
+    /// the affine coordinates of the generator
+    fn gen() -> (F, F);
 }
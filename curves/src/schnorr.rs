@@ -0,0 +1,323 @@
+use super::{ECGroup, Ford, Fq};
+use crypto::digest::Digest;
+use crypto::sha2::Sha256;
+///
+/// implementation of BIP-340 schnorr signatures, over x-only public keys
+///
+/// aas, neucrypt
+use rand::Rng;
+
+pub const SCHNORR_SIG_SIZE: usize = 64;
+
+/// sha256(sha256(tag) || sha256(tag) || data), as defined by BIP-340
+pub fn tagged_hash(tag: &str, data: &[&[u8]]) -> [u8; 32] {
+    let mut taghash = [0; 32];
+    let mut hasher = Sha256::new();
+    hasher.input(tag.as_bytes());
+    hasher.result(&mut taghash);
+
+    let mut hasher = Sha256::new();
+    hasher.input(&taghash);
+    hasher.input(&taghash);
+    for d in data.iter() {
+        hasher.input(d);
+    }
+    let mut res = [0; 32];
+    hasher.result(&mut res);
+    res
+}
+
+/// the 32-byte x coordinate of a point, which BIP-340 uses as its public key encoding
+pub fn xonly_bytes<F: Fq, T: Ford, E: ECGroup<F, T>>(pt: &E) -> [u8; 32] {
+    let mut b = [0; 32];
+    pt.affine().x().to_bytes(&mut b);
+    b
+}
+
+pub fn has_even_y<F: Fq, T: Ford, E: ECGroup<F, T>>(pt: &E) -> bool {
+    !pt.affine().y().bit(0)
+}
+
+/// the point with the given x coordinate and an even y coordinate, if there is one
+pub fn lift_x<F: Fq, T: Ford, E: ECGroup<F, T>>(x: &[u8; 32]) -> Option<E> {
+    let mut xf = F::from_bytes(&x[..]);
+    xf.normalize();
+    let mut xb = [0; 32];
+    xf.to_bytes(&mut xb);
+    // x must be less than the field modulus
+    if xb != *x {
+        return None;
+    }
+    E::from_x(&xf, false).ok()
+}
+
+/// e = int(hash_BIP0340/challenge(R.x || P.x || msg)) mod order
+pub fn schnorr_challenge<T: Ford>(rx: &[u8; 32], px: &[u8; 32], msg: &[u8]) -> T {
+    T::from_bytes(&tagged_hash("BIP0340/challenge", &[&rx[..], &px[..], msg]))
+}
+
+pub fn schnorr_verify<F: Fq, T: Ford, E: ECGroup<F, T>>(
+    msg: &[u8],
+    sig: &[u8; SCHNORR_SIG_SIZE],
+    pk: &[u8; 32],
+) -> bool {
+    let pkpt: E = match lift_x(pk) {
+        Some(pkpt) => pkpt,
+        None => return false,
+    };
+
+    let mut rx = [0; 32];
+    rx.copy_from_slice(&sig[0..32]);
+    let mut rxf = F::from_bytes(&rx[..]);
+    rxf.normalize();
+    let mut rxb = [0; 32];
+    rxf.to_bytes(&mut rxb);
+    let s = T::from_bytes(&sig[32..64]);
+    let mut sb = [0; 32];
+    s.to_bytes(&mut sb);
+    // r must be less than the field modulus, and s less than the group order
+    if rxb != rx || sb[..] != sig[32..64] {
+        return false;
+    }
+
+    // R = s*G - e*P must have an even y coordinate and x coordinate r
+    let e: T = schnorr_challenge(&rx, pk, msg);
+    let rpt = E::op(&E::scalar_gen(&s), &pkpt.scalar(&e).neg()).affine();
+    !rpt.is_infinity() && has_even_y(&rpt) && xonly_bytes(&rpt) == rx
+}
+
+/// signs msg with sk. aux is fresh randomness that is mixed into the deterministic nonce
+pub fn schnorr_sign_with_aux<F: Fq, T: Ford, E: ECGroup<F, T>>(
+    msg: &[u8],
+    sk: &T,
+    aux: &[u8; 32],
+) -> [u8; SCHNORR_SIG_SIZE] {
+    // the secret key is negated if necessary so that its public key has an even y coordinate
+    let pk = E::scalar_gen(sk).affine();
+    let d = if has_even_y(&pk) { *sk } else { sk.neg() };
+    let px = xonly_bytes(&pk);
+
+    let mut t = [0; 32];
+    d.to_bytes(&mut t);
+    let auxhash = tagged_hash("BIP0340/aux", &[&aux[..]]);
+    for ii in 0..32 {
+        t[ii] ^= auxhash[ii];
+    }
+
+    // probability that k == 0 is exponentially small. we skip the check
+    let kp = T::from_bytes(&tagged_hash("BIP0340/nonce", &[&t[..], &px[..], msg]));
+    let rpt = E::scalar_gen(&kp).affine();
+    let k = if has_even_y(&rpt) { kp } else { kp.neg() };
+    let rx = xonly_bytes(&rpt);
+
+    let e: T = schnorr_challenge(&rx, &px, msg);
+    let mut sig = [0; SCHNORR_SIG_SIZE];
+    sig[0..32].copy_from_slice(&rx);
+    k.add(&e.mul(&d)).to_bytes(&mut sig[32..64]);
+    sig
+}
+
+pub fn schnorr_sign<F: Fq, T: Ford, E: ECGroup<F, T>>(
+    msg: &[u8],
+    sk: &T,
+    rng: &mut dyn Rng,
+) -> [u8; SCHNORR_SIG_SIZE] {
+    let mut aux = [0; 32];
+    rng.fill_bytes(&mut aux);
+    schnorr_sign_with_aux::<F, T, E>(msg, sk, &aux)
+}
+
+/// the BIP-341 tweak t = int(hash_TapTweak(P.x || merkle_root)). a key without a script tree
+/// commits to no merkle root, as in BIP-86
+pub fn taproot_tweak<T: Ford>(
+    px: &[u8; 32],
+    merkle_root: Option<&[u8; 32]>,
+) -> Result<T, &'static str> {
+    let th = match merkle_root {
+        Some(root) => tagged_hash("TapTweak", &[&px[..], &root[..]]),
+        None => tagged_hash("TapTweak", &[&px[..]]),
+    };
+    let t = T::from_bytes(&th);
+    let mut tb = [0; 32];
+    t.to_bytes(&mut tb);
+    if tb != th {
+        return Err("Taproot tweak is not less than the group order");
+    }
+    Ok(t)
+}
+
+/// the taproot output key Q = lift_x(P.x) + t*G, along with the tweak t
+pub fn taproot_tweak_pubkey<F: Fq, T: Ford, E: ECGroup<F, T>>(
+    px: &[u8; 32],
+    merkle_root: Option<&[u8; 32]>,
+) -> Result<(E, T), &'static str> {
+    let p: E = match lift_x(px) {
+        Some(p) => p,
+        None => return Err("Internal key is not the x coordinate of a curve point"),
+    };
+    let t: T = taproot_tweak(px, merkle_root)?;
+    let q = E::op(&p, &E::scalar_gen(&t)).affine();
+    if q.is_infinity() {
+        return Err("Taproot output key is the point at infinity");
+    }
+    Ok((q, t))
+}
+
+/// tweaks a secret key so that it signs, via schnorr_sign, for the taproot output key of its public key
+pub fn taproot_tweak_seckey<F: Fq, T: Ford, E: ECGroup<F, T>>(
+    sk: &T,
+    merkle_root: Option<&[u8; 32]>,
+) -> Result<T, &'static str> {
+    let p = E::scalar_gen(sk).affine();
+    let d = if has_even_y(&p) { *sk } else { sk.neg() };
+    let t: T = taproot_tweak(&xonly_bytes(&p), merkle_root)?;
+    Ok(d.add(&t))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate rand;
+    use super::super::f_4141::FSecp256Ord;
+    use super::super::f_fc2f::FSecp256;
+    use super::super::secp256k1::P256;
+    use super::*;
+
+    type Point = P256<FSecp256, FSecp256Ord>;
+
+    fn from_hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|ii| u8::from_str_radix(&s[ii..(ii + 2)], 16).unwrap())
+            .collect()
+    }
+
+    fn from_hex32(s: &str) -> [u8; 32] {
+        let mut b = [0; 32];
+        b.copy_from_slice(&from_hex(s));
+        b
+    }
+
+    #[test]
+    fn test_schnorr_vectors() {
+        // test vectors 0, 1 and 2 from BIP-340
+        let vectors = [
+            (
+                "0000000000000000000000000000000000000000000000000000000000000003",
+                "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA821525F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0",
+            ),
+            (
+                "B7E151628AED2A6ABF7158809CF4F3C762E7160F38B4DA56A784D9045190CFEF",
+                "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "6896BD60EEAE296DB48A229FF71DFE071BDE413E6D43F917DC8DCF8C78DE33418906D11AC976ABCCB20B091292BFF4EA897EFCB639EA871CFA95F6DE339E4B0A",
+            ),
+            (
+                "C90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74020BBEA63B14E5C9",
+                "DD308AFEC5777E13121FA72B9CC1B7CC0139715309B086C960E18FD969774EB8",
+                "C87AA53824B4D7AE2EB035A2B5BBBCCC080E76CDC6D1692C4B0B62D798E6D906",
+                "7E2D58D8B3BCDF1ABADEC7829054F90DDA9805AAB56C77333024B9D0A508B75C",
+                "5831AAEED7B44BB74E5EAB94BA9D4294C49BCF2A60728D8B4C200F50DD313C1BAB745879A5AD954A72C45A91C3A51D3C7ADEA98D82F8481E0E1E03674A6F3FB7",
+            ),
+        ];
+
+        for &(sk, pk, aux, msg, sig) in vectors.iter() {
+            let sk = FSecp256Ord::from_bytes(&from_hex(sk));
+            let pk = from_hex32(pk);
+            let msg = from_hex(msg);
+            let sig = from_hex(sig);
+
+            assert_eq!(xonly_bytes(&Point::scalar_gen(&sk)), pk);
+            let computed =
+                schnorr_sign_with_aux::<FSecp256, FSecp256Ord, Point>(&msg, &sk, &from_hex32(aux));
+            assert_eq!(&computed[..], &sig[..]);
+            assert!(schnorr_verify::<FSecp256, FSecp256Ord, Point>(
+                &msg, &computed, &pk
+            ));
+        }
+    }
+
+    #[test]
+    fn test_schnorr_rejects_invalid() {
+        let mut rng = rand::os::OsRng::new().unwrap();
+        let (sk, pk): (FSecp256Ord, Point) = Point::rand(&mut rng);
+        let pk = xonly_bytes(&pk);
+        let msg = "this is a random test message that is long".as_bytes();
+
+        let sig = schnorr_sign::<FSecp256, FSecp256Ord, Point>(msg, &sk, &mut rng);
+        assert!(schnorr_verify::<FSecp256, FSecp256Ord, Point>(
+            msg, &sig, &pk
+        ));
+        assert!(!schnorr_verify::<FSecp256, FSecp256Ord, Point>(
+            "other msg".as_bytes(),
+            &sig,
+            &pk
+        ));
+
+        let mut badsig = sig;
+        badsig[63] ^= 1;
+        assert!(!schnorr_verify::<FSecp256, FSecp256Ord, Point>(
+            msg, &badsig, &pk
+        ));
+
+        // r equal to the field modulus, and s equal to the group order, are out of range
+        let mut badsig = sig;
+        badsig[0..32].copy_from_slice(&from_hex32(
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+        ));
+        assert!(!schnorr_verify::<FSecp256, FSecp256Ord, Point>(
+            msg, &badsig, &pk
+        ));
+        let mut badsig = sig;
+        badsig[32..64].copy_from_slice(&from_hex32(
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+        ));
+        assert!(!schnorr_verify::<FSecp256, FSecp256Ord, Point>(
+            msg, &badsig, &pk
+        ));
+
+        // a public key that is not the x coordinate of a curve point (BIP-340 vector 5)
+        let badpk = from_hex32("EEFDEA4CDB677750A420FEE807EACF21EB9898AE79B9768766E4FAA04A2D4A34");
+        assert!(lift_x::<FSecp256, FSecp256Ord, Point>(&badpk).is_none());
+        assert!(!schnorr_verify::<FSecp256, FSecp256Ord, Point>(
+            msg, &sig, &badpk
+        ));
+    }
+
+    #[test]
+    fn test_taproot_tweak() {
+        // the first receiving address from BIP-86, m/86'/0'/0'/0/0
+        let px = from_hex32("CC8A4BC64D897BDDC5FBC2F670F7A8BA0B386779106CF1223C6FC5D7CD6FC115");
+        let (q, _): (Point, FSecp256Ord) = taproot_tweak_pubkey(&px, None).unwrap();
+        assert_eq!(
+            xonly_bytes(&q),
+            from_hex32("A60869F0DBCF1DC659C9CECBAF8050135EA9E8CDC487053F1DC6880949DC684C")
+        );
+
+        // a tweaked secret key signs for the output key, with or without a script tree
+        let mut rng = rand::os::OsRng::new().unwrap();
+        let (sk, p): (FSecp256Ord, Point) = Point::rand(&mut rng);
+        let msg = "this is a random test message that is long".as_bytes();
+        let root = [7u8; 32];
+        for merkle_root in [None, Some(&root)].iter() {
+            let (q, _): (Point, FSecp256Ord) =
+                taproot_tweak_pubkey(&xonly_bytes(&p), *merkle_root).unwrap();
+            let tsk =
+                taproot_tweak_seckey::<FSecp256, FSecp256Ord, Point>(&sk, *merkle_root).unwrap();
+            let sig = schnorr_sign::<FSecp256, FSecp256Ord, Point>(msg, &tsk, &mut rng);
+            assert!(schnorr_verify::<FSecp256, FSecp256Ord, Point>(
+                msg,
+                &sig,
+                &xonly_bytes(&q)
+            ));
+            assert!(!schnorr_verify::<FSecp256, FSecp256Ord, Point>(
+                msg,
+                &sig,
+                &xonly_bytes(&p)
+            ));
+        }
+    }
+}
@@ -1,6 +1,9 @@
 ///
 /// Curve equation y^2 = x^3 + 7.
 ///
+/// P256 is generic over the curve coefficients, see CurveParams. The formulas for a = -3
+/// are used for NIST P-256, whose parameters are in secp256r1.rs
+///
 /// The field is mod
 /// 115792089237316195423570985008687907853269984665640564039457584007908834671663
 ///
@@ -11,31 +14,38 @@
 ///
 ///
 /// aas, neucrypt
-use super::{ECGroup, Ford, Fq};
+use super::{CurveParams, ECGroup, Ford, Fq};
 use rand::Rng;
 use std::fmt;
 use std::fmt::{Debug, Display};
 use std::marker::PhantomData;
 
 #[derive(Copy, Clone, Eq, PartialEq)]
-pub struct P256<F: Fq + Copy + Eq, T: Ford> {
+pub struct P256<F: Fq + Copy + Eq, T: Ford, C: CurveParams<F> = Secp256k1Params> {
     pub x: F,
     pub y: F,
     pub z: F,
     pub inf: bool,
 
     /// this field is used only because the rand/scalar trait methods
-    /// require a type T that handles order arithmetic
-    pub p: PhantomData<T>,
+    /// require a type T that handles order arithmetic, and a type C with the curve coefficients
+    pub p: PhantomData<(T, C)>,
 }
 
+/// secp256k1, y^2 = x^3 + 7
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Secp256k1Params;
+
 const CURVE_B: u64 = 7;
 const CURVE_B3: u64 = 3 * CURVE_B;
+// multi_scalar_mul switches from Strauss-Shamir to Pippenger at this many points
+const PIPPENGER_THRESHOLD: usize = 128;
 
-impl<F, T> Debug for P256<F, T>
+impl<F, T, C> Debug for P256<F, T, C>
 where
     F: Fq,
     T: Ford,
+    C: CurveParams<F>,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -46,10 +56,11 @@ where
     }
 }
 
-impl<F, T> Display for P256<F, T>
+impl<F, T, C> Display for P256<F, T, C>
 where
     F: Fq,
     T: Ford,
+    C: CurveParams<F>,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -60,10 +71,11 @@ where
     }
 }
 
-impl<F, T> ECGroup<F, T> for P256<F, T>
+impl<F, T, C> ECGroup<F, T> for P256<F, T, C>
 where
     F: Fq,
     T: Ford,
+    C: CurveParams<F>,
 {
     const INF: Self = P256 {
         x: F::ZERO,
@@ -73,23 +85,14 @@ where
         p: PhantomData,
     };
     const NBYTES: usize = 2 * F::NBYTES;
+    const NBYTES_COMPRESSED: usize = 1 + F::NBYTES;
+    const NBYTES_UNCOMPRESSED: usize = 1 + 2 * F::NBYTES;
 
     fn gen() -> Self {
+        let (x, y) = C::gen();
         P256 {
-            x: F::from_slice(&[
-                0x2815B16F81798,
-                0xDB2DCE28D959F,
-                0xE870B07029BFC,
-                0xBBAC55A06295C,
-                0x79BE667EF9DC,
-            ]),
-            y: F::from_slice(&[
-                0x7D08FFB10D4B8,
-                0x48A68554199C4,
-                0xE1108A8FD17B4,
-                0xC4655DA4FBFC0,
-                0x483ADA7726A3,
-            ]),
+            x,
+            y,
             z: F::ONE,
             p: PhantomData,
             inf: false,
@@ -106,9 +109,7 @@ where
     /// Should return a Result() that is the point, or an error if the
     /// x and y are not on the point
     fn from_xy(x: &F, y: &F) -> Result<Self, &'static str> {
-        let x2 = x.sqr();
-        let x3 = x2.mul(&x);
-        let mut lhs = x3.add(&F::from_slice(&[CURVE_B, 0, 0, 0, 0]));
+        let mut lhs = Self::curve_rhs(x);
         let mut rhs = y.sqr();
         lhs.normalize();
         rhs.normalize();
@@ -122,10 +123,32 @@ where
                 p: PhantomData,
             })
         } else {
-            Result::Err("Point is not on curve (y^2 = x^3 + 7)")
+            Result::Err(C::NOT_ON_CURVE)
         }
     }
 
+    fn from_x(x: &F, odd: bool) -> Result<Self, &'static str> {
+        let rhs = Self::curve_rhs(x);
+        let mut y = match rhs.sqrt() {
+            Ok(y) => y,
+            Err(_) => return Result::Err(C::NOT_ON_CURVE),
+        };
+        y.normalize();
+        if y.bit(0) != odd {
+            y = y.neg(1);
+        }
+        let mut x = *x;
+        x.normalize();
+
+        Result::Ok(P256 {
+            x,
+            y,
+            z: F::ONE,
+            inf: false,
+            p: PhantomData,
+        })
+    }
+
     // can also generate by hashing to the curve
     fn rand(rng: &mut dyn Rng) -> (T, Self) {
         let x = <T as Ford>::rand(rng);
@@ -149,8 +172,8 @@ where
             y.normalize();
 
             P256 {
-                x: x,
-                y: y,
+                x,
+                y,
                 z: F::ONE,
                 inf: self.inf,
                 p: PhantomData,
@@ -159,7 +182,6 @@ where
     }
 
     fn op(a: &Self, b: &Self) -> Self {
-        // algorithm 7 from https://eprint.iacr.org/2015/1060.pdf
         if b.inf {
             return P256 {
                 x: a.x,
@@ -177,64 +199,13 @@ where
                 p: PhantomData,
             };
         }
-
-        let t0 = a.x.mul(&b.x);
-        let t1 = a.y.mul(&b.y); // 2 t1 = y1 * y2
-        let t2 = a.z.mul(&b.z); // 3 t2 = z1 * Z2
-        let t3 = a.x.add(&a.y); // 4 t3 = X1 + Y1
-        let t4 = b.x.add(&b.y); // 5. t4 ← X2 + Y2
-        let t3 = t3.mul(&t4); // 6. t3 ← t3 · t4
-        let t4 = t0.add(&t1); // 7. t4 ← t0 + t1
-        let t3 = t3.add(&t4.neg(2)); // 8. t3 ← t3−t4
-        let t4 = a.y.add(&a.z); // 9. t4 ← Y1+Z1
-        let x3 = b.y.add(&b.z); // 10. X3 ← Y2+b.z
-        let t4 = t4.mul(&x3); // 11. t4 ← t4·X3
-        let x3 = t1.add(&t2); // 12. X3 ← t1+t2
-        let t4 = t4.add(&x3.neg(2)); // 13. t4 ← t4−x3
-        let x3 = a.x.add(&a.z); // 14. x3 ← X1+Z1
-        let y3 = b.x.add(&b.z); // 15. Y3 ← X2+Z2
-        let x3 = x3.mul(&y3); // 16. x3 ← x3·y3
-        let y3 = t0.add(&t2); // 17. y3 ← t0+t2
-        let y3 = x3.add(&y3.neg(2)); // 18. y3 ← x3−y3
-        let x3 = t0.add(&t0); // 19. x3 ← t0+t0
-        let t0 = x3.add(&t0); // 20. t0 ← x3+t0
-
-        let t2 = t2.muli(CURVE_B3); // 21. t2 ← b3·t2
-        let mut z3 = t1.add(&t2); // 22. Z3 ← t1+t2
-        let mut t1 = t1.add(&t2.neg(21)); // 23. t1 ← t1−t2
-        let mut y3 = y3.muli(CURVE_B3); // 24. y3 ← b3·y3
-        y3.normalize_weak();
-        z3.normalize_weak();
-
-        let x3 = t4.mul(&y3); // 25. x3 ← t4·y3
-        t1.normalize();
-        let t2 = t3.mul(&t1); // 26. t2 ← t3·t1
-
-        let mut x3 = t2.add(&x3.neg(1)); // 27. x3 ← t2−x3
-        let y3 = y3.mul(&t0); // 28. y3 ← y3·t0
-
-        let t1 = t1.mul(&z3); // 29. t1 ← t1·z3
-        let mut y3 = t1.add(&y3); // 30. y3 ← t1+y3
-
-        let t0 = t0.mul(&t3); // 31. t0 ← t0·t3
-        let z3 = z3.mul(&t4); // 32. z3 ← z3·t4
-        let mut z3 = z3.add(&t0); // 33. z3 ← z3+t0
-
-        x3.normalize();
-        y3.normalize();
-        z3.normalize();
-
-        let inf = x3.is_zero() && z3.is_zero();
-        P256 {
-            x: x3,
-            y: y3,
-            z: z3,
-            inf: inf,
-            p: PhantomData,
-        }
+        Self::op_complete(a, b)
     }
 
     fn dbl(&self) -> Self {
+        if C::A_IS_MINUS_3 {
+            return self.dbl_a3();
+        }
         let t0 = self.y.sqr(); // 1. t0 ← Y·Y
         let z3 = t0.add(&t0); // 2. Z3 ← t0+t0
         let z3 = z3.add(&z3); // 3. Z3 ← Z3+Z3
@@ -274,7 +245,7 @@ where
         let y = y.neg(1);
         P256 {
             x: self.x,
-            y: y,
+            y,
             z: self.z,
             inf: self.inf,
             p: PhantomData,
@@ -328,7 +299,7 @@ where
             x: r0.x,
             y: r0.y,
             z: r0.z,
-            inf: inf,
+            inf,
             p: PhantomData,
         };
     }
@@ -379,7 +350,7 @@ where
     }
 
     default fn scalar_gen(x: &T) -> Self {
-        Self::gen().scalar(x)
+        Self::gen().scalar_ct(x)
     }
 
     fn precomp_table(x: &Self) -> Vec<Self> {
@@ -392,10 +363,62 @@ where
         table
     }
 
+    /// Montgomery ladder that always performs one addition and one doubling per bit
+    fn scalar_ct(&self, x: &T) -> Self {
+        let mut r0 = Self::INF;
+        let mut r1 = *self;
+        if self.inf {
+            r1 = Self::INF;
+        }
+
+        for i in (0..T::NBITS).rev() {
+            let b = x.bit(i) as u32;
+            // if b==0 { r1 = r0 + r1; r0 = dbl(r0); }
+            // if b==1 { r0 = r0 + r1; r1 = dbl(r1); }
+            F::swap(&mut r0.x, &mut r1.x, b);
+            F::swap(&mut r0.y, &mut r1.y, b);
+            F::swap(&mut r0.z, &mut r1.z, b);
+            r1 = Self::op_complete(&r0, &r1);
+            r0 = r0.dbl();
+            F::swap(&mut r0.x, &mut r1.x, b);
+            F::swap(&mut r0.y, &mut r1.y, b);
+            F::swap(&mut r0.z, &mut r1.z, b);
+        }
+
+        r0.with_inf_from_coords().affine()
+    }
+
+    /// adds every entry of the table to an accumulator, and keeps the sum only where the
+    /// scalar has a one bit. This is several times slower than scalar_table_multi
+    fn scalar_table_multi_ct(table: &[Self], x: &T) -> Self {
+        let mut acc = Self::INF;
+        for i in 0..T::NBITS {
+            let sum = Self::op_complete(&acc, &table[i]);
+            acc.mov_point(&sum, x.bit(i));
+        }
+
+        acc.with_inf_from_coords().affine()
+    }
+
+    fn multi_scalar_mul(points: &[Self], scalars: &[T]) -> Self {
+        assert_eq!(points.len(), scalars.len());
+        if points.len() < PIPPENGER_THRESHOLD {
+            Self::strauss(points, scalars)
+        } else {
+            Self::pippenger(points, scalars)
+        }
+    }
+
     /// self needs to be normalized before calling this method
     fn to_bytes(&self, b: &mut [u8]) {
-        self.x.to_bytes(&mut b[0..32]);
-        self.y.to_bytes(&mut b[32..64]);
+        if self.inf {
+            for v in b[0..Self::NBYTES].iter_mut() {
+                *v = 0;
+            }
+        } else {
+            self.x.to_bytes(&mut b[0..32]);
+            self.y.to_bytes(&mut b[32..64]);
+        }
     }
 
     fn from_bytes(b: &[u8]) -> Self {
@@ -403,13 +426,118 @@ where
         let y = F::from_bytes(&b[32..64]);
 
         P256 {
-            x: x,
-            y: y,
+            x,
+            y,
             z: F::ONE,
             inf: false,
             p: PhantomData,
         }
     }
+
+    fn try_from_bytes(b: &[u8]) -> Result<Self, &'static str> {
+        if b.len() < Self::NBYTES {
+            return Err("Point encoding is too short");
+        }
+        if b[0..Self::NBYTES].iter().all(|&v| v == 0) {
+            return Ok(Self::INF);
+        }
+        let x = field_from_bytes::<F>(&b[0..F::NBYTES])?;
+        let y = field_from_bytes::<F>(&b[F::NBYTES..Self::NBYTES])?;
+        Self::from_xy(&x, &y)
+    }
+
+    /// self needs to be normalized before calling this method
+    fn to_bytes_compressed(&self, b: &mut [u8]) {
+        if self.inf {
+            for v in b[0..Self::NBYTES_COMPRESSED].iter_mut() {
+                *v = 0;
+            }
+        } else {
+            b[0] = if self.y.bit(0) { 0x03 } else { 0x02 };
+            self.x.to_bytes(&mut b[1..Self::NBYTES_COMPRESSED]);
+        }
+    }
+
+    fn from_bytes_compressed(b: &[u8]) -> Result<Self, &'static str> {
+        if b.len() < Self::NBYTES_COMPRESSED {
+            return Err("Compressed point encoding is too short");
+        }
+        match b[0] {
+            0x00 if b[1..Self::NBYTES_COMPRESSED].iter().all(|&v| v == 0) => Ok(Self::INF),
+            0x02 | 0x03 => {
+                let x = field_from_bytes::<F>(&b[1..Self::NBYTES_COMPRESSED])?;
+                Self::from_x(&x, b[0] == 0x03)
+            }
+            _ => Err("Compressed point encoding has an invalid prefix"),
+        }
+    }
+
+    /// self needs to be normalized before calling this method
+    fn to_bytes_uncompressed(&self, b: &mut [u8]) {
+        if self.inf {
+            for v in b[0..Self::NBYTES_UNCOMPRESSED].iter_mut() {
+                *v = 0;
+            }
+        } else {
+            b[0] = 0x04;
+            self.to_bytes(&mut b[1..Self::NBYTES_UNCOMPRESSED]);
+        }
+    }
+
+    fn from_bytes_uncompressed(b: &[u8]) -> Result<Self, &'static str> {
+        if b.len() < Self::NBYTES_UNCOMPRESSED {
+            return Err("Uncompressed point encoding is too short");
+        }
+        match b[0] {
+            0x00 if b[1..Self::NBYTES_UNCOMPRESSED].iter().all(|&v| v == 0) => Ok(Self::INF),
+            0x04 => {
+                let x = field_from_bytes::<F>(&b[1..(1 + F::NBYTES)])?;
+                let y = field_from_bytes::<F>(&b[(1 + F::NBYTES)..Self::NBYTES_UNCOMPRESSED])?;
+                Self::from_xy(&x, &y)
+            }
+            _ => Err("Uncompressed point encoding has an invalid prefix"),
+        }
+    }
+}
+
+impl<F: Fq> CurveParams<F> for Secp256k1Params {
+    const A_IS_MINUS_3: bool = false;
+    const NOT_ON_CURVE: &'static str = "Point is not on curve (y^2 = x^3 + 7)";
+
+    fn b() -> F {
+        F::from_slice(&[CURVE_B, 0, 0, 0, 0])
+    }
+
+    fn gen() -> (F, F) {
+        (
+            F::from_slice(&[
+                0x2815B16F81798,
+                0xDB2DCE28D959F,
+                0xE870B07029BFC,
+                0xBBAC55A06295C,
+                0x79BE667EF9DC,
+            ]),
+            F::from_slice(&[
+                0x7D08FFB10D4B8,
+                0x48A68554199C4,
+                0xE1108A8FD17B4,
+                0xC4655DA4FBFC0,
+                0x483ADA7726A3,
+            ]),
+        )
+    }
+}
+
+// parses a big-endian field element, rejecting values that are not less than the modulus
+fn field_from_bytes<F: Fq>(b: &[u8]) -> Result<F, &'static str> {
+    let mut x = F::from_bytes(b);
+    x.normalize();
+    let mut xb = [0u8; 32];
+    x.to_bytes(&mut xb);
+    if xb[..] != b[..] {
+        return Err("Coordinate is not less than the field modulus");
+    }
+    Ok(x)
 }
 
 //Note: this will hopefully work when Rust gets specialization right, but not yet.
@@ -419,18 +547,285 @@ where
     }
 }*/
 
-impl<F, T> P256<F, T>
+impl<F, T, C> P256<F, T, C>
 where
     F: Fq,
     T: Ford,
+    C: CurveParams<F>,
 {
     // fast scalar
     // fast multi-exp
 
-    //	const table: [ P256<F, T>; 5] = tab();
+    //	const table: [ P256<F, T, C>; 5] = tab();
+
+    // algorithm 7 from https://eprint.iacr.org/2015/1060.pdf. The formula is complete, so it needs
+    // no special case for the point at infinity, as long as that point is represented as (0:1:0)
+    fn op_complete(a: &P256<F, T, C>, b: &P256<F, T, C>) -> P256<F, T, C> {
+        if C::A_IS_MINUS_3 {
+            return Self::op_complete_a3(a, b);
+        }
+        let t0 = a.x.mul(&b.x);
+        let t1 = a.y.mul(&b.y); // 2 t1 = y1 * y2
+        let t2 = a.z.mul(&b.z); // 3 t2 = z1 * Z2
+        let t3 = a.x.add(&a.y); // 4 t3 = X1 + Y1
+        let t4 = b.x.add(&b.y); // 5. t4 ← X2 + Y2
+        let t3 = t3.mul(&t4); // 6. t3 ← t3 · t4
+        let t4 = t0.add(&t1); // 7. t4 ← t0 + t1
+        let t3 = t3.add(&t4.neg(2)); // 8. t3 ← t3−t4
+        let t4 = a.y.add(&a.z); // 9. t4 ← Y1+Z1
+        let x3 = b.y.add(&b.z); // 10. X3 ← Y2+b.z
+        let t4 = t4.mul(&x3); // 11. t4 ← t4·X3
+        let x3 = t1.add(&t2); // 12. X3 ← t1+t2
+        let t4 = t4.add(&x3.neg(2)); // 13. t4 ← t4−x3
+        let x3 = a.x.add(&a.z); // 14. x3 ← X1+Z1
+        let y3 = b.x.add(&b.z); // 15. Y3 ← X2+Z2
+        let x3 = x3.mul(&y3); // 16. x3 ← x3·y3
+        let y3 = t0.add(&t2); // 17. y3 ← t0+t2
+        let y3 = x3.add(&y3.neg(2)); // 18. y3 ← x3−y3
+        let x3 = t0.add(&t0); // 19. x3 ← t0+t0
+        let t0 = x3.add(&t0); // 20. t0 ← x3+t0
+
+        let t2 = t2.muli(CURVE_B3); // 21. t2 ← b3·t2
+        let mut z3 = t1.add(&t2); // 22. Z3 ← t1+t2
+        let mut t1 = t1.add(&t2.neg(21)); // 23. t1 ← t1−t2
+        let mut y3 = y3.muli(CURVE_B3); // 24. y3 ← b3·y3
+        y3.normalize_weak();
+        z3.normalize_weak();
+
+        let x3 = t4.mul(&y3); // 25. x3 ← t4·y3
+        t1.normalize();
+        let t2 = t3.mul(&t1); // 26. t2 ← t3·t1
+
+        let mut x3 = t2.add(&x3.neg(1)); // 27. x3 ← t2−x3
+        let y3 = y3.mul(&t0); // 28. y3 ← y3·t0
+
+        let t1 = t1.mul(&z3); // 29. t1 ← t1·z3
+        let mut y3 = t1.add(&y3); // 30. y3 ← t1+y3
+
+        let t0 = t0.mul(&t3); // 31. t0 ← t0·t3
+        let z3 = z3.mul(&t4); // 32. z3 ← z3·t4
+        let mut z3 = z3.add(&t0); // 33. z3 ← z3+t0
+
+        x3.normalize();
+        y3.normalize();
+        z3.normalize();
+
+        let inf = x3.is_zero() && z3.is_zero();
+        P256 {
+            x: x3,
+            y: y3,
+            z: z3,
+            inf,
+            p: PhantomData,
+        }
+    }
+
+    // algorithm 4 from https://eprint.iacr.org/2015/1060.pdf, the complete addition formula for
+    // a = -3. The field must keep its elements reduced, since this uses sub rather than neg
+    fn op_complete_a3(a: &P256<F, T, C>, b: &P256<F, T, C>) -> P256<F, T, C> {
+        let cb = C::b();
+        let t0 = a.x.mul(&b.x); // 1. t0 ← X1·X2
+        let t1 = a.y.mul(&b.y); // 2. t1 ← Y1·Y2
+        let t2 = a.z.mul(&b.z); // 3. t2 ← Z1·Z2
+        let t3 = a.x.add(&a.y); // 4. t3 ← X1+Y1
+        let t4 = b.x.add(&b.y); // 5. t4 ← X2+Y2
+        let t3 = t3.mul(&t4); // 6. t3 ← t3·t4
+        let t4 = t0.add(&t1); // 7. t4 ← t0+t1
+        let t3 = t3.sub(&t4); // 8. t3 ← t3−t4
+        let t4 = a.y.add(&a.z); // 9. t4 ← Y1+Z1
+        let x3 = b.y.add(&b.z); // 10. X3 ← Y2+Z2
+        let t4 = t4.mul(&x3); // 11. t4 ← t4·X3
+        let x3 = t1.add(&t2); // 12. X3 ← t1+t2
+        let t4 = t4.sub(&x3); // 13. t4 ← t4−X3
+        let x3 = a.x.add(&a.z); // 14. X3 ← X1+Z1
+        let y3 = b.x.add(&b.z); // 15. Y3 ← X2+Z2
+        let x3 = x3.mul(&y3); // 16. X3 ← X3·Y3
+        let y3 = t0.add(&t2); // 17. Y3 ← t0+t2
+        let y3 = x3.sub(&y3); // 18. Y3 ← X3−Y3
+        let z3 = cb.mul(&t2); // 19. Z3 ← b·t2
+        let x3 = y3.sub(&z3); // 20. X3 ← Y3−Z3
+        let z3 = x3.add(&x3); // 21. Z3 ← X3+X3
+        let x3 = x3.add(&z3); // 22. X3 ← X3+Z3
+        let z3 = t1.sub(&x3); // 23. Z3 ← t1−X3
+        let x3 = t1.add(&x3); // 24. X3 ← t1+X3
+        let y3 = cb.mul(&y3); // 25. Y3 ← b·Y3
+        let t1 = t2.add(&t2); // 26. t1 ← t2+t2
+        let t2 = t1.add(&t2); // 27. t2 ← t1+t2
+        let y3 = y3.sub(&t2); // 28. Y3 ← Y3−t2
+        let y3 = y3.sub(&t0); // 29. Y3 ← Y3−t0
+        let t1 = y3.add(&y3); // 30. t1 ← Y3+Y3
+        let y3 = t1.add(&y3); // 31. Y3 ← t1+Y3
+        let t1 = t0.add(&t0); // 32. t1 ← t0+t0
+        let t0 = t1.add(&t0); // 33. t0 ← t1+t0
+        let t0 = t0.sub(&t2); // 34. t0 ← t0−t2
+        let t1 = t4.mul(&y3); // 35. t1 ← t4·Y3
+        let t2 = t0.mul(&y3); // 36. t2 ← t0·Y3
+        let y3 = x3.mul(&z3); // 37. Y3 ← X3·Z3
+        let y3 = y3.add(&t2); // 38. Y3 ← Y3+t2
+        let x3 = t3.mul(&x3); // 39. X3 ← t3·X3
+        let x3 = x3.sub(&t1); // 40. X3 ← X3−t1
+        let z3 = t4.mul(&z3); // 41. Z3 ← t4·Z3
+        let t1 = t3.mul(&t0); // 42. t1 ← t3·t0
+        let z3 = z3.add(&t1); // 43. Z3 ← Z3+t1
+
+        let inf = x3.is_zero() && z3.is_zero();
+        P256 {
+            x: x3,
+            y: y3,
+            z: z3,
+            inf,
+            p: PhantomData,
+        }
+    }
+
+    // algorithm 6 from https://eprint.iacr.org/2015/1060.pdf, doubling for a = -3
+    fn dbl_a3(&self) -> P256<F, T, C> {
+        let cb = C::b();
+        let t0 = self.x.sqr(); // 1. t0 ← X·X
+        let t1 = self.y.sqr(); // 2. t1 ← Y·Y
+        let t2 = self.z.sqr(); // 3. t2 ← Z·Z
+        let t3 = self.x.mul(&self.y); // 4. t3 ← X·Y
+        let t3 = t3.add(&t3); // 5. t3 ← t3+t3
+        let z3 = self.x.mul(&self.z); // 6. Z3 ← X·Z
+        let z3 = z3.add(&z3); // 7. Z3 ← Z3+Z3
+        let y3 = cb.mul(&t2); // 8. Y3 ← b·t2
+        let y3 = y3.sub(&z3); // 9. Y3 ← Y3−Z3
+        let x3 = y3.add(&y3); // 10. X3 ← Y3+Y3
+        let y3 = x3.add(&y3); // 11. Y3 ← X3+Y3
+        let x3 = t1.sub(&y3); // 12. X3 ← t1−Y3
+        let y3 = t1.add(&y3); // 13. Y3 ← t1+Y3
+        let y3 = x3.mul(&y3); // 14. Y3 ← X3·Y3
+        let x3 = x3.mul(&t3); // 15. X3 ← X3·t3
+        let t3 = t2.add(&t2); // 16. t3 ← t2+t2
+        let t2 = t2.add(&t3); // 17. t2 ← t2+t3
+        let z3 = cb.mul(&z3); // 18. Z3 ← b·Z3
+        let z3 = z3.sub(&t2); // 19. Z3 ← Z3−t2
+        let z3 = z3.sub(&t0); // 20. Z3 ← Z3−t0
+        let t3 = z3.add(&z3); // 21. t3 ← Z3+Z3
+        let z3 = z3.add(&t3); // 22. Z3 ← Z3+t3
+        let t3 = t0.add(&t0); // 23. t3 ← t0+t0
+        let t0 = t3.add(&t0); // 24. t0 ← t3+t0
+        let t0 = t0.sub(&t2); // 25. t0 ← t0−t2
+        let t0 = t0.mul(&z3); // 26. t0 ← t0·Z3
+        let y3 = y3.add(&t0); // 27. Y3 ← Y3+t0
+        let t0 = self.y.mul(&self.z); // 28. t0 ← Y·Z
+        let t0 = t0.add(&t0); // 29. t0 ← t0+t0
+        let z3 = t0.mul(&z3); // 30. Z3 ← t0·Z3
+        let x3 = x3.sub(&z3); // 31. X3 ← X3−Z3
+        let z3 = t0.mul(&t1); // 32. Z3 ← t0·t1
+        let z3 = z3.add(&z3); // 33. Z3 ← Z3+Z3
+        let z3 = z3.add(&z3); // 34. Z3 ← Z3+Z3
+
+        P256 {
+            x: x3,
+            y: y3,
+            z: z3,
+            inf: self.inf,
+            p: PhantomData,
+        }
+    }
+
+    // x^3 + ax + b
+    fn curve_rhs(x: &F) -> F {
+        let x3 = x.sqr().mul(x);
+        if C::A_IS_MINUS_3 {
+            x3.sub(&x.muli(3)).add(&C::b())
+        } else {
+            x3.add(&C::b())
+        }
+    }
+
+    // the infinity flag is not maintained by the constant time routines, so it is recomputed
+    // from the coordinates once they are done
+    fn with_inf_from_coords(mut self) -> P256<F, T, C> {
+        self.inf = self.x.is_zero() && self.z.is_zero();
+        if self.inf {
+            self.y = F::ONE;
+        }
+        self
+    }
+
+    fn mov_point(&mut self, a: &P256<F, T, C>, sel: bool) {
+        self.x.mov(&a.x, sel);
+        self.y.mov(&a.y, sel);
+        self.z.mov(&a.z, sel);
+    }
+
+    // Strauss-Shamir: every point gets a table of its first 16 multiples, and all of the
+    // scalars share a single chain of doublings, 4 bits at a time
+    fn strauss(points: &[P256<F, T, C>], scalars: &[T]) -> P256<F, T, C> {
+        let tables: Vec<[P256<F, T, C>; 16]> = points
+            .iter()
+            .map(|p| {
+                let mut table = [P256::INF; 16];
+                for k in 1..16 {
+                    table[k] = P256::op(&table[k - 1], p);
+                }
+                table
+            })
+            .collect();
+
+        let mut acc = P256::INF;
+        for j in (0..(T::NBITS / 4)).rev() {
+            acc = acc.dbl().dbl().dbl().dbl();
+            for (table, x) in tables.iter().zip(scalars.iter()) {
+                let w = x.get_window(j) as usize;
+                if w != 0 {
+                    acc = P256::op(&acc, &table[w]);
+                }
+            }
+        }
+        acc.affine()
+    }
+
+    // Pippenger's bucket method: for each window of c bits, every point is added into the
+    // bucket of its digit, and the buckets are summed with a running sum so that bucket d
+    // is counted d times
+    fn pippenger(points: &[P256<F, T, C>], scalars: &[T]) -> P256<F, T, C> {
+        let mut c = 2;
+        while (1usize << (c + 2)) < points.len() {
+            c += 1;
+        }
+
+        let mut acc = P256::INF;
+        for w in (0..((T::NBITS + c - 1) / c)).rev() {
+            for _ in 0..c {
+                acc = acc.dbl();
+            }
+
+            let mut buckets = vec![P256::INF; (1 << c) - 1];
+            for (p, x) in points.iter().zip(scalars.iter()) {
+                let mut d = 0;
+                for k in 0..c {
+                    let i = w * c + k;
+                    if i < T::NBITS && x.bit(i) {
+                        d |= 1 << k;
+                    }
+                }
+                if d != 0 {
+                    buckets[d - 1] = P256::op(&buckets[d - 1], p);
+                }
+            }
+
+            let mut running = P256::INF;
+            let mut sum = P256::INF;
+            for bucket in buckets.iter().rev() {
+                running = P256::op(&running, bucket);
+                sum = P256::op(&sum, &running);
+            }
+            acc = P256::op(&acc, &sum);
+        }
+        acc.affine()
+    }
 
     // fn hash_to_curve(message: &[u8]) -> Self;
+}
 
+impl<F, T> P256<F, T, Secp256k1Params>
+where
+    F: Fq,
+    T: Ford,
+{
     // This method is taken from the C language secp256k1 implementation
     // to compare speed.  It is not a constant time operation.
     pub fn op2(a: &P256<F, T>, b: &P256<F, T>) -> P256<F, T> {
@@ -1074,6 +1469,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_scalar_ct() {
+        let mut rng = rand::os::OsRng::new().unwrap();
+        let (_, h): (FSecp256Ord, P256<FSecp256, FSecp256Ord>) = P256::rand(&mut rng);
+        let table_h = P256::precomp_table(&h);
+
+        for _ in 0..N {
+            let x = FSecp256Ord::rand(&mut rng);
+            let expected = P256::scalar_table_multi(&table_h[..], &x);
+            assert!(h.scalar_ct(&x) == expected);
+            assert!(P256::scalar_table_multi_ct(&table_h[..], &x) == expected);
+        }
+
+        // the ladder and the table both pass through the point at infinity
+        assert!(h.scalar_ct(&FSecp256Ord::ZERO).is_infinity());
+        assert!(P256::scalar_table_multi_ct(&table_h[..], &FSecp256Ord::ZERO).is_infinity());
+        let inf: P256<FSecp256, FSecp256Ord> = P256::INF;
+        assert!(inf.scalar_ct(&FSecp256Ord::rand(&mut rng)).is_infinity());
+        let minus_one = FSecp256Ord::ONE.neg();
+        assert!(h.scalar_ct(&minus_one) == h.neg().affine());
+        assert!(P256::scalar_table_multi_ct(&table_h[..], &minus_one) == h.neg().affine());
+    }
+
+    #[test]
+    fn test_multi_scalar_mul() {
+        let mut rng = rand::os::OsRng::new().unwrap();
+
+        // both sides of the threshold, so that both Strauss-Shamir and Pippenger are exercised
+        for &n in [0, 1, 2, 5, PIPPENGER_THRESHOLD, PIPPENGER_THRESHOLD + 37].iter() {
+            let mut points = Vec::with_capacity(n);
+            let mut scalars = Vec::with_capacity(n);
+            let mut expected: P256<FSecp256, FSecp256Ord> = P256::INF;
+            for ii in 0..n {
+                // include a zero scalar and the point at infinity
+                let p = if ii == 2 {
+                    P256::INF
+                } else {
+                    P256::rand(&mut rng).1
+                };
+                let x = if ii == 1 {
+                    FSecp256Ord::ZERO
+                } else {
+                    FSecp256Ord::rand(&mut rng)
+                };
+                expected = P256::op(&expected, &p.scalar(&x));
+                points.push(p);
+                scalars.push(x);
+            }
+            assert!(P256::multi_scalar_mul(&points[..], &scalars[..]) == expected.affine());
+        }
+    }
+
     #[bench]
     fn bench_dbl(b: &mut Bencher) {
         let g: P256<FSecp256, FSecp256Ord> = P256::gen();
@@ -1144,6 +1591,85 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_sec1_encoding() {
+        type Point = P256<FSecp256, FSecp256Ord>;
+        let mut rng = rand::os::OsRng::new().unwrap();
+
+        // the generator has an even y coordinate
+        let mut gb = [0u8; 65];
+        Point::gen().to_bytes_uncompressed(&mut gb);
+        assert_eq!(gb[0], 0x04);
+        let mut gcb = [0u8; 33];
+        Point::gen().to_bytes_compressed(&mut gcb);
+        assert_eq!(gcb[0], 0x02);
+        assert_eq!(gcb[1..], gb[1..33]);
+
+        for _ in 0..16 {
+            let (_, p): (FSecp256Ord, Point) = Point::rand(&mut rng);
+            let mut cb = [0u8; 33];
+            p.to_bytes_compressed(&mut cb);
+            assert_eq!(Point::from_bytes_compressed(&cb).unwrap(), p);
+            let mut ub = [0u8; 65];
+            p.to_bytes_uncompressed(&mut ub);
+            assert_eq!(Point::from_bytes_uncompressed(&ub).unwrap(), p);
+        }
+
+        let mut infb = [0xffu8; 65];
+        Point::INF.to_bytes_compressed(&mut infb);
+        assert!(Point::from_bytes_compressed(&infb).unwrap().is_infinity());
+        Point::INF.to_bytes_uncompressed(&mut infb);
+        assert!(Point::from_bytes_uncompressed(&infb).unwrap().is_infinity());
+
+        // invalid prefixes, points off the curve, and coordinates that are too large are rejected
+        gcb[0] = 0x04;
+        assert!(Point::from_bytes_compressed(&gcb).is_err());
+        gb[64] ^= 1;
+        assert!(Point::from_bytes_uncompressed(&gb).is_err());
+        let mut offcurve = [0u8; 33];
+        offcurve[0] = 0x02;
+        let x = "EEFDEA4CDB677750A420FEE807EACF21EB9898AE79B9768766E4FAA04A2D4A34";
+        for ii in 0..32 {
+            offcurve[ii + 1] = u8::from_str_radix(&x[(2 * ii)..(2 * ii + 2)], 16).unwrap();
+        }
+        assert!(Point::from_bytes_compressed(&offcurve).is_err());
+        for ii in 1..33 {
+            offcurve[ii] = 0xff;
+        }
+        assert!(Point::from_bytes_compressed(&offcurve).is_err());
+    }
+
+    #[test]
+    fn test_try_from_bytes() {
+        type Point = P256<FSecp256, FSecp256Ord>;
+        let mut rng = rand::os::OsRng::new().unwrap();
+
+        for _ in 0..16 {
+            let (_, p): (FSecp256Ord, Point) = Point::rand(&mut rng);
+            let mut b = [0u8; 64];
+            p.affine().to_bytes(&mut b);
+            assert_eq!(Point::try_from_bytes(&b).unwrap(), p);
+
+            // flipping a bit of y moves the point off the curve
+            b[63] ^= 1;
+            assert!(Point::try_from_bytes(&b).is_err());
+        }
+
+        let mut infb = [0xffu8; 64];
+        Point::INF.to_bytes(&mut infb);
+        assert!(infb.iter().all(|&v| v == 0));
+        assert!(Point::try_from_bytes(&infb).unwrap().is_infinity());
+
+        // coordinates that are not less than the field modulus are rejected
+        let mut b = [0u8; 64];
+        Point::gen().to_bytes(&mut b);
+        for ii in 0..32 {
+            b[ii] = 0xff;
+        }
+        assert!(Point::try_from_bytes(&b).is_err());
+        assert!(Point::try_from_bytes(&b[0..32]).is_err());
+    }
+
     #[bench]
     fn bench_scalar_gen(b: &mut Bencher) {
         let mut rng = rand::os::OsRng::new().unwrap();
@@ -0,0 +1,170 @@
+/// NIST P-256, also known as secp256r1 or prime256v1
+///
+/// Curve equation y^2 = x^3 - 3x + b, with
+/// b = 0x5AC635D8AA3A93E7B3EBBD55769886BC651D06B0CC53B0F63BCE3C3E27D2604B
+///
+/// The field is mod
+/// 115792089210356248762697446949407573530086143415290314195533631308867097853951
+///
+/// Generator is
+/// (0x6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296, 0x4FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5, 1)
+///
+/// The group law is the one in secp256k1.rs, with the formulas for a = -3
+use super::f_ffff::FP256;
+use super::{CurveParams, Fq};
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Secp256r1Params;
+
+impl CurveParams<FP256> for Secp256r1Params {
+    const A_IS_MINUS_3: bool = true;
+    const NOT_ON_CURVE: &'static str = "Point is not on curve (y^2 = x^3 - 3x + b)";
+
+    fn b() -> FP256 {
+        FP256::from_slice(&[
+            0x3BCE3C3E27D2604B,
+            0x651D06B0CC53B0F6,
+            0xB3EBBD55769886BC,
+            0x5AC635D8AA3A93E7,
+        ])
+    }
+
+    fn gen() -> (FP256, FP256) {
+        (
+            FP256::from_slice(&[
+                0xF4A13945D898C296,
+                0x77037D812DEB33A0,
+                0xF8BCE6E563A440F2,
+                0x6B17D1F2E12C4247,
+            ]),
+            FP256::from_slice(&[
+                0xCBB6406837BF51F5,
+                0x2BCE33576B315ECE,
+                0x8EE7EB4A7C0F9E16,
+                0x4FE342E2FE1A7F9B,
+            ]),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{ecdsa, precomp, ECGroup, Ford, SecpR1, SecpR1Ord};
+    extern crate rand;
+
+    fn bytes(s: &str) -> Vec<u8> {
+        (0..s.len() / 2)
+            .map(|i| u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn point(x: &str, y: &str) -> SecpR1 {
+        let mut b = bytes(x);
+        b.extend(bytes(y));
+        SecpR1::try_from_bytes(&b).unwrap()
+    }
+
+    #[test]
+    fn test_p256_group() {
+        let g = SecpR1::gen();
+        let g2 = point(
+            "7cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc47669978",
+            "07775510db8ed040293d9ac69f7430dbba7dade63ce982299e04b79d227873d1",
+        );
+        assert!(SecpR1::op(&g, &g).affine() == g2);
+        assert!(g.dbl().affine() == g2);
+        assert!(SecpR1::op(&g2, &g.neg()).affine() == g);
+        assert!(SecpR1::op(&g, &g.neg()).affine().is_infinity());
+        assert!(SecpR1::op(&g, &SecpR1::INF).affine() == g);
+        assert!(SecpR1::INF.dbl().affine().is_infinity());
+
+        // (n-1)*G = -G
+        let minus_one = SecpR1Ord::ZERO.sub(&SecpR1Ord::ONE);
+        assert!(g.scalar(&minus_one).affine() == g.neg().affine());
+        assert!(g.scalar_ct(&minus_one) == g.neg().affine());
+    }
+
+    #[test]
+    fn test_p256_scalar() {
+        let k = SecpR1Ord::from_bytes(&bytes(
+            "8e1c4a2a2e0b5a0d3e7d7c2f1b2a39485f6e7d8c9bab0c1d2e3f405162738495",
+        ));
+        let expected = point(
+            "3fb6858b0cc3c3a8b08287ebdba014226fd4da610f74cd79bee281a7f3aeee54",
+            "1e17f437cbd7708334e2a4264d77801bb3f1f4114783a030ab95a4cea319ad12",
+        );
+        let g = SecpR1::gen();
+        assert!(g.scalar(&k).affine() == expected);
+        assert!(g.scalar_ct(&k) == expected);
+        assert!(SecpR1::scalar_gen(&k) == expected);
+        assert!(SecpR1::scalar_table_multi(&precomp::SECP256R1_TABLE[..], &k) == expected);
+        assert!(SecpR1::scalar_table_multi_ct(&precomp::SECP256R1_TABLE[..], &k) == expected);
+
+        let mut rng = rand::os::OsRng::new().unwrap();
+        let (x, p) = SecpR1::rand(&mut rng);
+        let y = SecpR1Ord::rand(&mut rng);
+        let sum = SecpR1::op(&p.scalar(&y), &SecpR1::scalar_gen(&x.mul(&x))).affine();
+        assert!(SecpR1::multi_scalar_mul(&[g, p], &[x.mul(&x), y]) == sum);
+    }
+
+    #[test]
+    fn test_p256_table() {
+        let table = SecpR1::precomp_table(&SecpR1::gen());
+        for i in 0..256 {
+            assert!(table[i].affine() == precomp::SECP256R1_TABLE[i]);
+        }
+    }
+
+    #[test]
+    fn test_p256_encoding() {
+        let mut rng = rand::os::OsRng::new().unwrap();
+        let (_, p) = SecpR1::rand(&mut rng);
+
+        let mut b = [0u8; 33];
+        p.to_bytes_compressed(&mut b);
+        assert!(SecpR1::from_bytes_compressed(&b).unwrap() == p);
+        let mut b = [0u8; 65];
+        p.to_bytes_uncompressed(&mut b);
+        assert!(SecpR1::from_bytes_uncompressed(&b).unwrap() == p);
+
+        // (1, 2) is not on the curve
+        let mut b = [0u8; 64];
+        b[31] = 1;
+        b[63] = 2;
+        assert!(SecpR1::try_from_bytes(&b).is_err());
+    }
+
+    #[test]
+    fn test_p256_ecdsa() {
+        // RFC 6979, A.2.5, with SHA-256 and the message "sample"
+        let pk = point(
+            "60fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6",
+            "7903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299",
+        );
+        let sk = SecpR1Ord::from_bytes(&bytes(
+            "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721",
+        ));
+        let r = SecpR1Ord::from_bytes(&bytes(
+            "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716",
+        ));
+        let s = SecpR1Ord::from_bytes(&bytes(
+            "f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8",
+        ));
+        assert!(SecpR1::scalar_gen(&sk) == pk);
+        assert!(ecdsa::ecdsa_verify(b"sample", (&r, &s), &pk));
+        assert!(!ecdsa::ecdsa_verify(b"samples", (&r, &s), &pk));
+
+        let mut rng = rand::os::OsRng::new().unwrap();
+        let (sk, pk): (SecpR1Ord, SecpR1) = ecdsa::ecdsa_keygen(&mut rng);
+        let msg = b"this is a random test message that is long";
+        let (r, s) = ecdsa::ecdsa_sign::<_, _, SecpR1>(msg, &sk, &mut rng);
+        assert!(ecdsa::ecdsa_verify(msg, (&r, &s), &pk));
+        assert!(ecdsa::ecdsa_verify_with_tables(
+            msg,
+            (&r, &s),
+            &precomp::SECP256R1_TABLE[..],
+            &SecpR1::precomp_table(&pk)[..]
+        ));
+        assert!(!ecdsa::ecdsa_verify(msg, (&r, &s), &SecpR1::gen()));
+    }
+}
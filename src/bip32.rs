@@ -0,0 +1,250 @@
+/***********
+ * This module implements BIP-32 non-hardened derivation of child public keys
+ * (https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki).
+ *
+ * Hardened derivation requires the secret key, which no party holds, so only
+ * public derivation is supported. Each derived key records the sum of the tweaks
+ * from the root key; signers add it to the root key when signing with the child.
+ ***********/
+
+use crypto::digest::Digest;
+use crypto::hmac::Hmac;
+use crypto::mac::Mac;
+use crypto::ripemd160::Ripemd160;
+use crypto::sha2::{Sha256, Sha512};
+
+use curves::{ECGroup, Ford, Secp, SecpOrd};
+
+use super::mpecdsa_error::*;
+
+pub const CHAINCODE_SIZE: usize = 32;
+// indices at or above this value denote hardened derivation
+pub const HARDENED_INDEX: u32 = 1 << 31;
+pub const XPUB_SIZE: usize = 78;
+const XPUB_VERSION: [u8; 4] = [0x04, 0x88, 0xB2, 0x1E];
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExtendedPublicKey {
+    depth: u8,
+    parent_fingerprint: [u8; 4],
+    child_number: u32,
+    chaincode: [u8; CHAINCODE_SIZE],
+    pk: Secp,
+    tweak: SecpOrd,
+}
+
+impl ExtendedPublicKey {
+    // creates the master extended key for a root public key
+    pub fn new(pk: &Secp, chaincode: &[u8; CHAINCODE_SIZE]) -> ExtendedPublicKey {
+        ExtendedPublicKey {
+            depth: 0,
+            parent_fingerprint: [0u8; 4],
+            child_number: 0,
+            chaincode: *chaincode,
+            pk: pk.affine(),
+            tweak: SecpOrd::ZERO,
+        }
+    }
+
+    pub fn pk(&self) -> Secp {
+        self.pk
+    }
+
+    pub fn chaincode(&self) -> &[u8; CHAINCODE_SIZE] {
+        &self.chaincode
+    }
+
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    pub fn child_number(&self) -> u32 {
+        self.child_number
+    }
+
+    // the discrete log of pk - root pk, which must be added to the root secret key to sign
+    pub fn tweak(&self) -> SecpOrd {
+        self.tweak
+    }
+
+    pub fn derive_child(&self, index: u32) -> Result<ExtendedPublicKey, MPECDSAError> {
+        if index >= HARDENED_INDEX {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Hardened derivation is not possible without the secret key",
+            )));
+        }
+        if self.depth == u8::MAX {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Derivation path is too long",
+            )));
+        }
+
+        // I = HMAC-SHA512(chaincode, ser_P(pk) || ser_32(index))
+        let mut data = [0u8; 33 + 4];
+        data[0..33].copy_from_slice(&compressed(&self.pk));
+        data[33..].copy_from_slice(&index.to_be_bytes());
+        let mut hmac = Hmac::new(Sha512::new(), &self.chaincode);
+        hmac.input(&data);
+        let mut i = [0u8; 64];
+        hmac.raw_result(&mut i);
+
+        // I_L must be less than the group order, and the child must not be the point at infinity
+        let il = SecpOrd::from_bytes(&i[0..32]);
+        let mut ilraw = [0u8; SecpOrd::NBYTES];
+        il.to_bytes(&mut ilraw);
+        let pk = Secp::op(&Secp::scalar_gen(&il), &self.pk).affine();
+        if ilraw[..] != i[0..32] || pk.is_infinity() {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Derived key is invalid; the next index should be used instead",
+            )));
+        }
+
+        let mut chaincode = [0u8; CHAINCODE_SIZE];
+        chaincode.copy_from_slice(&i[32..]);
+        Ok(ExtendedPublicKey {
+            depth: self.depth + 1,
+            parent_fingerprint: fingerprint(&self.pk),
+            child_number: index,
+            chaincode,
+            pk,
+            tweak: self.tweak.add(&il),
+        })
+    }
+
+    pub fn derive_path(&self, path: &[u32]) -> Result<ExtendedPublicKey, MPECDSAError> {
+        let mut key = self.clone();
+        for &index in path.iter() {
+            key = key.derive_child(index)?;
+        }
+        Ok(key)
+    }
+
+    // the 78-byte serialization, with mainnet version bytes
+    pub fn to_bytes(&self) -> [u8; XPUB_SIZE] {
+        let mut out = [0u8; XPUB_SIZE];
+        out[0..4].copy_from_slice(&XPUB_VERSION);
+        out[4] = self.depth;
+        out[5..9].copy_from_slice(&self.parent_fingerprint);
+        out[9..13].copy_from_slice(&self.child_number.to_be_bytes());
+        out[13..45].copy_from_slice(&self.chaincode);
+        out[45..].copy_from_slice(&compressed(&self.pk));
+        out
+    }
+
+    // the familiar "xpub..." string, which watch-only wallets can import
+    pub fn to_base58(&self) -> String {
+        base58check(&self.to_bytes())
+    }
+}
+
+// SEC1 compressed encoding, as used by BIP-32
+fn compressed(pk: &Secp) -> [u8; 33] {
+    let mut out = [0u8; 33];
+    pk.affine().to_bytes_compressed(&mut out);
+    out
+}
+
+// the first four bytes of RIPEMD-160(SHA-256(ser_P(pk)))
+fn fingerprint(pk: &Secp) -> [u8; 4] {
+    let mut sha = [0u8; 32];
+    let mut hasher = Sha256::new();
+    hasher.input(&compressed(pk));
+    hasher.result(&mut sha);
+    let mut ripemd = [0u8; 20];
+    let mut hasher = Ripemd160::new();
+    hasher.input(&sha);
+    hasher.result(&mut ripemd);
+    let mut out = [0u8; 4];
+    out.copy_from_slice(&ripemd[0..4]);
+    out
+}
+
+fn base58check(data: &[u8]) -> String {
+    // the checksum is the first four bytes of SHA-256(SHA-256(data))
+    let mut checksum = [0u8; 32];
+    let mut hasher = Sha256::new();
+    hasher.input(data);
+    hasher.result(&mut checksum);
+    let mut hasher = Sha256::new();
+    hasher.input(&checksum);
+    hasher.result(&mut checksum);
+    let mut bytes = data.to_vec();
+    bytes.extend_from_slice(&checksum[0..4]);
+
+    // repeatedly divide the big-endian number by 58, collecting remainders
+    let mut digits: Vec<u8> = Vec::with_capacity(bytes.len() * 138 / 100 + 1);
+    for &byte in bytes.iter() {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    // leading zero bytes are encoded as leading ones
+    let zeros = bytes.iter().take_while(|&&b| b == 0).count();
+    let mut out = String::with_capacity(zeros + digits.len());
+    for _ in 0..zeros {
+        out.push('1');
+    }
+    for &digit in digits.iter().rev() {
+        out.push(BASE58_ALPHABET[digit as usize] as char);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // test vector 2 from BIP-32
+    fn master() -> (SecpOrd, ExtendedPublicKey) {
+        let sk = SecpOrd::from_bytes(
+            &hex::decode("4b03d6fc340455b363f51020ad3ecca4f0850280cf436c70c727923f6db46c3e")
+                .unwrap(),
+        );
+        let mut chaincode = [0u8; CHAINCODE_SIZE];
+        chaincode.copy_from_slice(
+            &hex::decode("60499f801b896d83179a4374aeb7822aaeaceaa0db1f85ee3e904c4defbd9689")
+                .unwrap(),
+        );
+        (
+            sk,
+            ExtendedPublicKey::new(&Secp::scalar_gen(&sk), &chaincode),
+        )
+    }
+
+    #[test]
+    fn test_bip32_vector() {
+        let (sk, master) = master();
+        assert_eq!(master.to_base58(), "xpub661MyMwAqRbcFW31YEwpkMuc5THy2PSt5bDMsktWQcFF8syAmRUapSCGu8ED9W6oDMSgv6Zz8idoc4a6mr8BDzTJY47LJhkJ8UB7WEGuduB");
+
+        let child = master.derive_child(0).unwrap();
+        assert_eq!(child.to_base58(), "xpub69H7F5d8KSRgmmdJg2KhpAK8SR3DjMwAdkxj3ZuxV27CprR9LgpeyGmXUbC6wb7ERfvrnKZjXoUmmDznezpbZb7ap6r1D3tgFxHmwMkQTPH");
+        assert_eq!(
+            hex::encode(&compressed(&child.pk())[..]),
+            "02fc9e5af0ac8d9b3cecfe2a888e2117ba3d089d8585886c9c826b6b22a98d12ea"
+        );
+
+        // the accumulated tweak relates the root secret key to each descendant
+        let grandchild = master.derive_path(&[0, 7]).unwrap();
+        assert_eq!(grandchild, child.derive_child(7).unwrap());
+        assert_eq!(grandchild.depth(), 2);
+        assert_eq!(
+            Secp::scalar_gen(&sk.add(&grandchild.tweak())).affine(),
+            grandchild.pk()
+        );
+    }
+
+    #[test]
+    fn test_bip32_rejects_hardened() {
+        let (_, master) = master();
+        assert!(master.derive_child(HARDENED_INDEX).is_err());
+        assert!(master.derive_path(&[1, HARDENED_INDEX + 1]).is_err());
+    }
+}
@@ -1,12 +1,14 @@
 #![feature(test)]
 #![feature(integer_atomics)]
 
+pub mod bip32; // BIP-32 public key derivation
 pub mod channelstream;
 pub mod mpecdsa; // threshold ECDSA
 pub mod mpecdsa_error;
 pub mod mpmul; // multiparty multiplication
 pub mod mul; // two-party multiplication
 pub mod ote; // OT extension
+pub mod persist; // versioned serialization of signer state
 pub mod ro; // random oracle
 pub mod rot; // random OT
 pub mod zkpok; // zero knowledge proofs (incl NIZK) // mock networking for testing
@@ -19,7 +21,9 @@ extern crate openmp_sys;
 extern crate crypto;
 use crypto::digest::Digest;
 use crypto::sha2::Sha256;
-use curves::{Ford, SecpOrd};
+use curves::secp256k1::P256;
+use curves::{precomp, CurveParams, ECGroup, Ford, Fq, Secp, SecpOrd, SecpR1, SecpR1Ord};
+use mpecdsa_error::*;
 
 extern crate hex;
 
@@ -34,18 +38,194 @@ should be done more intelligently in the future.
 const RO_TAG_SIZE: usize = 20; // Why 20? Because our vectorized SHA-256 impl takes only 52 = 32+20 bytes as input
 pub const HASH_SIZE: usize = 32;
 const HASH_BLOCK_SIZE: usize = 64;
-const ENCODING_PER_ELEMENT_BITS: usize = SecpOrd::NBITS + 160;
+// every supported curve has 256-bit scalars, so buffers are sized by these rather than by the
+// associated constants of a generic curve, which cannot be used as array lengths
+const SCALAR_BITS: usize = 256;
+const SCALAR_SIZE: usize = SCALAR_BITS / 8;
+const ENCODING_PER_ELEMENT_BITS: usize = SCALAR_BITS + 160;
 const ENCODING_EXTRA_BITS: usize = 0; // From IN96, this must be 2*s
-const RAND_ENCODING_PER_ELEMENT_BITS: usize = SecpOrd::NBITS + 160;
+const RAND_ENCODING_PER_ELEMENT_BITS: usize = SCALAR_BITS + 160;
 const RAND_ENCODING_EXTRA_BITS: usize = 0; // From IN96, this must be 2*s
 const OT_SEC_PARAM: usize = 128 + 80; // From KOS, this should be 128+s
 
+/* Points are sent over the wire as headerless 64-byte x || y pairs by default.
+With the compressed_points feature, they are sent in SEC1 compressed form instead,
+which nearly halves every message that carries points, at the cost of a square root
+to decompress each point received. All parties must be built with the same setting.
+*/
+#[cfg(not(feature = "compressed_points"))]
+const POINT_SIZE: usize = Secp::NBYTES;
+#[cfg(feature = "compressed_points")]
+const POINT_SIZE: usize = Secp::NBYTES_COMPRESSED;
+
+/// A curve over which the signing protocols can run. Whichever curve is chosen, the base OTs
+/// under the multipliers use secp256k1, and every party must use the same curve
+pub trait Curve: Send + Sync + 'static {
+    type Field: Fq;
+    type Scalar: Ford + Send + Sync;
+    type Point: ECGroup<Self::Field, Self::Scalar> + WirePoint + Copy + Send + Sync;
+
+    /// 2^i times the generator, for i in 0..256
+    fn gen_table() -> &'static [Self::Point];
+    /// 2^i, for i in 0..256
+    fn gadget_table() -> &'static [Self::Scalar];
+}
+
+/// secp256k1, as used by bitcoin. Schnorr signatures, taproot, BIP-32 derivation, and
+/// persistence are available only over this curve
+pub struct Secp256k1;
+
+/// NIST P-256 (secp256r1), as used by WebAuthn/FIDO and most HSMs
+pub struct Secp256r1;
+
+impl Curve for Secp256k1 {
+    type Field = curves::f_fc2f::FSecp256;
+    type Scalar = SecpOrd;
+    type Point = Secp;
+
+    fn gen_table() -> &'static [Secp] {
+        &precomp::P256_TABLE[..]
+    }
+
+    fn gadget_table() -> &'static [SecpOrd] {
+        &precomp::GADGET_TABLE_256[..]
+    }
+}
+
+impl Curve for Secp256r1 {
+    type Field = curves::f_ffff::FP256;
+    type Scalar = SecpR1Ord;
+    type Point = SecpR1;
+
+    fn gen_table() -> &'static [SecpR1] {
+        &precomp::SECP256R1_TABLE[..]
+    }
+
+    fn gadget_table() -> &'static [SecpR1Ord] {
+        &precomp::GADGET_TABLE_SECP256R1[..]
+    }
+}
+
+// received points are always checked to lie on the curve, and a point that does not
+// is blamed on the party that sent it
+pub trait WirePoint: Sized {
+    // self needs to be normalized before calling this method
+    fn to_wire_bytes(&self, b: &mut [u8]);
+    fn from_wire_bytes(b: &[u8], sender: usize, phase: ProtocolPhase)
+        -> Result<Self, MPECDSAError>;
+    // as from_wire_bytes, but also rejects the point at infinity, which is never a valid public
+    // key or nonce
+    fn from_wire_bytes_finite(
+        b: &[u8],
+        sender: usize,
+        phase: ProtocolPhase,
+    ) -> Result<Self, MPECDSAError>;
+}
+
+fn invalid_point_error(sender: usize, phase: ProtocolPhase, e: &str) -> MPECDSAError {
+    MPECDSAError::Blame(BlameError::new(
+        sender,
+        phase,
+        &format!("sent an invalid curve point: {}", e),
+    ))
+}
+
+fn finite_point<F: Fq, T: Ford, C: CurveParams<F>>(
+    p: P256<F, T, C>,
+    sender: usize,
+    phase: ProtocolPhase,
+) -> Result<P256<F, T, C>, MPECDSAError> {
+    if p.is_infinity() {
+        Err(invalid_point_error(sender, phase, "point at infinity"))
+    } else {
+        Ok(p)
+    }
+}
+
+#[cfg(not(feature = "compressed_points"))]
+impl<F: Fq, T: Ford, C: CurveParams<F>> WirePoint for P256<F, T, C> {
+    fn to_wire_bytes(&self, b: &mut [u8]) {
+        self.to_bytes(b);
+    }
+
+    fn from_wire_bytes(
+        b: &[u8],
+        sender: usize,
+        phase: ProtocolPhase,
+    ) -> Result<Self, MPECDSAError> {
+        Self::try_from_bytes(b).map_err(|e| invalid_point_error(sender, phase, e))
+    }
+
+    fn from_wire_bytes_finite(
+        b: &[u8],
+        sender: usize,
+        phase: ProtocolPhase,
+    ) -> Result<Self, MPECDSAError> {
+        finite_point(Self::from_wire_bytes(b, sender, phase)?, sender, phase)
+    }
+}
+
+#[cfg(feature = "compressed_points")]
+impl<F: Fq, T: Ford, C: CurveParams<F>> WirePoint for P256<F, T, C> {
+    fn to_wire_bytes(&self, b: &mut [u8]) {
+        self.to_bytes_compressed(b);
+    }
+
+    fn from_wire_bytes(
+        b: &[u8],
+        sender: usize,
+        phase: ProtocolPhase,
+    ) -> Result<Self, MPECDSAError> {
+        Self::from_bytes_compressed(b).map_err(|e| invalid_point_error(sender, phase, e))
+    }
+
+    fn from_wire_bytes_finite(
+        b: &[u8],
+        sender: usize,
+        phase: ProtocolPhase,
+    ) -> Result<Self, MPECDSAError> {
+        finite_point(Self::from_wire_bytes(b, sender, phase)?, sender, phase)
+    }
+}
+
 fn ecdsa_hash(res: &mut [u8; HASH_SIZE], msg: &[u8]) {
     let mut hasher = Sha256::new();
     hasher.input(msg);
     hasher.result(res);
 }
 
+// overwrites secret state in a way that the compiler will not elide, even if the state is
+// about to be freed
+fn wipe<T: Copy>(secret: &mut T, zero: T) {
+    unsafe {
+        std::ptr::write_volatile(secret, zero);
+    }
+}
+
+// as wipe, for a buffer of secret bytes
+fn wipe_bytes(secret: &mut [u8]) {
+    for b in secret.iter_mut() {
+        wipe(b, 0);
+    }
+}
+
+// maps a hash output to a uniformly random scalar. from_bytes reduces only once, which is
+// noticeably biased where the order is far below 2^256 (by about 2^-32 for P-256), so a value
+// that is out of range is hashed again instead
+fn scalar_from_hash<S: Ford>(h: &[u8]) -> S {
+    let mut h: [u8; HASH_SIZE] = h[0..HASH_SIZE].try_into().unwrap();
+    loop {
+        let s = S::from_bytes(&h);
+        let mut b = [0u8; HASH_SIZE];
+        s.to_bytes(&mut b);
+        if b == h {
+            return s;
+        }
+        let prev = h;
+        hash(&mut h, &prev);
+    }
+}
+
 fn vec_eq(va: &[u8], vb: &[u8]) -> bool {
     (va.len() == vb.len()) &&  // zip stops at the shortest
 	 va.iter()
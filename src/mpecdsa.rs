@@ -8,14 +8,16 @@
  * by Doerner, Kondi, Lee, and shelat
  ***********/
 
+use super::bip32::*;
 use super::mpecdsa_error::*;
 use super::mpmul::*;
 use super::mul::*;
+use super::persist;
 use super::ro::*;
 use super::zkpok::*;
 use super::*;
 use byteorder::{ByteOrder, LittleEndian};
-use curves::{ecdsa, precomp, ECGroup, Ford, Fq, Secp, SecpOrd};
+use curves::{ecdsa, schnorr, ECGroup, Ford, Fq, Secp, SecpOrd};
 use rand::Rng;
 use rayon::prelude::*;
 use std::io::prelude::*;
@@ -23,46 +25,226 @@ use std::io::{BufWriter, Cursor};
 
 extern crate test;
 
+// the most refresh packages from a single epoch that a signer will apply, and so the most
+// nonces it will persist, since from_bytes must not trust the count in a file
+const MAX_REFRESHED: usize = 1 << 16;
+
 //#[derive(Clone)]
-pub struct Alice2P {
+pub struct GenericAlice2P<C: Curve> {
     ro: GroupROTagger,
-    multiplier: mul::MulSender,
-    ska: SecpOrd,
+    multiplier: mul::GenericMulSender<C>,
+    ska: C::Scalar,
     #[allow(dead_code)]
-    pk: Secp,
-    pktable: Vec<Secp>,
+    pk: C::Point,
+    pktable: Vec<C::Point>,
+    chaincode: [u8; CHAINCODE_SIZE],
+    presigs: Vec<[u8; RO_TAG_SIZE]>,
 }
 
 //#[derive(Clone)]
-pub struct Bob2P {
+pub struct GenericBob2P<C: Curve> {
     ro: GroupROTagger,
-    multiplier: mul::MulRecver,
-    skb: SecpOrd,
+    multiplier: mul::GenericMulRecver<C>,
+    skb: C::Scalar,
     #[allow(dead_code)]
-    pk: Secp,
-    pktable: Vec<Secp>,
+    pk: C::Point,
+    pktable: Vec<C::Point>,
+    chaincode: [u8; CHAINCODE_SIZE],
+    presigs: Vec<[u8; RO_TAG_SIZE]>,
 }
 
-pub struct ThresholdSigner {
+pub struct GenericThresholdSigner<C: Curve> {
     playerindex: usize,
     threshold: usize,
     ro: GroupROTagger,
-    multiplier: Vec<mul::MulPlayer>,
-    poly_point: SecpOrd,
+    multiplier: Vec<mul::MulPlayer<C>>,
+    poly_point: C::Scalar,
     #[allow(dead_code)]
-    pk: Secp,
-    pktable: Vec<Secp>,
+    pk: C::Point,
+    pktable: Vec<C::Point>,
+    chaincode: [u8; CHAINCODE_SIZE],
+    // the number of refresh packages applied to this share. refreshed holds the nonces of the
+    // packages applied from refresh_epoch, the newest epoch in which an applied package was made
+    epoch: usize,
+    refresh_epoch: usize,
+    refreshed: Vec<C::Point>,
+}
+
+// the signers over secp256k1, which is what most callers want. the generic forms take the curve as
+// a parameter, e.g. GenericThresholdSigner<Secp256r1>
+pub type Alice2P = GenericAlice2P<Secp256k1>;
+pub type Bob2P = GenericBob2P<Secp256k1>;
+pub type ThresholdSigner = GenericThresholdSigner<Secp256k1>;
+
+// (R, tag, epoch in which the package was made, coin, schnorr nonce, schnorr z)
+pub type ProactiveRefreshPackage<C = Secp256k1> = (
+    <C as Curve>::Point,
+    Vec<u8>,
+    usize,
+    <C as Curve>::Scalar,
+    <C as Curve>::Point,
+    <C as Curve>::Scalar,
+);
+
+// a signature together with the recovery id of its instance point, from which the public key
+// can be recovered by ecdsa::recover_public_key. every signing protocol returns signatures in
+// low-s form (BIP-62/BIP-146), with the recovery id adjusted to match.
+pub type Signature<C = Secp256k1> = ecdsa::Signature<<C as Curve>::Scalar>;
+
+// a key derived from the signer's own, as its public tweak and the derived public key
+type DerivedKey<C> = (<C as Curve>::Scalar, <C as Curve>::Point);
+
+// what a threshold signer is asked to sign: the digest, the derived key to sign it under (or
+// None for the root key), the tag of a refresh package to generate alongside the signature (if
+// any), and whether the signature is returned in the 2-of-n case
+struct SignRequest<'a, C: Curve> {
+    digest: &'a [u8; HASH_SIZE],
+    key: Option<&'a DerivedKey<C>>,
+    tag: Option<&'a [u8]>,
+    returnsig: bool,
+}
+
+// the signature, if this party learns it, and the refresh package, if one was requested
+type SignOutput<C> = (Option<Signature<C>>, Option<ProactiveRefreshPackage<C>>);
+
+// The message-independent output of the two-party signing protocol. Alice and Bob each hold one,
+// matched by an identifier derived from the random oracle. Each party tracks the presignatures it
+// has made, and each can be used only once. Presignatures are not persisted by to_bytes.
+pub struct Presig2P<C: Curve = Secp256k1> {
+    id: [u8; RO_TAG_SIZE],
+    rx: C::Scalar,
+    recid: u8,
+    t1: C::Scalar,
+    t2: C::Scalar,
+    enckey: [u8; HASH_SIZE],
+}
+
+// in the two-party protocols bob alone reconstructs the signature, so he sends it back to alice,
+// who must check it before relying on it. alice knows R, so the recovery id need not be sent,
+// but bob sends s as reconstructed, before normalization, so that the id remains consistent with it.
+fn send_signature<C: Curve, TW: Write>(
+    sig: &Signature<C>,
+    send: &mut TW,
+) -> Result<(), MPECDSAError> {
+    send.write_all(&sig.to_compact())?;
+    send.flush()?;
+    Ok(())
+}
+
+fn recv_signature<C: Curve, TR: Read>(
+    digest: &[u8; HASH_SIZE],
+    counterparty: usize,
+    pk: &C::Point,
+    key: Option<&DerivedKey<C>>,
+    recid: u8,
+    recv: &mut TR,
+) -> Result<Signature<C>, MPECDSAError> {
+    let mut sigraw = [0u8; 2 * SCALAR_SIZE];
+    recv.read_exact(&mut sigraw)?;
+    let sig = match Signature::<C>::from_compact(&sigraw) {
+        Ok(sig) => Signature::<C> { recid, ..sig }.normalize_s(),
+        Err(_) => {
+            return Err(MPECDSAError::Blame(BlameError::new(
+                counterparty,
+                ProtocolPhase::Sign,
+                "Returned signature is malformed (bob cheated)",
+            )))
+        }
+    };
+    // recovering the signing key also verifies the signature
+    let pk = key.map_or(*pk, |key| key.1).affine();
+    if ecdsa::recover_public_key_prehashed::<_, _, C::Point>(digest, &sig) != Some(pk) {
+        return Err(MPECDSAError::Blame(BlameError::new(
+            counterparty,
+            ProtocolPhase::Sign,
+            "Returned signature failed to verify (bob cheated)",
+        )));
+    }
+    Ok(sig)
+}
+
+// Signing with a derived key adds its tweak to the root secret key. The tweak is public, so rather
+// than designating one party to add it to their share of sk/k, every party adds the tweak times its
+// share of 1/k. This also allows presignatures to be made before the key is chosen.
+fn derived_tweak<C: Curve>(key: Option<&DerivedKey<C>>) -> C::Scalar {
+    key.map_or(C::Scalar::ZERO, |key| key.0)
+}
+
+// pktable is the table for the root key, which is used if no derived key is given
+fn verify_with_key<C: Curve>(
+    digest: &[u8; HASH_SIZE],
+    sig: (&C::Scalar, &C::Scalar),
+    pktable: &[C::Point],
+    key: Option<&DerivedKey<C>>,
+) -> bool {
+    match key {
+        Some(key) => ecdsa::ecdsa_verify_prehashed(digest, sig, &key.1),
+        None => ecdsa::ecdsa_verify_prehashed_with_tables(digest, sig, C::gen_table(), pktable),
+    }
+}
+
+// a derived key must descend from the signer's own public key, or else the final verification
+// would fail and appear to be the fault of the counterparty
+fn check_derived_key(
+    pk: &Secp,
+    key: &ExtendedPublicKey,
+) -> Result<DerivedKey<Secp256k1>, MPECDSAError> {
+    let tweakg = Secp::scalar_table_multi(Secp256k1::gen_table(), &key.tweak());
+    if Secp::op(pk, &tweakg).affine() != key.pk() {
+        return Err(MPECDSAError::General(GeneralError::new(
+            "Derived key does not descend from this signer's public key",
+        )));
+    }
+    Ok((key.tweak(), key.pk()))
+}
+
+// removes a presignature from the list of those outstanding, so that it cannot be used again
+fn take_presig<C: Curve>(
+    presigs: &mut Vec<[u8; RO_TAG_SIZE]>,
+    presig: &Presig2P<C>,
+) -> Result<(), MPECDSAError> {
+    match presigs.iter().position(|id| *id == presig.id) {
+        Some(index) => {
+            presigs.swap_remove(index);
+            Ok(())
+        }
+        None => Err(MPECDSAError::General(GeneralError::new(
+            "Presignature was already used or belongs to a different signer",
+        ))),
+    }
+}
+
+// The message-independent output of the threshold signing protocol: the instance key R and additive
+// shares of k^{-1} and sk/k, all of which have already passed the consistency checks.
+// It is consumed by finish_sign, and must never be used for more than one signature. It is bound
+// to the key and epoch of the shares it was made from, so any refresh or resharing invalidates it.
+pub struct PreSignature<C: Curve = Secp256k1> {
+    parties: Vec<usize>,
+    playerindex: usize,
+    pk: C::Point,
+    epoch: usize,
+    r: C::Point,
+    kinv: C::Scalar,
+    skkinv: C::Scalar,
 }
 
-pub type ProactiveRefreshPackage = (Secp, Vec<u8>, SecpOrd, Secp, SecpOrd);
+impl<C: Curve> PreSignature<C> {
+    pub fn r(&self) -> C::Point {
+        self.r
+    }
+
+    pub fn parties(&self) -> &[usize] {
+        &self.parties[..]
+    }
+}
 
-impl Alice2P {
+impl<C: Curve> GenericAlice2P<C> {
     pub fn new<TR: Read, TW: Write>(
-        ska: &SecpOrd,
+        ska: &C::Scalar,
         rng: &mut dyn Rng,
         recv: &mut TR,
         send: &mut TW,
-    ) -> Result<Alice2P, MPECDSAError> {
+    ) -> Result<GenericAlice2P<C>, MPECDSAError> {
         let ro = GroupROTagger::from_network_unverified(
             0,
             rng,
@@ -71,26 +253,37 @@ impl Alice2P {
         )?;
 
         // commit to PoK-DL for pk_a
-        let pka = Secp::scalar_table_multi(&precomp::P256_TABLE[..], &ska).affine();
+        let pka = C::Point::scalar_table_multi_ct(C::gen_table(), &ska).affine();
         let (proofcommitment, proof) =
-            prove_dl_fs_to_com(ska, &pka, &ModelessGroupROTagger::new(&ro, false), rng)?;
-        send.write(&proofcommitment)?;
+            prove_dl_fs_to_com::<C>(ska, &pka, &ModelessGroupROTagger::new(&ro, false), rng)?;
+        send.write_all(&proofcommitment)?;
+
+        // commit to alice's contribution to the chain code
+        let mut chaincode_raw = [0u8; CHAINCODE_SIZE + RO_TAG_SIZE];
+        rng.fill_bytes(&mut chaincode_raw[RO_TAG_SIZE..]);
+        chaincode_raw[0..RO_TAG_SIZE].copy_from_slice(&ro.next_broadcast_tag()[..]);
+        let mut chaincodecom = [0u8; HASH_SIZE];
+        hash(&mut chaincodecom, &chaincode_raw);
+        send.write_all(&chaincodecom)?;
         send.flush()?;
 
         // recv pk_b
-        let mut buf = [0u8; Secp::NBYTES];
+        let mut buf = [0u8; POINT_SIZE];
         recv.read_exact(&mut buf)?;
-        let pkb: Secp = Secp::from_bytes(&buf);
+        let pkb: C::Point = C::Point::from_wire_bytes_finite(&buf, 1, ProtocolPhase::Setup)?;
 
         // verify PoK-DL for pk_b
-        match verify_dl_fs(
+        match verify_dl_fs::<C, _>(
             &pkb,
             &ModelessDyadicROTagger::new(&ro.get_dyadic_tagger(1).unwrap(), false),
             recv,
+            ProtocolPhase::Setup,
         ) {
             Ok(f) => {
                 if !f {
-                    return Err(MPECDSAError::Proof(ProofError::new(
+                    return Err(MPECDSAError::Blame(BlameError::new(
+                        1,
+                        ProtocolPhase::Setup,
                         "Proof of Knowledge failed for ECDSA secret key (bob cheated)",
                     )));
                 }
@@ -98,25 +291,35 @@ impl Alice2P {
             Err(e) => return Err(e),
         };
 
+        // recv bob's contribution to the chain code
+        let mut chaincode = [0u8; CHAINCODE_SIZE];
+        recv.read_exact(&mut chaincode)?;
+        for ii in 0..CHAINCODE_SIZE {
+            chaincode[ii] ^= chaincode_raw[RO_TAG_SIZE + ii];
+        }
+
         // send pk_a
-        // open commitment to PoK-DL
-        pka.to_bytes(&mut buf);
-        send.write(&buf)?;
-        send.write(&proof)?;
+        // open commitments to PoK-DL and chain code contribution
+        pka.to_wire_bytes(&mut buf);
+        send.write_all(&buf)?;
+        send.write_all(&proof)?;
+        send.write_all(&chaincode_raw[RO_TAG_SIZE..])?;
         send.flush()?;
 
         // initialize multiplication
-        let mul = mul::MulSender::new(&ro.get_dyadic_tagger(1).unwrap(), rng, recv, send)?;
+        let mul = mul::GenericMulSender::new(&ro.get_dyadic_tagger(1).unwrap(), rng, recv, send)?;
 
         // calc pk, setup OT exts
-        let pk = pkb.scalar_table(&ska).affine();
-        let pktable = Secp::precomp_table(&pk);
-        let res = Alice2P {
-            ro: ro,
+        let pk = pkb.scalar_ct(&ska).affine();
+        let pktable = C::Point::precomp_table(&pk);
+        let res = GenericAlice2P {
+            ro,
             multiplier: mul,
             ska: ska.clone(),
-            pk: pk,
-            pktable: pktable,
+            pk,
+            pktable,
+            chaincode,
+            presigs: Vec::new(),
         };
 
         Ok(res)
@@ -129,6 +332,99 @@ impl Alice2P {
         recv: &mut TR,
         send: &mut TW,
     ) -> Result<(), MPECDSAError> {
+        let mut digest = [0u8; HASH_SIZE];
+        ecdsa_hash(&mut digest, msg);
+        self.sign_prehashed(&digest, rng, recv, send)
+    }
+
+    // Signs a digest computed by the caller, such as a keccak-256 hash or a bitcoin sighash,
+    // rather than the sha-256 hash of a message.
+    pub fn sign_prehashed<TR: Read, TW: Write + Send>(
+        &self,
+        digest: &[u8; HASH_SIZE],
+        rng: &mut dyn Rng,
+        recv: &mut TR,
+        send: &mut TW,
+    ) -> Result<(), MPECDSAError> {
+        self.sign_with_key(None, digest, rng, recv, send)
+    }
+
+    // Like sign, but with a final flight in which bob returns the signature, so that alice can
+    // verify and publish it herself. Bob must call Bob2P::sign_with_return.
+    pub fn sign_with_return<TR: Read, TW: Write + Send>(
+        &self,
+        msg: &[u8],
+        rng: &mut dyn Rng,
+        recv: &mut TR,
+        send: &mut TW,
+    ) -> Result<Signature<C>, MPECDSAError> {
+        let mut digest = [0u8; HASH_SIZE];
+        ecdsa_hash(&mut digest, msg);
+        let presig = self.presign_inner(rng, recv, send)?;
+        self.sign_presigned_inner(&presig, None, &digest, send)?;
+        recv_signature::<C, _>(&digest, 1, &self.pk, None, presig.recid, recv)
+    }
+
+    fn sign_with_key<TR: Read, TW: Write + Send>(
+        &self,
+        key: Option<&DerivedKey<C>>,
+        digest: &[u8; HASH_SIZE],
+        rng: &mut dyn Rng,
+        recv: &mut TR,
+        send: &mut TW,
+    ) -> Result<(), MPECDSAError> {
+        let presig = self.presign_inner(rng, recv, send)?;
+        self.sign_presigned_inner(&presig, key, digest, send)
+    }
+
+    // Runs the two message-independent flights of signing ahead of time. Bob must call
+    // Bob2P::presign at the same time, and must sign with the matching presignature.
+    pub fn presign<TR: Read, TW: Write + Send>(
+        &mut self,
+        rng: &mut dyn Rng,
+        recv: &mut TR,
+        send: &mut TW,
+    ) -> Result<Presig2P<C>, MPECDSAError> {
+        let presig = self.presign_inner(rng, recv, send)?;
+        self.presigs.push(presig.id);
+        Ok(presig)
+    }
+
+    // Signs with a presignature, which requires only a single flight from Alice to Bob.
+    pub fn sign_presigned<TW: Write>(
+        &mut self,
+        presig: Presig2P<C>,
+        msg: &[u8],
+        send: &mut TW,
+    ) -> Result<(), MPECDSAError> {
+        take_presig(&mut self.presigs, &presig)?;
+        let mut digest = [0u8; HASH_SIZE];
+        ecdsa_hash(&mut digest, msg);
+        self.sign_presigned_inner(&presig, None, &digest, send)
+    }
+
+    // Signs with a presignature, with the same final flight as sign_with_return. Bob must call
+    // Bob2P::sign_presigned_with_return.
+    pub fn sign_presigned_with_return<TR: Read, TW: Write>(
+        &mut self,
+        presig: Presig2P<C>,
+        msg: &[u8],
+        recv: &mut TR,
+        send: &mut TW,
+    ) -> Result<Signature<C>, MPECDSAError> {
+        take_presig(&mut self.presigs, &presig)?;
+        let mut digest = [0u8; HASH_SIZE];
+        ecdsa_hash(&mut digest, msg);
+        self.sign_presigned_inner(&presig, None, &digest, send)?;
+        recv_signature::<C, _>(&digest, 1, &self.pk, None, presig.recid, recv)
+    }
+
+    fn presign_inner<TR: Read, TW: Write + Send>(
+        &self,
+        rng: &mut dyn Rng,
+        recv: &mut TR,
+        send: &mut TW,
+    ) -> Result<Presig2P<C>, MPECDSAError> {
         let mut bufsend = BufWriter::new(send);
 
         // precompute things you won't need till later
@@ -136,30 +432,25 @@ impl Alice2P {
         // alice's instance key is of a special form for the two round version:
         // k_a = H(k'_a*G)+k'_a
         // this prevents her from choosing the value conveniently
-        let kaprime = SecpOrd::rand(rng);
-        let kapad = SecpOrd::rand(rng);
-
-        // hash the message
-        let mut z = [0; HASH_SIZE];
-        ecdsa_hash(&mut z, msg);
-        let z = SecpOrd::from_bytes(&z);
+        let kaprime = C::Scalar::rand(rng);
+        let kapad = C::Scalar::rand(rng);
 
         // online phase
         let dro = self.ro.get_dyadic_tagger(1).unwrap();
 
         // recv D_b from bob
-        let mut dbraw = [0u8; Secp::NBYTES];
+        let mut dbraw = [0u8; POINT_SIZE];
         recv.read_exact(&mut dbraw)?;
-        let db = Secp::from_bytes(&dbraw);
-        let dbtable = Secp::precomp_table(&db);
+        let db = C::Point::from_wire_bytes_finite(&dbraw, 1, ProtocolPhase::Sign)?;
+        let dbtable = C::Point::precomp_table(&db);
 
-        let rprime = Secp::scalar_table_multi(&dbtable[..], &kaprime).affine();
-        let mut rprimeraw = [0u8; Secp::NBYTES + RO_TAG_SIZE];
-        rprime.to_bytes(&mut rprimeraw[RO_TAG_SIZE..]);
+        let rprime = C::Point::scalar_table_multi_ct(&dbtable[..], &kaprime).affine();
+        let mut rprimeraw = [0u8; POINT_SIZE + RO_TAG_SIZE];
+        rprime.to_wire_bytes(&mut rprimeraw[RO_TAG_SIZE..]);
         rprimeraw[0..RO_TAG_SIZE].copy_from_slice(&dro.next_dyadic_tag());
         let mut kaoffsetraw = [0u8; HASH_SIZE];
         hash(&mut kaoffsetraw, &rprimeraw);
-        let kaoffset = SecpOrd::from_bytes(&kaoffsetraw);
+        let kaoffset = C::Scalar::from_bytes(&kaoffsetraw);
         let ka = kaoffset.add(&kaprime);
 
         let kai = ka.inv();
@@ -167,30 +458,31 @@ impl Alice2P {
 
         // compute R = k_a*k_b*G, and get the x coordinate
         // do this early to save time later and give bob a chance to start the extensions
-        let r = Secp::scalar_table_multi(&dbtable[..], &ka).affine();
-        let mut rxb = [0u8; SecpOrd::NBYTES];
-        r.x.to_bytes(&mut rxb);
-        let rx = SecpOrd::from_bytes(&rxb);
-        let r_table = Secp::precomp_table(&r);
-        let kapadda = Secp::scalar_table_multi(&precomp::P256_TABLE[..], &ka.mul(&kapad));
+        let r = C::Point::scalar_table_multi_ct(&dbtable[..], &ka).affine();
+        let mut rxb = [0u8; SCALAR_SIZE];
+        r.x().to_bytes(&mut rxb);
+        let rx = C::Scalar::from_bytes(&rxb);
+        let r_table = C::Point::precomp_table(&r);
+        let kapadda = C::Point::scalar_table_multi_ct(C::gen_table(), &ka.mul(&kapad));
 
         // Prove knowledge of ka for R; hardcoded fiat-shamir so we can do preprocessing
-        let kaproof_randcommitted = SecpOrd::rand(rng);
-        let mut kaproof_buf = [0u8; 2 * Secp::NBYTES + SecpOrd::NBYTES + RO_TAG_SIZE];
-        let kaproof_randcommitment = Secp::scalar_table_multi(&dbtable[..], &kaproof_randcommitted);
-        kaproof_randcommitment.to_bytes(
-            &mut kaproof_buf[(RO_TAG_SIZE + Secp::NBYTES)..(2 * Secp::NBYTES + RO_TAG_SIZE)],
+        let kaproof_randcommitted = C::Scalar::rand(rng);
+        let mut kaproof_buf = [0u8; 2 * POINT_SIZE + SCALAR_SIZE + RO_TAG_SIZE];
+        let kaproof_randcommitment =
+            C::Point::scalar_table_multi_ct(&dbtable[..], &kaproof_randcommitted);
+        kaproof_randcommitment.to_wire_bytes(
+            &mut kaproof_buf[(RO_TAG_SIZE + POINT_SIZE)..(2 * POINT_SIZE + RO_TAG_SIZE)],
         );
-        r.to_bytes(&mut kaproof_buf[RO_TAG_SIZE..(Secp::NBYTES + RO_TAG_SIZE)]);
+        r.to_wire_bytes(&mut kaproof_buf[RO_TAG_SIZE..(POINT_SIZE + RO_TAG_SIZE)]);
         kaproof_buf[0..RO_TAG_SIZE].copy_from_slice(&dro.next_dyadic_tag());
         let mut kaproof_challenge = [0u8; HASH_SIZE];
         hash(
             &mut kaproof_challenge,
-            &kaproof_buf[0..(2 * Secp::NBYTES + RO_TAG_SIZE)],
+            &kaproof_buf[0..(2 * POINT_SIZE + RO_TAG_SIZE)],
         );
-        let kaproof_challenge = SecpOrd::from_bytes(&kaproof_challenge[..]);
+        let kaproof_challenge = C::Scalar::from_bytes(&kaproof_challenge[..]);
         let kaproof_z = ka.mul(&kaproof_challenge).add(&kaproof_randcommitted);
-        kaproof_z.to_bytes(&mut kaproof_buf[(2 * Secp::NBYTES + RO_TAG_SIZE)..]);
+        kaproof_z.to_bytes(&mut kaproof_buf[(2 * POINT_SIZE + RO_TAG_SIZE)..]);
 
         // generate OT extensions for two multiplications (input independent for alice)
         let extensions = self.multiplier.mul_extend(2, &dro, recv)?;
@@ -199,8 +491,8 @@ impl Alice2P {
 
         // alice sends D'_a = k'_a*G rather than D_a so that bob can check her work
         // she also sends her proof of knowledge for k_a
-        bufsend.write(&rprimeraw[RO_TAG_SIZE..])?;
-        bufsend.write(&kaproof_buf[(Secp::NBYTES + RO_TAG_SIZE)..])?;
+        bufsend.write_all(&rprimeraw[RO_TAG_SIZE..])?;
+        bufsend.write_all(&kaproof_buf[(POINT_SIZE + RO_TAG_SIZE)..])?;
         bufsend.flush()?;
 
         // perform two multiplications with 1/k_a and sk_a/k_a.
@@ -213,7 +505,7 @@ impl Alice2P {
             &mut bufsend,
         )?[0];
         bufsend.flush()?;
-        let mut gamma1raw = [0u8; Secp::NBYTES + RO_TAG_SIZE];
+        let mut gamma1raw = [0u8; POINT_SIZE + RO_TAG_SIZE];
         gamma1raw[0..RO_TAG_SIZE].copy_from_slice(&dro.next_dyadic_tag());
         let t2a = self.multiplier.mul_transfer(
             &[&skai],
@@ -226,59 +518,164 @@ impl Alice2P {
         bufsend.flush()?;
 
         // compute check value Gamma_1 for alice
-        let gamma1 = Secp::op(
-            &Secp::op(
-                &Secp::scalar_table_multi(&r_table[..], &t1a.neg()),
+        let gamma1 = C::Point::op(
+            &C::Point::op(
+                &C::Point::scalar_table_multi_ct(&r_table[..], &t1a.neg()),
                 &kapadda,
             ),
-            &Secp::gen(),
+            &C::Point::gen(),
         )
         .affine();
-        gamma1.to_bytes(&mut gamma1raw[RO_TAG_SIZE..]);
+        gamma1.to_wire_bytes(&mut gamma1raw[RO_TAG_SIZE..]);
         let mut enckey = [0u8; HASH_SIZE];
         hash(&mut enckey, &gamma1raw);
-        let mut kapadraw = [0u8; SecpOrd::NBYTES];
+        let mut kapadraw = [0u8; SCALAR_SIZE];
         kapad.to_bytes(&mut kapadraw);
-        for ii in 0..SecpOrd::NBYTES {
+        for ii in 0..SCALAR_SIZE {
             kapadraw[ii] ^= enckey[ii];
         }
-        bufsend.write(&kapadraw)?;
+        bufsend.write_all(&kapadraw)?;
         bufsend.flush()?;
 
-        // compute signature share m_a for alice
-        let mut ma = [0u8; SecpOrd::NBYTES];
-        let m_a = t1a.mul(&z).add(&t2a.mul(&rx));
-        m_a.to_bytes(&mut ma);
+        // end second message (alice to bob)
 
-        // compute check value Gamma_2, and encrypt m_a with H(Gamma_2)
-        let t2ag = Secp::scalar_table_multi(&precomp::P256_TABLE[..], &t2a.neg());
-        let t1apk = Secp::scalar_table_multi(&self.pktable[..], &t1a);
-        let gamma2 = Secp::op(&t2ag, &t1apk).affine();
-        let mut gamma2raw = [0u8; Secp::NBYTES + RO_TAG_SIZE];
-        gamma2.to_bytes(&mut gamma2raw[RO_TAG_SIZE..]);
+        // compute check value Gamma_2, with which m_a will be encrypted
+        let t2ag = C::Point::scalar_table_multi_ct(C::gen_table(), &t2a.neg());
+        let t1apk = C::Point::scalar_table_multi_ct(&self.pktable[..], &t1a);
+        let gamma2 = C::Point::op(&t2ag, &t1apk).affine();
+        let mut gamma2raw = [0u8; POINT_SIZE + RO_TAG_SIZE];
+        gamma2.to_wire_bytes(&mut gamma2raw[RO_TAG_SIZE..]);
         gamma2raw[0..RO_TAG_SIZE].copy_from_slice(&dro.next_dyadic_tag());
         hash(&mut enckey, &gamma2raw);
-        for ii in 0..SecpOrd::NBYTES {
-            ma[ii] ^= enckey[ii];
+
+        Ok(Presig2P {
+            id: dro.next_dyadic_tag(),
+            rx,
+            recid: ecdsa::recovery_id(&r),
+            t1: t1a,
+            t2: t2a,
+            enckey,
+        })
+    }
+
+    fn sign_presigned_inner<TW: Write>(
+        &self,
+        presig: &Presig2P<C>,
+        key: Option<&DerivedKey<C>>,
+        digest: &[u8; HASH_SIZE],
+        send: &mut TW,
+    ) -> Result<(), MPECDSAError> {
+        let z = C::Scalar::from_bytes(digest);
+        let t2 = presig.t2.add(&derived_tweak::<C>(key).mul(&presig.t1));
+
+        // compute signature share m_a for alice, and encrypt it with H(Gamma_2)
+        let mut ma = [0u8; SCALAR_SIZE];
+        let m_a = presig.t1.mul(&z).add(&t2.mul(&presig.rx));
+        m_a.to_bytes(&mut ma);
+        for ii in 0..SCALAR_SIZE {
+            ma[ii] ^= presig.enckey[ii];
         }
 
-        // send encrypted signature share
-        bufsend.write(&ma)?;
-        bufsend.flush()?;
+        // send the presignature identifier and encrypted signature share
+        send.write_all(&presig.id)?;
+        send.write_all(&ma)?;
+        send.flush()?;
 
-        // end second message (alice to bob)
+        // end third message (alice to bob)
 
         Ok(())
     }
 }
 
-impl Bob2P {
+// persistence and BIP-32 derivation are defined only over secp256k1
+impl GenericAlice2P<Secp256k1> {
+    // As with ThresholdSigner::to_bytes, the serialized state includes the RO counters,
+    // so it must be rewritten after every signature and never restored twice.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut body = Vec::new();
+        // we perform only local IO, so there should never be an error
+        persist::write_scalar(&self.ska, &mut body).unwrap();
+        persist::write_point(&self.pk, &mut body).unwrap();
+        body.write_all(&self.chaincode).unwrap();
+        self.ro.write_to(&mut body).unwrap();
+        self.multiplier.write_to(&mut body).unwrap();
+        let sealed = persist::seal(persist::PersistKind::Alice2P, &body);
+        wipe_bytes(&mut body);
+        sealed
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Result<Alice2P, MPECDSAError> {
+        let mut body = Cursor::new(persist::unseal(persist::PersistKind::Alice2P, data)?);
+        let ska = persist::read_scalar(&mut body)?;
+        let pk = persist::read_point(&mut body)?;
+        let mut chaincode = [0u8; CHAINCODE_SIZE];
+        body.read_exact(&mut chaincode)?;
+        let ro = GroupROTagger::read_from(&mut body)?;
+        if ro.get_supergroup_party_count() != 2 || ro.get_playerindex() != 0 {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Serialized Random Oracle tagger does not belong to Alice",
+            )));
+        }
+        let multiplier = mul::GenericMulSender::read_from(&mut body)?;
+        persist::finish_body(&body)?;
+
+        let pktable = Secp::precomp_table(&pk);
+        Ok(GenericAlice2P {
+            ro,
+            multiplier,
+            ska,
+            pk,
+            pktable,
+            chaincode,
+            presigs: Vec::new(),
+        })
+    }
+
+    // encrypts the output of to_bytes under a key derived from the passphrase, for storage at rest
+    pub fn to_encrypted_bytes(
+        &self,
+        passphrase: &[u8],
+        rng: &mut dyn Rng,
+    ) -> Result<Vec<u8>, MPECDSAError> {
+        let mut sealed = self.to_bytes();
+        let encrypted = persist::encrypt(&sealed, passphrase, rng);
+        wipe_bytes(&mut sealed);
+        encrypted
+    }
+
+    pub fn from_encrypted_bytes(data: &[u8], passphrase: &[u8]) -> Result<Alice2P, MPECDSAError> {
+        let mut sealed = persist::decrypt(persist::PersistKind::Alice2P, data, passphrase)?;
+        let res = GenericAlice2P::from_bytes(&sealed);
+        wipe_bytes(&mut sealed);
+        res
+    }
+
+    // the extended public key for the root key, from which child keys can be derived
+    pub fn xpub(&self) -> ExtendedPublicKey {
+        ExtendedPublicKey::new(&self.pk, &self.chaincode)
+    }
+
+    // Signs a digest under a key derived from xpub(). Bob must sign with the same key.
+    pub fn sign_derived<TR: Read, TW: Write + Send>(
+        &self,
+        key: &ExtendedPublicKey,
+        digest: &[u8; HASH_SIZE],
+        rng: &mut dyn Rng,
+        recv: &mut TR,
+        send: &mut TW,
+    ) -> Result<(), MPECDSAError> {
+        let key = check_derived_key(&self.pk, key)?;
+        self.sign_with_key(Some(&key), digest, rng, recv, send)
+    }
+}
+
+impl<C: Curve> GenericBob2P<C> {
     pub fn new<TR: Read, TW: Write>(
-        skb: &SecpOrd,
+        skb: &C::Scalar,
         rng: &mut dyn Rng,
         recv: &mut TR,
         send: &mut TW,
-    ) -> Result<Bob2P, MPECDSAError> {
+    ) -> Result<GenericBob2P<C>, MPECDSAError> {
         let ro = GroupROTagger::from_network_unverified(
             1,
             rng,
@@ -286,19 +683,21 @@ impl Bob2P {
             &mut [Some(send), None],
         )?;
 
-        // recv PoK commitment
+        // recv PoK and chain code commitments
         let mut proofcommitment = [0u8; 32];
         recv.read_exact(&mut proofcommitment)?;
+        let mut chaincodecom = [0u8; HASH_SIZE];
+        recv.read_exact(&mut chaincodecom)?;
 
         // send pk_b
-        let pkb = Secp::scalar_table_multi(&precomp::P256_TABLE[..], &skb).affine();
-        let mut buf = [0u8; Secp::NBYTES];
-        pkb.to_bytes(&mut buf);
-        send.write(&buf)?;
+        let pkb = C::Point::scalar_table_multi_ct(C::gen_table(), &skb).affine();
+        let mut buf = [0u8; POINT_SIZE];
+        pkb.to_wire_bytes(&mut buf);
+        send.write_all(&buf)?;
         send.flush()?;
 
         // prove dl for pk_b
-        prove_dl_fs(
+        prove_dl_fs::<C, _>(
             &skb,
             &pkb,
             &ModelessGroupROTagger::new(&ro, false),
@@ -306,36 +705,64 @@ impl Bob2P {
             send,
         )?;
 
+        // send bob's contribution to the chain code; alice is already committed to hers
+        let mut chaincode = [0u8; CHAINCODE_SIZE];
+        rng.fill_bytes(&mut chaincode);
+        send.write_all(&chaincode)?;
+        send.flush()?;
+
         // recv pk_a
         recv.read_exact(&mut buf)?;
-        let pka: Secp = Secp::from_bytes(&buf);
+        let pka: C::Point = C::Point::from_wire_bytes_finite(&buf, 0, ProtocolPhase::Setup)?;
 
-        let proofresult = verify_dl_fs_with_com(
+        let proofresult = verify_dl_fs_with_com::<C, _>(
             &pka,
             &proofcommitment,
             &ModelessDyadicROTagger::new(&ro.get_dyadic_tagger(0).unwrap(), false),
             recv,
+            ProtocolPhase::Setup,
         );
 
+        // recv alice's contribution to the chain code, and check it against her commitment
+        let mut chaincode_raw = [0u8; CHAINCODE_SIZE + RO_TAG_SIZE];
+        recv.read_exact(&mut chaincode_raw[RO_TAG_SIZE..])?;
+        chaincode_raw[0..RO_TAG_SIZE].copy_from_slice(&ro.next_counterparty_broadcast_tag(0)?[..]);
+        let mut chaincodecomcomp = [0u8; HASH_SIZE];
+        hash(&mut chaincodecomcomp, &chaincode_raw);
+        if chaincodecomcomp != chaincodecom {
+            return Err(MPECDSAError::Blame(BlameError::new(
+                0,
+                ProtocolPhase::Setup,
+                "decommitted incorrectly to chain code (alice cheated)",
+            )));
+        }
+        for ii in 0..CHAINCODE_SIZE {
+            chaincode[ii] ^= chaincode_raw[RO_TAG_SIZE + ii];
+        }
+
         // initialize multiplication
-        let mul = mul::MulRecver::new(&ro.get_dyadic_tagger(0).unwrap(), rng, recv, send)?;
+        let mul = mul::GenericMulRecver::new(&ro.get_dyadic_tagger(0).unwrap(), rng, recv, send)?;
 
         // verify PoK to which alice previously committed, then calc pk, setup OT exts
         match proofresult {
             Ok(true) => {
-                let pk = pka.scalar_table(&skb).affine();
-                let pktable = Secp::precomp_table(&pk);
-                let res = Bob2P {
-                    ro: ro,
+                let pk = pka.scalar_ct(&skb).affine();
+                let pktable = C::Point::precomp_table(&pk);
+                let res = GenericBob2P {
+                    ro,
                     multiplier: mul,
                     skb: skb.clone(),
-                    pk: pk,
-                    pktable: pktable,
+                    pk,
+                    pktable,
+                    chaincode,
+                    presigs: Vec::new(),
                 };
 
                 Ok(res)
             }
-            Ok(false) => Err(MPECDSAError::Proof(ProofError::new(
+            Ok(false) => Err(MPECDSAError::Blame(BlameError::new(
+                0,
+                ProtocolPhase::Setup,
                 "Proof of Knowledge failed for ECDSA secret key (alice cheated)",
             ))),
             Err(e) => Err(e),
@@ -348,17 +775,111 @@ impl Bob2P {
         rng: &mut dyn Rng,
         recv: &mut TR,
         send: &mut TW,
-    ) -> Result<(SecpOrd, SecpOrd), MPECDSAError> {
+    ) -> Result<Signature<C>, MPECDSAError> {
+        let mut digest = [0u8; HASH_SIZE];
+        ecdsa_hash(&mut digest, msg);
+        self.sign_prehashed(&digest, rng, recv, send)
+    }
+
+    // see Alice2P::sign_prehashed
+    pub fn sign_prehashed<TR: Read, TW: Write>(
+        &self,
+        digest: &[u8; HASH_SIZE],
+        rng: &mut dyn Rng,
+        recv: &mut TR,
+        send: &mut TW,
+    ) -> Result<Signature<C>, MPECDSAError> {
+        self.sign_with_key(None, digest, rng, recv, send)
+    }
+
+    fn sign_with_key<TR: Read, TW: Write>(
+        &self,
+        key: Option<&DerivedKey<C>>,
+        digest: &[u8; HASH_SIZE],
+        rng: &mut dyn Rng,
+        recv: &mut TR,
+        send: &mut TW,
+    ) -> Result<Signature<C>, MPECDSAError> {
+        let presig = self.presign_inner(rng, recv, send)?;
+        Ok(self
+            .sign_presigned_inner(&presig, key, digest, recv)?
+            .normalize_s())
+    }
+
+    // see Alice2P::sign_with_return
+    pub fn sign_with_return<TR: Read, TW: Write>(
+        &self,
+        msg: &[u8],
+        rng: &mut dyn Rng,
+        recv: &mut TR,
+        send: &mut TW,
+    ) -> Result<Signature<C>, MPECDSAError> {
+        let mut digest = [0u8; HASH_SIZE];
+        ecdsa_hash(&mut digest, msg);
+        let presig = self.presign_inner(rng, recv, send)?;
+        let sig = self.sign_presigned_inner(&presig, None, &digest, recv)?;
+        send_signature::<C, _>(&sig, send)?;
+        Ok(sig.normalize_s())
+    }
+
+    // see Alice2P::presign
+    pub fn presign<TR: Read, TW: Write>(
+        &mut self,
+        rng: &mut dyn Rng,
+        recv: &mut TR,
+        send: &mut TW,
+    ) -> Result<Presig2P<C>, MPECDSAError> {
+        let presig = self.presign_inner(rng, recv, send)?;
+        self.presigs.push(presig.id);
+        Ok(presig)
+    }
+
+    pub fn sign_presigned<TR: Read>(
+        &mut self,
+        presig: Presig2P<C>,
+        msg: &[u8],
+        recv: &mut TR,
+    ) -> Result<Signature<C>, MPECDSAError> {
+        take_presig(&mut self.presigs, &presig)?;
+        let mut digest = [0u8; HASH_SIZE];
+        ecdsa_hash(&mut digest, msg);
+        Ok(self
+            .sign_presigned_inner(&presig, None, &digest, recv)?
+            .normalize_s())
+    }
+
+    // see Alice2P::sign_presigned_with_return
+    pub fn sign_presigned_with_return<TR: Read, TW: Write>(
+        &mut self,
+        presig: Presig2P<C>,
+        msg: &[u8],
+        recv: &mut TR,
+        send: &mut TW,
+    ) -> Result<Signature<C>, MPECDSAError> {
+        take_presig(&mut self.presigs, &presig)?;
+        let mut digest = [0u8; HASH_SIZE];
+        ecdsa_hash(&mut digest, msg);
+        let sig = self.sign_presigned_inner(&presig, None, &digest, recv)?;
+        send_signature::<C, _>(&sig, send)?;
+        Ok(sig.normalize_s())
+    }
+
+    fn presign_inner<TR: Read, TW: Write>(
+        &self,
+        rng: &mut dyn Rng,
+        recv: &mut TR,
+        send: &mut TW,
+    ) -> Result<Presig2P<C>, MPECDSAError> {
         let mut bufsend = BufWriter::new(send);
 
         // no precomputation - we want to begin writing as soon as possible
 
         // choose k_b, calc D_b = k_b*G, send D_b
-        let kb = SecpOrd::rand(rng);
-        let db = Secp::scalar_table_multi(&precomp::P256_TABLE[..], &kb);
-        let mut dbraw = [0u8; Secp::NBYTES];
-        db.to_bytes(&mut dbraw);
-        bufsend.write(&dbraw)?;
+        let kb = C::Scalar::rand(rng);
+        let db = C::Point::scalar_table_multi_ct(C::gen_table(), &kb);
+        let mut dbraw = [0u8; POINT_SIZE];
+        db.to_wire_bytes(&mut dbraw);
+        bufsend.write_all(&dbraw)?;
         bufsend.flush()?;
 
         let dro = self.ro.get_dyadic_tagger(0).unwrap();
@@ -377,55 +898,55 @@ impl Bob2P {
         // end first message (bob to alice)
 
         // receive D'_a from alice, calculate D_a as D_a = H(D'_a)*G + D'_a
-        let mut rprimeraw = [0u8; Secp::NBYTES + RO_TAG_SIZE];
+        let mut rprimeraw = [0u8; POINT_SIZE + RO_TAG_SIZE];
         recv.read_exact(&mut rprimeraw[RO_TAG_SIZE..])?;
-        let rprime = Secp::from_bytes(&rprimeraw[RO_TAG_SIZE..]);
+        let rprime =
+            C::Point::from_wire_bytes_finite(&rprimeraw[RO_TAG_SIZE..], 0, ProtocolPhase::Sign)?;
         rprimeraw[0..RO_TAG_SIZE].copy_from_slice(&rprime_tag);
         let mut kaoffsetraw = [0u8; HASH_SIZE];
         hash(&mut kaoffsetraw, &rprimeraw);
-        let kaoffset = SecpOrd::from_bytes(&kaoffsetraw);
-        let kbkaoffsetg = Secp::scalar_table_multi(&precomp::P256_TABLE[..], &kb.mul(&kaoffset));
+        let kaoffset = C::Scalar::from_bytes(&kaoffsetraw);
+        let kbkaoffsetg = C::Point::scalar_table_multi_ct(C::gen_table(), &kb.mul(&kaoffset));
 
         // compute R = k_a*k_b*G, and get the x coordinate
-        let r = Secp::op(&kbkaoffsetg, &rprime).affine();
-        let mut rxb = [0u8; SecpOrd::NBYTES];
-        r.x.to_bytes(&mut rxb);
-        let rx = SecpOrd::from_bytes(&rxb);
-        let r_table = Secp::precomp_table(&r);
+        let r = C::Point::op(&kbkaoffsetg, &rprime).affine();
+        let mut rxb = [0u8; SCALAR_SIZE];
+        r.x().to_bytes(&mut rxb);
+        let rx = C::Scalar::from_bytes(&rxb);
+        let r_table = C::Point::precomp_table(&r);
 
         // verify alice's PoK of k_a for R
-        let mut kaproof_buf = [0u8; 2 * Secp::NBYTES + SecpOrd::NBYTES + RO_TAG_SIZE];
-        r.to_bytes(&mut kaproof_buf[RO_TAG_SIZE..(Secp::NBYTES + RO_TAG_SIZE)]);
-        recv.read_exact(&mut kaproof_buf[(RO_TAG_SIZE + Secp::NBYTES)..])?;
-        let kaproof_randcommitment = Secp::from_bytes(
-            &kaproof_buf[(RO_TAG_SIZE + Secp::NBYTES)..(2 * Secp::NBYTES + RO_TAG_SIZE)],
-        );
-        let kaproof_z = SecpOrd::from_bytes(&kaproof_buf[(RO_TAG_SIZE + 2 * Secp::NBYTES)..]);
+        let mut kaproof_buf = [0u8; 2 * POINT_SIZE + SCALAR_SIZE + RO_TAG_SIZE];
+        r.to_wire_bytes(&mut kaproof_buf[RO_TAG_SIZE..(POINT_SIZE + RO_TAG_SIZE)]);
+        recv.read_exact(&mut kaproof_buf[(RO_TAG_SIZE + POINT_SIZE)..])?;
+        let kaproof_randcommitment = C::Point::from_wire_bytes(
+            &kaproof_buf[(RO_TAG_SIZE + POINT_SIZE)..(2 * POINT_SIZE + RO_TAG_SIZE)],
+            0,
+            ProtocolPhase::Sign,
+        )?;
+        let kaproof_z = C::Scalar::from_bytes(&kaproof_buf[(RO_TAG_SIZE + 2 * POINT_SIZE)..]);
         let mut kaproof_challenge = [0u8; HASH_SIZE];
         kaproof_buf[0..RO_TAG_SIZE].copy_from_slice(&kaproof_tag);
         hash(
             &mut kaproof_challenge,
-            &kaproof_buf[0..(2 * Secp::NBYTES + RO_TAG_SIZE)],
+            &kaproof_buf[0..(2 * POINT_SIZE + RO_TAG_SIZE)],
         );
-        let kaproof_challenge = SecpOrd::from_bytes(&kaproof_challenge[..]);
-        let kaproof_lhs = Secp::op(
-            &Secp::scalar_table_multi(&r_table[..], &kaproof_challenge),
+        let kaproof_challenge = C::Scalar::from_bytes(&kaproof_challenge[..]);
+        let kaproof_lhs = C::Point::op(
+            &C::Point::scalar_table_multi(&r_table[..], &kaproof_challenge),
             &kaproof_randcommitment,
         )
         .affine();
         let kaproof_rhs =
-            Secp::scalar_table_multi(&precomp::P256_TABLE[..], &kaproof_z.mul(&kb)).affine();
+            C::Point::scalar_table_multi_ct(C::gen_table(), &kaproof_z.mul(&kb)).affine();
         if kaproof_lhs != kaproof_rhs {
-            return Err(MPECDSAError::Proof(ProofError::new(
+            return Err(MPECDSAError::Blame(BlameError::new(
+                0,
+                ProtocolPhase::Sign,
                 "Proof of Knowledge failed for ECDSA signing (alice cheated)",
             )));
         }
 
-        // hash message
-        let mut z = [0u8; HASH_SIZE];
-        ecdsa_hash(&mut z, msg);
-        let z = SecpOrd::from_bytes(&z);
-
         // perform multiplications using the extensions we just generated
         let t1b = self.multiplier.mul_transfer(
             &[&extensions.0[0]],
@@ -435,9 +956,9 @@ impl Bob2P {
             &dro,
             recv,
         )?[0];
-        let gamma1 = Secp::scalar_table_multi(&r_table[..], &t1b).affine(); // start calculating gamma_b early, to give the sender extra time
-        let mut gamma1raw = [0u8; Secp::NBYTES + RO_TAG_SIZE];
-        gamma1.to_bytes(&mut gamma1raw[RO_TAG_SIZE..]);
+        let gamma1 = C::Point::scalar_table_multi_ct(&r_table[..], &t1b).affine(); // start calculating gamma_b early, to give the sender extra time
+        let mut gamma1raw = [0u8; POINT_SIZE + RO_TAG_SIZE];
+        gamma1.to_wire_bytes(&mut gamma1raw[RO_TAG_SIZE..]);
         gamma1raw[0..RO_TAG_SIZE].copy_from_slice(&dro.next_dyadic_tag());
         let mut enckey = [0u8; HASH_SIZE];
         hash(&mut enckey, &gamma1raw);
@@ -451,58 +972,176 @@ impl Bob2P {
         )?[0];
 
         // compute the first check messages Gamma_1, and decrypt the pad
-        let mut kapadraw = [0u8; SecpOrd::NBYTES];
+        let mut kapadraw = [0u8; SCALAR_SIZE];
         recv.read_exact(&mut kapadraw)?;
-        for ii in 0..SecpOrd::NBYTES {
+        for ii in 0..SCALAR_SIZE {
             kapadraw[ii] ^= enckey[ii];
         }
-        let kapad = SecpOrd::from_bytes(&kapadraw);
+        let kapad = C::Scalar::from_bytes(&kapadraw);
 
         let t1baug = t1b.sub(&kbi.mul(&kapad));
-        let t2bg = Secp::scalar_table_multi(&precomp::P256_TABLE[..], &t2b);
-        let t1bpk = Secp::scalar_table_multi(&self.pktable[..], &t1baug.neg());
-        let gamma2 = Secp::op(&t2bg, &t1bpk).affine();
-        let mut gamma2raw = [0u8; Secp::NBYTES + RO_TAG_SIZE];
-        gamma2.to_bytes(&mut gamma2raw[RO_TAG_SIZE..]);
+        let t2bg = C::Point::scalar_table_multi_ct(C::gen_table(), &t2b);
+        let t1bpk = C::Point::scalar_table_multi_ct(&self.pktable[..], &t1baug.neg());
+        let gamma2 = C::Point::op(&t2bg, &t1bpk).affine();
+        let mut gamma2raw = [0u8; POINT_SIZE + RO_TAG_SIZE];
+        gamma2.to_wire_bytes(&mut gamma2raw[RO_TAG_SIZE..]);
         gamma2raw[0..RO_TAG_SIZE].copy_from_slice(&dro.next_dyadic_tag());
         hash(&mut enckey, &gamma2raw);
 
+        // end second message (alice to bob)
+
+        Ok(Presig2P {
+            id: dro.next_dyadic_tag(),
+            rx,
+            recid: ecdsa::recovery_id(&r),
+            t1: t1baug,
+            t2: t2b,
+            enckey,
+        })
+    }
+
+    fn sign_presigned_inner<TR: Read>(
+        &self,
+        presig: &Presig2P<C>,
+        key: Option<&DerivedKey<C>>,
+        digest: &[u8; HASH_SIZE],
+        recv: &mut TR,
+    ) -> Result<Signature<C>, MPECDSAError> {
+        // alice must have used the matching presignature
+        let mut id = [0u8; RO_TAG_SIZE];
+        recv.read_exact(&mut id)?;
+        if id != presig.id {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Counterparty signed with a different presignature",
+            )));
+        }
+
+        let z = C::Scalar::from_bytes(digest);
+        let rx = presig.rx;
+        let t2 = presig.t2.add(&derived_tweak::<C>(key).mul(&presig.t1));
+
         // compute bob's signature share m_b
-        let m_b = t1baug.mul(&z).add(&t2b.mul(&rx));
+        let m_b = presig.t1.mul(&z).add(&t2.mul(&rx));
 
         // receive alice's signature share m_a, and decrypt using expected key
-        let mut ma = [0u8; SecpOrd::NBYTES];
+        let mut ma = [0u8; SCALAR_SIZE];
         recv.read_exact(&mut ma)?;
-        for ii in 0..SecpOrd::NBYTES {
-            ma[ii] ^= enckey[ii];
+        for ii in 0..SCALAR_SIZE {
+            ma[ii] ^= presig.enckey[ii];
         }
-        let m_a = SecpOrd::from_bytes(&ma);
+        let m_a = C::Scalar::from_bytes(&ma);
 
         // reconstruct signature
         let s = m_a.add(&m_b);
 
-        // end second message (alice to bob)
+        // end third message (alice to bob)
 
         // verify signature. Abort if it's incorrect.
-        if ecdsa::ecdsa_verify_with_tables(msg, (&rx, &s), &precomp::P256_TABLE, &self.pktable[..])
-        {
-            Ok((rx, s))
+        if verify_with_key::<C>(digest, (&rx, &s), &self.pktable[..], key) {
+            Ok(ecdsa::Signature {
+                r: rx,
+                s,
+                recid: presig.recid,
+            })
         } else {
-            Err(MPECDSAError::Proof(ProofError::new(
+            Err(MPECDSAError::Blame(BlameError::new(
+                0,
+                ProtocolPhase::Sign,
                 "Signature verification failed for ECDSA signing (alice cheated)",
             )))
         }
     }
 }
 
-impl ThresholdSigner {
+// see Alice2P<Secp256k1>
+impl GenericBob2P<Secp256k1> {
+    // see Alice2P::to_bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut body = Vec::new();
+        // we perform only local IO, so there should never be an error
+        persist::write_scalar(&self.skb, &mut body).unwrap();
+        persist::write_point(&self.pk, &mut body).unwrap();
+        body.write_all(&self.chaincode).unwrap();
+        self.ro.write_to(&mut body).unwrap();
+        self.multiplier.write_to(&mut body).unwrap();
+        let sealed = persist::seal(persist::PersistKind::Bob2P, &body);
+        wipe_bytes(&mut body);
+        sealed
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Result<Bob2P, MPECDSAError> {
+        let mut body = Cursor::new(persist::unseal(persist::PersistKind::Bob2P, data)?);
+        let skb = persist::read_scalar(&mut body)?;
+        let pk = persist::read_point(&mut body)?;
+        let mut chaincode = [0u8; CHAINCODE_SIZE];
+        body.read_exact(&mut chaincode)?;
+        let ro = GroupROTagger::read_from(&mut body)?;
+        if ro.get_supergroup_party_count() != 2 || ro.get_playerindex() != 1 {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Serialized Random Oracle tagger does not belong to Bob",
+            )));
+        }
+        let multiplier = mul::GenericMulRecver::read_from(&mut body)?;
+        persist::finish_body(&body)?;
+
+        let pktable = Secp::precomp_table(&pk);
+        Ok(GenericBob2P {
+            ro,
+            multiplier,
+            skb,
+            pk,
+            pktable,
+            chaincode,
+            presigs: Vec::new(),
+        })
+    }
+
+    // encrypts the output of to_bytes under a key derived from the passphrase, for storage at rest
+    pub fn to_encrypted_bytes(
+        &self,
+        passphrase: &[u8],
+        rng: &mut dyn Rng,
+    ) -> Result<Vec<u8>, MPECDSAError> {
+        let mut sealed = self.to_bytes();
+        let encrypted = persist::encrypt(&sealed, passphrase, rng);
+        wipe_bytes(&mut sealed);
+        encrypted
+    }
+
+    pub fn from_encrypted_bytes(data: &[u8], passphrase: &[u8]) -> Result<Bob2P, MPECDSAError> {
+        let mut sealed = persist::decrypt(persist::PersistKind::Bob2P, data, passphrase)?;
+        let res = GenericBob2P::from_bytes(&sealed);
+        wipe_bytes(&mut sealed);
+        res
+    }
+
+    // see Alice2P::xpub
+    pub fn xpub(&self) -> ExtendedPublicKey {
+        ExtendedPublicKey::new(&self.pk, &self.chaincode)
+    }
+
+    // see Alice2P::sign_derived
+    pub fn sign_derived<TR: Read, TW: Write>(
+        &self,
+        key: &ExtendedPublicKey,
+        digest: &[u8; HASH_SIZE],
+        rng: &mut dyn Rng,
+        recv: &mut TR,
+        send: &mut TW,
+    ) -> Result<Signature, MPECDSAError> {
+        let key = check_derived_key(&self.pk, key)?;
+        self.sign_with_key(Some(&key), digest, rng, recv, send)
+    }
+}
+
+impl<C: Curve> GenericThresholdSigner<C> {
     pub fn new<TR: Read + Send, TW: Write + Send>(
         playerindex: usize,
         threshold: usize,
         rng: &mut dyn Rng,
         recv: &mut [Option<TR>],
         send: &mut [Option<TW>],
-    ) -> Result<ThresholdSigner, MPECDSAError> {
+    ) -> Result<GenericThresholdSigner<C>, MPECDSAError> {
         if recv.len() != send.len() {
             return Err(MPECDSAError::General(GeneralError::new(
                 "Number of Send streams does not match number of Recv streams",
@@ -523,34 +1162,55 @@ impl ThresholdSigner {
             )?
         };
 
-        let sk_frag = SecpOrd::rand(rng);
+        let sk_frag = C::Scalar::rand(rng);
+        // with an honest majority there are no proofs of knowledge, so fragments are verified against
+        // Feldman commitments to each dealer's polynomial instead
+        let honest_majority = threshold < playercount / 2;
 
         // Random polynomial for shamir secret sharing.
         // This polynomial represents my secret; we will sum all the polynomials later to sum the secret.
         // Note that we generate k-1 coefficients; the last is the secret
-        let mut coefficients: Vec<SecpOrd> = Vec::with_capacity(threshold);
+        let mut coefficients: Vec<C::Scalar> = Vec::with_capacity(threshold);
         coefficients.push(sk_frag.clone());
         for _ in 1..threshold {
-            coefficients.push(SecpOrd::rand(rng));
+            coefficients.push(C::Scalar::rand(rng));
         }
 
         // poly_point will later be my point on the shared/summed polynomial. Create it early
         // so that the component from my own individual polynomial can be added.
-        let mut poly_point = SecpOrd::ZERO;
+        let mut poly_point = C::Scalar::ZERO;
+        let mut coefficients_com: Vec<Vec<C::Point>> = vec![Vec::new(); playercount];
+        let mut coefficients_com_raw = vec![0u8; threshold * POINT_SIZE];
+        if honest_majority {
+            for (jj, coefficient) in coefficients.iter().enumerate() {
+                let coefficient_com =
+                    C::Point::scalar_table_multi_ct(C::gen_table(), coefficient).affine();
+                coefficient_com.to_wire_bytes(
+                    &mut coefficients_com_raw[(jj * POINT_SIZE)..((jj + 1) * POINT_SIZE)],
+                );
+                coefficients_com[playerindex].push(coefficient_com);
+            }
+        }
         // evaluate my polynomial once for each player, and send everyone else their fragment
         for ii in 0..playercount {
             let mut poly_frag = coefficients[coefficients.len() - 1];
             for jj in (0..(coefficients.len() - 1)).rev() {
                 poly_frag = poly_frag
-                    .mul(&SecpOrd::from_native((ii + 1) as u64))
+                    .mul(&C::Scalar::from_native((ii + 1) as u64))
                     .add(&coefficients[jj]);
             }
             if ii == playerindex {
                 poly_point = poly_frag;
             } else {
-                let mut poly_frag_raw = [0u8; SecpOrd::NBYTES];
+                let mut poly_frag_raw = [0u8; SCALAR_SIZE];
                 poly_frag.to_bytes(&mut poly_frag_raw);
-                send[ii].as_mut().unwrap().write(&poly_frag_raw)?;
+                if honest_majority {
+                    send[ii]
+                        .as_mut()
+                        .unwrap()
+                        .write_all(&coefficients_com_raw)?;
+                }
+                send[ii].as_mut().unwrap().write_all(&poly_frag_raw)?;
                 send[ii].as_mut().unwrap().flush()?;
             }
         }
@@ -558,21 +1218,44 @@ impl ThresholdSigner {
         // recieve polynomial fragments from each player, and sum them to find my point on the shared/summed polynomial
         for ii in 0..playercount {
             if ii != playerindex {
-                let mut poly_frag_raw = [0u8; SecpOrd::NBYTES];
+                let mut poly_frag_raw = [0u8; SCALAR_SIZE];
+                if honest_majority {
+                    recv[ii]
+                        .as_mut()
+                        .unwrap()
+                        .read_exact(&mut coefficients_com_raw)?;
+                    for jj in 0..threshold {
+                        coefficients_com[ii].push(C::Point::from_wire_bytes(
+                            &coefficients_com_raw[(jj * POINT_SIZE)..((jj + 1) * POINT_SIZE)],
+                            ii,
+                            ProtocolPhase::Setup,
+                        )?);
+                    }
+                }
                 recv[ii].as_mut().unwrap().read_exact(&mut poly_frag_raw)?;
-                let poly_frag = SecpOrd::from_bytes(&poly_frag_raw);
+                let poly_frag = C::Scalar::from_bytes(&poly_frag_raw);
+                if honest_majority
+                    && C::Point::scalar_table_multi_ct(C::gen_table(), &poly_frag).affine()
+                        != feldman_eval::<C>(&coefficients_com[ii], playerindex)
+                {
+                    return Err(MPECDSAError::Blame(BlameError::new(
+                        ii,
+                        ProtocolPhase::Setup,
+                        "dealt a share inconsistent with its commitments",
+                    )));
+                }
                 poly_point = poly_point.add(&poly_frag);
             }
         }
 
-        let mut points_com: Vec<Secp> = Vec::with_capacity(playercount);
-        let mut pk = Secp::INF;
+        let mut points_com: Vec<C::Point> = Vec::with_capacity(playercount);
+        let mut pk = C::Point::INF;
 
-        if threshold >= playercount / 2 {
+        if !honest_majority {
             // calculate p(playerindex)*G, an EC point with my polynomial point in the exponent, and broadcast it to everyone
             // in the dishonest majority case, we also need a PoK
-            let point_com = Secp::scalar_table_multi(&precomp::P256_TABLE, &poly_point);
-            let (proofcommitment, proof) = prove_dl_fs_to_com(
+            let point_com = C::Point::scalar_table_multi_ct(C::gen_table(), &poly_point);
+            let (proofcommitment, proof) = prove_dl_fs_to_com::<C>(
                 &poly_point,
                 &point_com,
                 &ModelessGroupROTagger::new(&ro, false),
@@ -580,7 +1263,7 @@ impl ThresholdSigner {
             )?;
             for ii in 0..playercount {
                 if ii != playerindex {
-                    send[ii].as_mut().unwrap().write(&proofcommitment)?;
+                    send[ii].as_mut().unwrap().write_all(&proofcommitment)?;
                     send[ii].as_mut().unwrap().flush()?;
                 }
             }
@@ -595,12 +1278,12 @@ impl ThresholdSigner {
                 }
             }
             // when all commitments are in, release the proof
-            let mut point_com_raw = [0u8; Secp::NBYTES];
-            point_com.to_bytes(&mut point_com_raw);
+            let mut point_com_raw = [0u8; POINT_SIZE];
+            point_com.to_wire_bytes(&mut point_com_raw);
             for ii in 0..playercount {
                 if ii != playerindex {
-                    send[ii].as_mut().unwrap().write(&point_com_raw)?;
-                    send[ii].as_mut().unwrap().write(&proof)?;
+                    send[ii].as_mut().unwrap().write_all(&point_com_raw)?;
+                    send[ii].as_mut().unwrap().write_all(&proof)?;
                     send[ii].as_mut().unwrap().flush()?;
                 }
             }
@@ -610,66 +1293,99 @@ impl ThresholdSigner {
                     points_com.push(point_com);
                 } else {
                     recv[ii].as_mut().unwrap().read_exact(&mut point_com_raw)?;
-                    let this_point_com = Secp::from_bytes(&point_com_raw);
-                    if verify_dl_fs_with_com(
+                    let this_point_com =
+                        C::Point::from_wire_bytes(&point_com_raw, ii, ProtocolPhase::Setup)?;
+                    if verify_dl_fs_with_com::<C, _>(
                         &this_point_com,
                         &othercommitments[ii],
                         &ModelessDyadicROTagger::new(&ro.get_dyadic_tagger(ii).unwrap(), false),
                         &mut recv[ii].as_mut().unwrap(),
+                        ProtocolPhase::Setup,
                     )? {
                         points_com.push(this_point_com);
                     } else {
-                        return Err(MPECDSAError::Proof(ProofError::new(&format!(
-                            "Proof of Knowledge failed for player {}'s public key fragment",
-                            ii
-                        ))));
+                        return Err(MPECDSAError::Blame(BlameError::new(
+                            ii,
+                            ProtocolPhase::Setup,
+                            "Proof of Knowledge failed for the public key fragment",
+                        )));
                     }
                 }
             }
         } else {
-            // calculate p(playerindex)*G, an EC point with my polynomial point in the exponent, and broadcast it to everyone
-            let point_com = Secp::scalar_table_multi(&precomp::P256_TABLE, &poly_point);
-            let mut point_com_raw = [0u8; Secp::NBYTES];
-            point_com.to_bytes(&mut point_com_raw);
+            // calculate p(playerindex)*G, an EC point with my polynomial point in the exponent, and broadcast it to everyone,
+            // along with a hash of each dealer's polynomial commitments, so that a dealer who equivocates is detected
+            let point_com = C::Point::scalar_table_multi_ct(C::gen_table(), &poly_point);
+            let coms_hashes = commitment_hashes::<C>(&coefficients_com);
+            let mut point_com_raw = vec![0u8; POINT_SIZE + playercount * HASH_SIZE];
+            point_com.to_wire_bytes(&mut point_com_raw[0..POINT_SIZE]);
+            point_com_raw[POINT_SIZE..].copy_from_slice(&coms_hashes.concat());
             for ii in 0..playercount {
                 if ii != playerindex {
-                    send[ii].as_mut().unwrap().write(&point_com_raw)?;
+                    send[ii].as_mut().unwrap().write_all(&point_com_raw)?;
                     send[ii].as_mut().unwrap().flush()?;
                 }
             }
 
-            // receive commitments to everyone's polynomial points
+            // receive commitments to everyone's polynomial points, and check them against the polynomial commitments
             for ii in 0..playercount {
                 if ii == playerindex {
                     points_com.push(point_com);
                 } else {
                     recv[ii].as_mut().unwrap().read_exact(&mut point_com_raw)?;
-                    points_com.push(Secp::from_bytes(&point_com_raw));
+                    check_commitment_echo(
+                        &coms_hashes,
+                        &point_com_raw[POINT_SIZE..],
+                        &(0..playercount).collect::<Vec<usize>>(),
+                        playerindex,
+                        ii,
+                        ProtocolPhase::Setup,
+                    )?;
+                    let this_point_com = C::Point::from_wire_bytes(
+                        &point_com_raw[0..POINT_SIZE],
+                        ii,
+                        ProtocolPhase::Setup,
+                    )?;
+                    let mut expected_point_com = C::Point::INF;
+                    for jj in 0..playercount {
+                        expected_point_com = C::Point::op(
+                            &expected_point_com,
+                            &feldman_eval::<C>(&coefficients_com[jj], ii),
+                        );
+                    }
+                    if this_point_com != expected_point_com.affine() {
+                        return Err(MPECDSAError::Blame(BlameError::new(
+                            ii,
+                            ProtocolPhase::Setup,
+                            "broadcast a point inconsistent with the polynomial commitments",
+                        )));
+                    }
+                    points_com.push(this_point_com);
                 }
             }
         }
 
         // for each contiguous set of parties, perform shamir reconsruction in the exponent and check the result against the known pk
         for ii in 0..(playercount - threshold + 1) {
-            let mut recon_sum = Secp::INF;
+            let mut recon_coefs = Vec::with_capacity(threshold);
             for jj in 0..threshold {
-                let mut coefnum = SecpOrd::ONE;
-                let mut coefdenom = SecpOrd::ONE;
+                let mut coefnum = C::Scalar::ONE;
+                let mut coefdenom = C::Scalar::ONE;
                 // calculate lagrange coefficient
                 for kk in 0..threshold {
                     if kk != jj {
-                        coefnum = coefnum.mul(&SecpOrd::from_native((ii + kk + 1) as u64));
+                        coefnum = coefnum.mul(&C::Scalar::from_native((ii + kk + 1) as u64));
                         coefdenom = coefdenom.mul(
-                            &SecpOrd::from_native((ii + kk + 1) as u64)
-                                .sub(&SecpOrd::from_native((ii + jj + 1) as u64)),
+                            &C::Scalar::from_native((ii + kk + 1) as u64)
+                                .sub(&C::Scalar::from_native((ii + jj + 1) as u64)),
                         );
                     }
                 }
-                let recon_frag = points_com[ii + jj].scalar_table(&coefnum.mul(&coefdenom.inv()));
-                recon_sum = Secp::op(&recon_sum, &recon_frag);
+                recon_coefs.push(coefnum.mul(&coefdenom.inv()));
             }
-            recon_sum = recon_sum.affine();
-            if pk == Secp::INF {
+            let recon_sum =
+                C::Point::multi_scalar_mul(&points_com[ii..(ii + threshold)], &recon_coefs[..]);
+            if pk == C::Point::INF {
                 pk = recon_sum;
             } else if recon_sum != pk {
                 return Err(MPECDSAError::Proof(ProofError::new(
@@ -678,763 +1394,1784 @@ impl ThresholdSigner {
             }
         }
 
-        // finally, each pair of parties must have multiplier setup between them. The player with the higher index is always Bob.
-        let mut rngs = Vec::with_capacity(playercount);
-        for _ in 0..playercount {
-            let mut newrng = rand::ChaChaRng::new_unseeded();
-            newrng.set_counter(rng.next_u64(), rng.next_u64());
-            rngs.push(newrng);
-        }
+        let chaincode = Self::gen_chaincode(playerindex, &ro, rng, recv, send)?;
 
-        let threadcount = match std::env::var_os("RAYON_NUM_THREADS") {
-            Some(val) => {
-                let val = val.into_string().unwrap().parse().unwrap();
-                if val > 0 {
-                    val
-                } else {
-                    playercount
-                }
-            }
-            None => playercount,
-        };
+        // finally, each pair of parties must have multiplier setup between them.
+        let multipliervec = Self::setup_multipliers(playerindex, &ro, rng, recv, send)?;
 
-        let rayonpool = rayon::ThreadPoolBuilder::new()
+        let pktable = C::Point::precomp_table(&pk);
+        Ok(GenericThresholdSigner {
+            ro,
+            playerindex,
+            threshold,
+            multiplier: multipliervec,
+            poly_point,
+            pk,
+            pktable,
+            chaincode,
+            epoch: 0,
+            refresh_epoch: 0,
+            refreshed: Vec::new(),
+        })
+    }
+
+    // Jointly chooses the BIP-32 chain code for the key. Every party commits to a random contribution
+    // before any is revealed, so the result is uniform as long as one party is honest.
+    fn gen_chaincode<TR: Read, TW: Write>(
+        playerindex: usize,
+        ro: &GroupROTagger,
+        rng: &mut dyn Rng,
+        recv: &mut [Option<TR>],
+        send: &mut [Option<TW>],
+    ) -> Result<[u8; CHAINCODE_SIZE], MPECDSAError> {
+        let playercount = recv.len();
+        let mut chaincode_raw = [0u8; CHAINCODE_SIZE + RO_TAG_SIZE];
+        rng.fill_bytes(&mut chaincode_raw[RO_TAG_SIZE..]);
+        chaincode_raw[0..RO_TAG_SIZE].copy_from_slice(&ro.next_broadcast_tag()[..]);
+        let mut chaincodecoms = vec![[0u8; HASH_SIZE]; playercount];
+        hash(&mut chaincodecoms[playerindex], &chaincode_raw);
+        for ii in 0..playercount {
+            if ii != playerindex {
+                send[ii]
+                    .as_mut()
+                    .unwrap()
+                    .write_all(&chaincodecoms[playerindex])?;
+                send[ii].as_mut().unwrap().flush()?;
+            }
+        }
+        for ii in 0..playercount {
+            if ii != playerindex {
+                recv[ii]
+                    .as_mut()
+                    .unwrap()
+                    .read_exact(&mut chaincodecoms[ii])?;
+            }
+        }
+
+        // when all commitments are in, release the contributions
+        for ii in 0..playercount {
+            if ii != playerindex {
+                send[ii]
+                    .as_mut()
+                    .unwrap()
+                    .write_all(&chaincode_raw[RO_TAG_SIZE..])?;
+                send[ii].as_mut().unwrap().flush()?;
+            }
+        }
+        let mut chaincode = [0u8; CHAINCODE_SIZE];
+        chaincode.copy_from_slice(&chaincode_raw[RO_TAG_SIZE..]);
+        for ii in 0..playercount {
+            if ii != playerindex {
+                recv[ii]
+                    .as_mut()
+                    .unwrap()
+                    .read_exact(&mut chaincode_raw[RO_TAG_SIZE..])?;
+                chaincode_raw[0..RO_TAG_SIZE]
+                    .copy_from_slice(&ro.next_counterparty_broadcast_tag(ii)?[..]);
+                let mut chaincodecomcomp = [0u8; HASH_SIZE];
+                hash(&mut chaincodecomcomp, &chaincode_raw);
+                if chaincodecoms[ii] != chaincodecomcomp {
+                    return Err(MPECDSAError::Blame(BlameError::new(
+                        ii,
+                        ProtocolPhase::Setup,
+                        "decommitted incorrectly to its chain code contribution",
+                    )));
+                }
+                for jj in 0..CHAINCODE_SIZE {
+                    chaincode[jj] ^= chaincode_raw[RO_TAG_SIZE + jj];
+                }
+            }
+        }
+        Ok(chaincode)
+    }
+
+    // sets up a multiplier between each pair of parties. The player with the higher index is always Bob.
+    fn setup_multipliers<TR: Read + Send, TW: Write + Send>(
+        playerindex: usize,
+        ro: &GroupROTagger,
+        rng: &mut dyn Rng,
+        recv: &mut [Option<TR>],
+        send: &mut [Option<TW>],
+    ) -> Result<Vec<MulPlayer<C>>, MPECDSAError> {
+        let playercount = recv.len();
+        let mut rngs = Vec::with_capacity(playercount);
+        for _ in 0..playercount {
+            let mut newrng = rand::ChaChaRng::new_unseeded();
+            newrng.set_counter(rng.next_u64(), rng.next_u64());
+            rngs.push(newrng);
+        }
+
+        let threadcount = match std::env::var_os("RAYON_NUM_THREADS") {
+            Some(val) => {
+                let val = val.into_string().unwrap().parse().unwrap();
+                if val > 0 {
+                    val
+                } else {
+                    playercount
+                }
+            }
+            None => playercount,
+        };
+
+        let rayonpool = rayon::ThreadPoolBuilder::new()
             .num_threads(threadcount)
             .build()
             .unwrap();
-        let multipliervec = rayonpool.install(|| {
+        rayonpool.install(|| {
             send.par_iter_mut()
                 .zip(recv.par_iter_mut())
                 .zip(rngs.par_iter_mut())
                 .enumerate()
                 .map(|(ii, ((sendi, recvi), rngi))| {
-                    if ii > playerindex {
-                        MulPlayer::Sender(
-                            mul::MulSender::new(
-                                &ro.get_dyadic_tagger(ii).unwrap(),
-                                rngi,
-                                recvi.as_mut().unwrap(),
-                                sendi.as_mut().unwrap(),
-                            )
-                            .unwrap(),
-                        )
+                    Ok(if ii > playerindex {
+                        MulPlayer::Sender(mul::GenericMulSender::new(
+                            &ro.get_dyadic_tagger(ii)?,
+                            rngi,
+                            recvi.as_mut().unwrap(),
+                            sendi.as_mut().unwrap(),
+                        )?)
                     } else if ii < playerindex {
-                        MulPlayer::Recver(
-                            mul::MulRecver::new(
-                                &ro.get_dyadic_tagger(ii).unwrap(),
-                                rngi,
-                                recvi.as_mut().unwrap(),
-                                sendi.as_mut().unwrap(),
-                            )
-                            .unwrap(),
-                        )
+                        MulPlayer::Recver(mul::GenericMulRecver::new(
+                            &ro.get_dyadic_tagger(ii)?,
+                            rngi,
+                            recvi.as_mut().unwrap(),
+                            sendi.as_mut().unwrap(),
+                        )?)
                     } else {
                         MulPlayer::Null
-                    }
+                    })
                 })
                 .collect()
-        });
-
-        let pktable = Secp::precomp_table(&pk);
-        Ok(ThresholdSigner {
-            ro: ro,
-            playerindex: playerindex,
-            threshold: threshold,
-            multiplier: multipliervec,
-            poly_point: poly_point,
-            pk: pk,
-            pktable: pktable,
         })
     }
 
-    pub fn sign<TR: Read + Send, TW: Write + Send>(
-        &mut self,
-        counterparties: &[usize],
-        msg: &[u8],
+    // Reshares the key held by an existing set of signers among a new set of parties, with a new threshold,
+    // preserving the public key. The new parties are numbered 0..recv.len(); those listed in dealers must
+    // each supply their old signer, and together they must meet the old threshold. An old signer is wiped
+    // only once its replacement has been built, so that after a failure the caller may try again. Old holders who are
+    // not among the new parties need not participate. Every dealer splits its Lagrange-weighted share
+    // with a fresh polynomial, and commits to the coefficients so that recipients can verify their shares.
+    pub fn reshare<TR: Read + Send, TW: Write + Send>(
+        old: Option<&mut GenericThresholdSigner<C>>,
+        dealers: &[usize],
+        playerindex: usize,
+        threshold: usize,
         rng: &mut dyn Rng,
         recv: &mut [Option<TR>],
         send: &mut [Option<TW>],
-    ) -> Result<Option<(SecpOrd, SecpOrd)>, MPECDSAError> {
-        if counterparties.len() != (self.threshold - 1) {
+    ) -> Result<GenericThresholdSigner<C>, MPECDSAError> {
+        if recv.len() != send.len() {
             return Err(MPECDSAError::General(GeneralError::new(
-                "Number of counterparties does not match threshold.",
+                "Number of Send streams does not match number of Recv streams",
+            )));
+        }
+        let playercount = recv.len();
+        if threshold < 2 || threshold > playercount || playerindex >= playercount {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Invalid threshold or player index for resharing",
+            )));
+        }
+        let mut sorteddealers = dealers.to_vec();
+        sorteddealers.sort();
+        sorteddealers.dedup();
+        if sorteddealers.len() != dealers.len()
+            || dealers.iter().any(|&ii| ii >= playercount)
+            || dealers.contains(&playerindex) != old.is_some()
+        {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Invalid dealer list for resharing",
             )));
         }
 
-        if self.threshold == 2 {
-            let counterparty = counterparties[0];
-            if self.playerindex > counterparty {
-                return Ok(Some(self.sign2t_bob(
-                    counterparty,
-                    msg,
-                    rng,
-                    &mut recv[counterparty].as_mut().unwrap(),
-                    &mut send[counterparty].as_mut().unwrap(),
-                )?));
-            } else if self.playerindex < counterparty {
-                self.sign2t_alice(
-                    counterparty,
-                    msg,
-                    rng,
-                    &mut recv[counterparty].as_mut().unwrap(),
-                    &mut send[counterparty].as_mut().unwrap(),
-                )?;
-                return Ok(None);
-            } else {
-                return Err(MPECDSAError::General(GeneralError::new(
-                    "Tried to sign with self as counterparty.",
+        let ro = {
+            let mut prunedrecv: Vec<Option<&mut TR>> =
+                recv.iter_mut().map(|val| val.as_mut()).collect();
+            let mut prunedsend: Vec<Option<&mut TW>> =
+                send.iter_mut().map(|val| val.as_mut()).collect();
+            GroupROTagger::from_network_unverified(
+                playerindex,
+                rng,
+                &mut prunedrecv[..],
+                &mut prunedsend[..],
+            )?
+        };
+
+        // each dealer announces its old index, the old threshold, the public key, the chain code,
+        // and its epoch, so that refresh packages made for the old shares cannot be applied to the new
+        let mut announce_raw = [0u8; 24 + POINT_SIZE + CHAINCODE_SIZE];
+        if let Some(ref old) = old {
+            LittleEndian::write_u64(&mut announce_raw[0..8], old.playerindex as u64);
+            LittleEndian::write_u64(&mut announce_raw[8..16], old.threshold as u64);
+            old.pk
+                .to_wire_bytes(&mut announce_raw[16..(16 + POINT_SIZE)]);
+            announce_raw[(16 + POINT_SIZE)..(16 + POINT_SIZE + CHAINCODE_SIZE)]
+                .copy_from_slice(&old.chaincode);
+            LittleEndian::write_u64(
+                &mut announce_raw[(16 + POINT_SIZE + CHAINCODE_SIZE)..],
+                old.epoch as u64,
+            );
+            for ii in 0..playercount {
+                if ii != playerindex {
+                    send[ii].as_mut().unwrap().write_all(&announce_raw)?;
+                    send[ii].as_mut().unwrap().flush()?;
+                }
+            }
+        }
+        let mut oldindices = Vec::with_capacity(dealers.len());
+        let mut oldthreshold = 0;
+        let mut pk = C::Point::INF;
+        let mut chaincode = [0u8; CHAINCODE_SIZE];
+        let mut epoch = 0;
+        for &ii in dealers.iter() {
+            let mut dealer_announce_raw = announce_raw;
+            if ii != playerindex {
+                recv[ii]
+                    .as_mut()
+                    .unwrap()
+                    .read_exact(&mut dealer_announce_raw)?;
+            }
+            let dealerpk = C::Point::from_wire_bytes_finite(
+                &dealer_announce_raw[16..(16 + POINT_SIZE)],
+                ii,
+                ProtocolPhase::Reshare,
+            )?;
+            let dealerthreshold = LittleEndian::read_u64(&dealer_announce_raw[8..16]) as usize;
+            let dealerchaincode =
+                &dealer_announce_raw[(16 + POINT_SIZE)..(16 + POINT_SIZE + CHAINCODE_SIZE)];
+            let dealerepoch =
+                LittleEndian::read_u64(&dealer_announce_raw[(16 + POINT_SIZE + CHAINCODE_SIZE)..])
+                    as usize;
+            if oldindices.len() == 0 {
+                pk = dealerpk;
+                oldthreshold = dealerthreshold;
+                chaincode.copy_from_slice(dealerchaincode);
+                epoch = dealerepoch;
+            } else if dealerpk != pk
+                || dealerthreshold != oldthreshold
+                || dealerchaincode != chaincode
+                || dealerepoch != epoch
+            {
+                return Err(MPECDSAError::Blame(BlameError::new(
+                    ii,
+                    ProtocolPhase::Reshare,
+                    "announced a different public key, threshold, chain code, or epoch for resharing",
                 )));
             }
-        } else {
-            let mut parties: Vec<usize> = counterparties.to_vec();
-            parties.push(self.playerindex);
-            parties.sort();
-            return Ok(Some(self.sign_threshold(&parties, msg, rng, recv, send)?));
+            oldindices.push(LittleEndian::read_u64(&dealer_announce_raw[0..8]) as usize);
         }
-    }
-
-    pub fn sign_and_gen_refresh<TR: Read + Send, TW: Write + Send>(
-        &mut self,
-        counterparties: &[usize],
-        msg: &[u8],
-        tag: &[u8],
-        rng: &mut dyn Rng,
-        recv: &mut [Option<TR>],
-        send: &mut [Option<TW>],
-    ) -> Result<(Option<(SecpOrd, SecpOrd)>, ProactiveRefreshPackage), MPECDSAError> {
-        if counterparties.len() != (self.threshold - 1) {
+        let mut sortedoldindices = oldindices.clone();
+        sortedoldindices.sort();
+        sortedoldindices.dedup();
+        if sortedoldindices.len() != oldindices.len() || oldindices.len() < oldthreshold {
             return Err(MPECDSAError::General(GeneralError::new(
-                "Number of counterparties does not match threshold.",
+                "Dealers do not hold enough distinct shares to reshare",
             )));
         }
 
-        if self.threshold == 2 {
-            let counterparty = counterparties[0];
+        // each dealer deals shares of its Lagrange-weighted poly_point on a fresh polynomial,
+        // and commits to the coefficients of that polynomial
+        let mut poly_point = C::Scalar::ZERO;
+        let mut coefficients_com: Vec<Vec<C::Point>> = vec![Vec::new(); dealers.len()];
+        if let Some(ref old) = old {
+            let mydealerindex = dealers.iter().position(|&x| x == playerindex).unwrap();
+            let mut coefnum = C::Scalar::ONE;
+            let mut coefdenom = C::Scalar::ONE;
+            // calculate lagrange coefficient
+            for kk in 0..oldindices.len() {
+                if kk != mydealerindex {
+                    coefnum = coefnum.mul(&C::Scalar::from_native((oldindices[kk] + 1) as u64));
+                    coefdenom = coefdenom.mul(
+                        &C::Scalar::from_native((oldindices[kk] + 1) as u64)
+                            .sub(&C::Scalar::from_native((old.playerindex + 1) as u64)),
+                    );
+                }
+            }
 
-            if self.playerindex > counterparty {
-                let (r, s, p) = self.sign2t_and_gen_refresh_bob(
-                    counterparty,
-                    msg,
-                    Some(tag),
-                    rng,
-                    recv[counterparty].as_mut().unwrap(),
-                    send[counterparty].as_mut().unwrap(),
-                )?;
-                return Ok((Some((r, s)), p.unwrap()));
-            } else if self.playerindex < counterparty {
-                return Ok((
-                    None,
-                    self.sign2t_and_gen_refresh_alice(
-                        counterparty,
-                        msg,
-                        Some(tag),
-                        rng,
-                        recv[counterparty].as_mut().unwrap(),
-                        send[counterparty].as_mut().unwrap(),
-                    )?
-                    .unwrap(),
-                ));
-            } else {
-                return Err(MPECDSAError::General(GeneralError::new(
-                    "Tried to sign with self as counterparty.",
-                )));
+            let mut coefficients: Vec<C::Scalar> = Vec::with_capacity(threshold);
+            coefficients.push(old.poly_point.mul(&coefnum.mul(&coefdenom.inv())));
+            for _ in 1..threshold {
+                coefficients.push(C::Scalar::rand(rng));
             }
-        } else {
-            return Err(MPECDSAError::General(GeneralError::new(
-                "Proactive refresh not available for this threshold",
+            let mut coefficients_com_raw = vec![0u8; threshold * POINT_SIZE];
+            for (jj, coefficient) in coefficients.iter().enumerate() {
+                let coefficient_com =
+                    C::Point::scalar_table_multi_ct(C::gen_table(), coefficient).affine();
+                coefficient_com.to_wire_bytes(
+                    &mut coefficients_com_raw[(jj * POINT_SIZE)..((jj + 1) * POINT_SIZE)],
+                );
+                coefficients_com[mydealerindex].push(coefficient_com);
+            }
+
+            for ii in 0..playercount {
+                let mut poly_frag = coefficients[coefficients.len() - 1];
+                for jj in (0..(coefficients.len() - 1)).rev() {
+                    poly_frag = poly_frag
+                        .mul(&C::Scalar::from_native((ii + 1) as u64))
+                        .add(&coefficients[jj]);
+                }
+                if ii == playerindex {
+                    poly_point = poly_frag;
+                } else {
+                    let mut poly_frag_raw = [0u8; SCALAR_SIZE];
+                    poly_frag.to_bytes(&mut poly_frag_raw);
+                    send[ii]
+                        .as_mut()
+                        .unwrap()
+                        .write_all(&coefficients_com_raw)?;
+                    send[ii].as_mut().unwrap().write_all(&poly_frag_raw)?;
+                    send[ii].as_mut().unwrap().flush()?;
+                }
+            }
+            for coefficient in coefficients.iter_mut() {
+                wipe(coefficient, C::Scalar::ZERO);
+            }
+        }
+
+        // receive and verify shares from each dealer
+        for (dd, &ii) in dealers.iter().enumerate() {
+            if ii != playerindex {
+                let mut coefficients_com_raw = vec![0u8; threshold * POINT_SIZE];
+                let mut poly_frag_raw = [0u8; SCALAR_SIZE];
+                recv[ii]
+                    .as_mut()
+                    .unwrap()
+                    .read_exact(&mut coefficients_com_raw)?;
+                recv[ii].as_mut().unwrap().read_exact(&mut poly_frag_raw)?;
+                let poly_frag = C::Scalar::from_bytes(&poly_frag_raw);
+
+                for jj in 0..threshold {
+                    coefficients_com[dd].push(C::Point::from_wire_bytes(
+                        &coefficients_com_raw[(jj * POINT_SIZE)..((jj + 1) * POINT_SIZE)],
+                        ii,
+                        ProtocolPhase::Reshare,
+                    )?);
+                }
+                if C::Point::scalar_table_multi_ct(C::gen_table(), &poly_frag).affine()
+                    != feldman_eval::<C>(&coefficients_com[dd], playerindex)
+                {
+                    return Err(MPECDSAError::Blame(BlameError::new(
+                        ii,
+                        ProtocolPhase::Reshare,
+                        "dealt a share inconsistent with its commitments",
+                    )));
+                }
+                poly_point = poly_point.add(&poly_frag);
+            }
+        }
+
+        // the dealt secrets must sum to the old secret key
+        let mut recon_pk = C::Point::INF;
+        for dd in 0..dealers.len() {
+            recon_pk = C::Point::op(&recon_pk, &coefficients_com[dd][0]);
+        }
+        if recon_pk.affine() != pk {
+            return Err(MPECDSAError::Proof(ProofError::new(
+                "Verification failed for public key reconstruction",
             )));
         }
-    }
 
-    pub fn apply_refresh(
-        &mut self,
-        refreshpackage: &ProactiveRefreshPackage,
-    ) -> Result<(), MPECDSAError> {
-        if self.threshold == 2 {
-            self.apply_refresh_2t(refreshpackage)
-        } else {
-            Err(MPECDSAError::General(GeneralError::new(
-                "Proactive refresh not available for this threshold",
-            )))
+        // make sure that every party saw the same commitments from each dealer
+        let coms_hashes = commitment_hashes::<C>(&coefficients_com);
+        let coms_hashes_raw = coms_hashes.concat();
+        for ii in 0..playercount {
+            if ii != playerindex {
+                send[ii].as_mut().unwrap().write_all(&coms_hashes_raw)?;
+                send[ii].as_mut().unwrap().flush()?;
+            }
+        }
+        for ii in 0..playercount {
+            if ii != playerindex {
+                let mut other_coms_hashes_raw = vec![0u8; dealers.len() * HASH_SIZE];
+                recv[ii]
+                    .as_mut()
+                    .unwrap()
+                    .read_exact(&mut other_coms_hashes_raw)?;
+                check_commitment_echo(
+                    &coms_hashes,
+                    &other_coms_hashes_raw,
+                    dealers,
+                    playerindex,
+                    ii,
+                    ProtocolPhase::Reshare,
+                )?;
+            }
         }
+        let multipliervec = Self::setup_multipliers(playerindex, &ro, rng, recv, send)?;
+
+        // the old share and the multipliers built on it must not outlive a successful resharing,
+        // but on failure the caller keeps them so that it can try again
+        if let Some(old) = old {
+            old.wipe();
+        }
+
+        let pktable = C::Point::precomp_table(&pk);
+        Ok(GenericThresholdSigner {
+            ro,
+            playerindex,
+            threshold,
+            multiplier: multipliervec,
+            poly_point,
+            pk,
+            pktable,
+            chaincode,
+            epoch: epoch + 1,
+            refresh_epoch: epoch + 1,
+            refreshed: Vec::new(),
+        })
     }
 
-    fn sign_threshold<TR: Read + Send, TW: Write + Send>(
+    // Issues a share to a new party, which joins the group with the next free index (i.e. recv.len() - 1).
+    // Every existing party must call this with the same quorum, which must contain exactly threshold parties;
+    // the new party calls join. The quorum evaluates the shared polynomial at the new index by Lagrange
+    // interpolation, with each member's contribution masked by pairwise random values that cancel in the sum.
+    // Like a refresh, adding a party begins a new epoch, in which the joining party starts too.
+    pub fn add_party<TR: Read + Send, TW: Write + Send>(
         &mut self,
-        counterparties: &[usize],
-        msg: &[u8],
+        quorum: &[usize],
         rng: &mut dyn Rng,
         recv: &mut [Option<TR>],
         send: &mut [Option<TW>],
-    ) -> Result<(SecpOrd, SecpOrd), MPECDSAError> {
-        self.ro.apply_subgroup_list(counterparties)?;
-        let sroindex = self.ro.current_broadcast_counter();
+    ) -> Result<(), MPECDSAError> {
+        let newindex = self.multiplier.len();
+        if recv.len() != newindex + 1 || send.len() != newindex + 1 {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Number of streams does not match the number of parties after addition",
+            )));
+        }
+        let mut sortedquorum = quorum.to_vec();
+        sortedquorum.sort();
+        sortedquorum.dedup();
+        if sortedquorum.len() != self.threshold || quorum.len() != self.threshold {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Quorum size does not match threshold.",
+            )));
+        }
+        self.check_counterparties(quorum)?;
 
-        let ki = SecpOrd::rand(rng);
-        let kipad = SecpOrd::rand(rng);
-        let kii = ki.inv();
-        let kipadki = kii.mul(&kipad);
+        self.ro.add_party(
+            recv[newindex].as_mut().unwrap(),
+            send[newindex].as_mut().unwrap(),
+        )?;
 
-        // create reduced sets of resources for the multipliers
-        let mut prunedrecv: Vec<&mut Option<TR>> = recv
-            .iter_mut()
-            .enumerate()
-            .filter_map(|(index, val)| {
-                if counterparties.contains(&index) {
-                    Some(val)
-                } else {
-                    None
+        if let Some(myquorumindex) = quorum.iter().position(|&x| x == self.playerindex) {
+            // calculate lagrange coefficient for the new index
+            let x = C::Scalar::from_native((newindex + 1) as u64);
+            let mut coefnum = C::Scalar::ONE;
+            let mut coefdenom = C::Scalar::ONE;
+            for kk in 0..quorum.len() {
+                if kk != myquorumindex {
+                    let xk = C::Scalar::from_native((quorum[kk] + 1) as u64);
+                    coefnum = coefnum.mul(&x.sub(&xk));
+                    coefdenom = coefdenom
+                        .mul(&C::Scalar::from_native((self.playerindex + 1) as u64).sub(&xk));
                 }
-            })
-            .collect();
-        let mut prunedsend: Vec<&mut Option<TW>> = send
-            .iter_mut()
-            .enumerate()
-            .filter_map(|(index, val)| {
-                if counterparties.contains(&index) {
-                    Some(val)
-                } else {
-                    None
+            }
+
+            // pairwise masks: each pair of quorum members shares a random value, which the
+            // lower-indexed member adds and the higher-indexed member subtracts
+            let mut mask = C::Scalar::ZERO;
+            let mut mask_raw = [0u8; SCALAR_SIZE];
+            for &ii in quorum.iter() {
+                if ii > self.playerindex {
+                    let pairmask = C::Scalar::rand(rng);
+                    pairmask.to_bytes(&mut mask_raw);
+                    send[ii].as_mut().unwrap().write_all(&mask_raw)?;
+                    send[ii].as_mut().unwrap().flush()?;
+                    mask = mask.add(&pairmask);
                 }
-            })
-            .collect();
-        let mut prunedmultiplier: Vec<&mul::MulPlayer> = self
-            .multiplier
-            .iter()
-            .enumerate()
-            .filter_map(|(index, val)| {
-                if counterparties.contains(&index) {
-                    Some(val)
-                } else {
-                    None
-                }
-            })
-            .collect();
-        let tempplayeri = self.playerindex;
-        let prunedplayerindex = counterparties
-            .iter()
-            .position(|&x| x == tempplayeri)
-            .unwrap();
-
-        //instance key and inverse instance key multiplication
-
-        let threadcount = match std::env::var_os("RAYON_NUM_THREADS") {
-            Some(val) => {
-                let val = val.into_string().unwrap().parse().unwrap();
-                if val > 0 {
-                    val
-                } else {
-                    counterparties.len()
+            }
+            for &ii in quorum.iter() {
+                if ii < self.playerindex {
+                    recv[ii].as_mut().unwrap().read_exact(&mut mask_raw)?;
+                    mask = mask.sub(&C::Scalar::from_bytes(&mask_raw));
                 }
             }
-            None => counterparties.len(),
-        };
 
-        let rayonpool = rayon::ThreadPoolBuilder::new()
-            .num_threads(threadcount)
-            .build()
-            .unwrap();
+            let share_frag = self
+                .poly_point
+                .mul(&coefnum.mul(&coefdenom.inv()))
+                .add(&mask);
+            let point_com = C::Point::scalar_table_multi_ct(C::gen_table(), &self.poly_point);
+            let mut issue_raw = [0u8; 16 + 2 * POINT_SIZE + SCALAR_SIZE + CHAINCODE_SIZE];
+            LittleEndian::write_u64(&mut issue_raw[0..8], self.threshold as u64);
+            self.pk.to_wire_bytes(&mut issue_raw[8..(8 + POINT_SIZE)]);
+            point_com
+                .affine()
+                .to_wire_bytes(&mut issue_raw[(8 + POINT_SIZE)..(8 + 2 * POINT_SIZE)]);
+            share_frag
+                .to_bytes(&mut issue_raw[(8 + 2 * POINT_SIZE)..(8 + 2 * POINT_SIZE + SCALAR_SIZE)]);
+            issue_raw[(8 + 2 * POINT_SIZE + SCALAR_SIZE)
+                ..(8 + 2 * POINT_SIZE + SCALAR_SIZE + CHAINCODE_SIZE)]
+                .copy_from_slice(&self.chaincode);
+            LittleEndian::write_u64(
+                &mut issue_raw[(8 + 2 * POINT_SIZE + SCALAR_SIZE + CHAINCODE_SIZE)..],
+                self.epoch as u64,
+            );
+            send[newindex].as_mut().unwrap().write_all(&issue_raw)?;
+            send[newindex].as_mut().unwrap().flush()?;
+        }
 
-        // message 1 send+recv, message 2 send
-        let prodshares = mprmul_round_one(
-            4,
-            prunedplayerindex,
-            &mut prunedmultiplier,
-            &self.ro,
+        // the new party has the highest index, so it is always Bob
+        let mulinstance = mul::GenericMulSender::new(
+            &self.ro.get_dyadic_tagger(newindex)?,
             rng,
-            &mut prunedrecv.as_mut_slice(),
-            &mut prunedsend.as_mut_slice(),
-            &rayonpool,
+            recv[newindex].as_mut().unwrap(),
+            send[newindex].as_mut().unwrap(),
         )?;
+        self.multiplier.push(MulPlayer::Sender(mulinstance));
+        self.epoch += 1;
+        self.refresh_epoch = self.epoch;
+        self.refreshed.clear();
+        Ok(())
+    }
 
-        let mut helpfulsendbuffer = vec![Some(Cursor::new(Vec::new())); counterparties.len()];
-
+    // The counterpart to add_party, for the party that joins the group.
+    pub fn join<TR: Read + Send, TW: Write + Send>(
+        playerindex: usize,
+        quorum: &[usize],
+        rng: &mut dyn Rng,
+        recv: &mut [Option<TR>],
+        send: &mut [Option<TW>],
+    ) -> Result<GenericThresholdSigner<C>, MPECDSAError> {
+        if recv.len() != send.len() {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Number of Send streams does not match number of Recv streams",
+            )));
+        }
+        let mut sortedquorum = quorum.to_vec();
+        sortedquorum.sort();
+        sortedquorum.dedup();
+        if sortedquorum.len() != quorum.len()
+            || quorum.len() < 2
+            || quorum.iter().any(|&ii| ii >= playerindex)
         {
-            let prodshares1: Vec<&[SecpOrd]> = prodshares
-                .iter()
-                .map(|x| if x.0.len() > 0 { &x.0[0..2] } else { &x.0[..] })
-                .collect();
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Invalid quorum for joining party",
+            )));
+        }
 
-            // message 2 send
-            mpmul_first(
-                &[ki, kipadki],
-                prunedplayerindex,
-                prodshares1.as_slice(),
-                helpfulsendbuffer
-                    .iter_mut()
-                    .collect::<Vec<_>>()
-                    .as_mut_slice(),
+        let ro = {
+            let mut prunedrecv: Vec<Option<&mut TR>> =
+                recv.iter_mut().map(|val| val.as_mut()).collect();
+            let mut prunedsend: Vec<Option<&mut TW>> =
+                send.iter_mut().map(|val| val.as_mut()).collect();
+            GroupROTagger::from_network_joining(
+                playerindex,
+                rng,
+                &mut prunedrecv[..],
+                &mut prunedsend[..],
+            )?
+        };
+
+        // receive the share fragments, and check the sum against the quorum's public shares
+        let x = C::Scalar::from_native((playerindex + 1) as u64);
+        let mut poly_point = C::Scalar::ZERO;
+        let mut pk = C::Point::INF;
+        let mut chaincode = [0u8; CHAINCODE_SIZE];
+        let mut epoch = 0;
+        let mut expected_point = C::Point::INF;
+        let mut recon_pk = C::Point::INF;
+        for (jj, &ii) in quorum.iter().enumerate() {
+            let mut issue_raw = [0u8; 16 + 2 * POINT_SIZE + SCALAR_SIZE + CHAINCODE_SIZE];
+            recv[ii].as_mut().unwrap().read_exact(&mut issue_raw)?;
+            let quorumpk = C::Point::from_wire_bytes_finite(
+                &issue_raw[8..(8 + POINT_SIZE)],
+                ii,
+                ProtocolPhase::Join,
+            )?;
+            let quorumchaincode = &issue_raw[(8 + 2 * POINT_SIZE + SCALAR_SIZE)
+                ..(8 + 2 * POINT_SIZE + SCALAR_SIZE + CHAINCODE_SIZE)];
+            let quorumepoch = LittleEndian::read_u64(
+                &issue_raw[(8 + 2 * POINT_SIZE + SCALAR_SIZE + CHAINCODE_SIZE)..],
+            ) as usize;
+            if LittleEndian::read_u64(&issue_raw[0..8]) != quorum.len() as u64
+                || (jj > 0
+                    && (quorumpk != pk || quorumchaincode != chaincode || quorumepoch != epoch))
+            {
+                return Err(MPECDSAError::Blame(BlameError::new(
+                    ii,
+                    ProtocolPhase::Join,
+                    "announced a different public key, threshold, chain code, or epoch",
+                )));
+            }
+            pk = quorumpk;
+            chaincode.copy_from_slice(quorumchaincode);
+            epoch = quorumepoch;
+            let point_com = C::Point::from_wire_bytes(
+                &issue_raw[(8 + POINT_SIZE)..(8 + 2 * POINT_SIZE)],
+                ii,
+                ProtocolPhase::Join,
             )?;
+            poly_point = poly_point.add(&C::Scalar::from_bytes(
+                &issue_raw[(8 + 2 * POINT_SIZE)..(8 + 2 * POINT_SIZE + SCALAR_SIZE)],
+            ));
+
+            // calculate lagrange coefficients for the new index and for zero
+            let xj = C::Scalar::from_native((ii + 1) as u64);
+            let mut coefnum = C::Scalar::ONE;
+            let mut coefnum0 = C::Scalar::ONE;
+            let mut coefdenom = C::Scalar::ONE;
+            for &kk in quorum.iter() {
+                if kk != ii {
+                    let xk = C::Scalar::from_native((kk + 1) as u64);
+                    coefnum = coefnum.mul(&x.sub(&xk));
+                    coefnum0 = coefnum0.mul(&C::Scalar::ZERO.sub(&xk));
+                    coefdenom = coefdenom.mul(&xj.sub(&xk));
+                }
+            }
+            let coefdenominv = coefdenom.inv();
+            expected_point = C::Point::op(
+                &expected_point,
+                &point_com.scalar_table(&coefnum.mul(&coefdenominv)),
+            );
+            recon_pk = C::Point::op(
+                &recon_pk,
+                &point_com.scalar_table(&coefnum0.mul(&coefdenominv)),
+            );
         }
 
-        let helpfulsentbuffer = helpfulsendbuffer
-            .into_iter()
-            .map(|x| x.unwrap().into_inner())
-            .collect();
+        if recon_pk.affine() != pk {
+            return Err(MPECDSAError::Proof(ProofError::new(
+                "Verification failed for public key reconstruction",
+            )));
+        }
+        if C::Point::scalar_table_multi_ct(C::gen_table(), &poly_point).affine()
+            != expected_point.affine()
+        {
+            return Err(MPECDSAError::Proof(ProofError::new(
+                "Issued share is inconsistent with the quorum's public shares",
+            )));
+        }
 
-        // message 2 recv
-        let linshares = mprmul_round_two(
-            prunedplayerindex,
-            &prodshares,
-            &mut prunedmultiplier,
-            &self.ro,
-            rng,
-            &mut prunedrecv.as_mut_slice(),
-            &mut prunedsend.as_mut_slice(),
-            &rayonpool,
-            Some(helpfulsentbuffer),
-        )?;
+        // the new party has the highest index, so it is always Bob
+        let mut multipliervec = Vec::with_capacity(recv.len());
+        for ii in 0..playerindex {
+            multipliervec.push(MulPlayer::Recver(mul::GenericMulRecver::new(
+                &ro.get_dyadic_tagger(ii)?,
+                rng,
+                recv[ii].as_mut().unwrap(),
+                send[ii].as_mut().unwrap(),
+            )?));
+        }
+        multipliervec.push(MulPlayer::Null);
 
-        let shares: Vec<Vec<(SecpOrd, SecpOrd)>> = prodshares
-            .into_iter()
-            .zip(linshares.into_iter())
-            .map(|(prodel, linel)| prodel.0.into_iter().zip(linel.into_iter()).collect())
-            .collect();
+        let pktable = C::Point::precomp_table(&pk);
+        Ok(GenericThresholdSigner {
+            ro,
+            playerindex,
+            threshold: quorum.len(),
+            multiplier: multipliervec,
+            poly_point,
+            pk,
+            pktable,
+            chaincode,
+            epoch: epoch + 1,
+            refresh_epoch: epoch + 1,
+            refreshed: Vec::new(),
+        })
+    }
 
-        let shares1: Vec<&[(SecpOrd, SecpOrd)]> = shares
-            .iter()
-            .map(|x| if x.len() > 0 { &x[0..2] } else { &x[..] })
+    // Removes a party from the group. Every remaining party must call this with the same removed index.
+    // Together they add a fresh sharing of zero to their shares, dealt over their pairwise channels, so
+    // the shares change without changing the key. The removed party takes no part and sees none of the
+    // dealt values, so its old share no longer interpolates with the new ones.
+    pub fn remove_party<TR: Read + Send, TW: Write + Send>(
+        &mut self,
+        removed: usize,
+        rng: &mut dyn Rng,
+        recv: &mut [Option<TR>],
+        send: &mut [Option<TW>],
+    ) -> Result<(), MPECDSAError> {
+        if removed >= self.multiplier.len() || removed == self.playerindex {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Invalid party to remove",
+            )));
+        }
+        if let MulPlayer::Null = self.multiplier[removed] {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Party has already been removed from the group",
+            )));
+        }
+        let remaining: Vec<usize> = (0..self.multiplier.len())
+            .filter(|&ii| {
+                ii == self.playerindex
+                    || (ii != removed && !matches!(self.multiplier[ii], MulPlayer::Null))
+            })
             .collect();
-
-        let shares2: Vec<&[(SecpOrd, SecpOrd)]> = shares
+        if remaining.len() < self.threshold {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Too few parties would remain in the group",
+            )));
+        }
+        let mydealerindex = remaining
             .iter()
-            .map(|x| if x.len() > 0 { &x[2..4] } else { &x[..] })
-            .collect();
-
-        // message 2 recv, message 3 to log(n)+1 send+recv
-        let mulresult = mpmul_rest(
-            &[ki, kipadki],
-            prunedplayerindex,
-            shares1.as_slice(),
-            &mut prunedrecv.as_mut_slice(),
-            &mut prunedsend.as_mut_slice(),
-        )?;
-        let ui = mulresult[0];
-        let vi = mulresult[1];
+            .position(|&x| x == self.playerindex)
+            .unwrap();
 
-        let mut coefnum = SecpOrd::ONE;
-        let mut coefdenom = SecpOrd::ONE;
-        // calculate lagrange coefficient
-        for kk in 0..self.threshold {
-            if kk != prunedplayerindex {
-                coefnum = coefnum.mul(&SecpOrd::from_native((counterparties[kk] + 1) as u64));
-                coefdenom = coefdenom.mul(
-                    &SecpOrd::from_native((counterparties[kk] + 1) as u64)
-                        .sub(&SecpOrd::from_native((self.playerindex + 1) as u64)),
-                );
+        // every remaining party must agree on the party to remove, and on the epoch
+        let mut announce_raw = [0u8; 16];
+        LittleEndian::write_u64(&mut announce_raw[0..8], removed as u64);
+        LittleEndian::write_u64(&mut announce_raw[8..16], self.epoch as u64);
+        for &ii in remaining.iter() {
+            if ii != self.playerindex {
+                send[ii].as_mut().unwrap().write_all(&announce_raw)?;
+                send[ii].as_mut().unwrap().flush()?;
             }
         }
-        let zi = self.poly_point.mul(&coefnum.mul(&coefdenom.inv()));
-
-        //secret key multiplication, step one
-        // message log(n)+2 send
-        mpswapmul_send(
-            &[(vi, zi)],
-            prunedplayerindex,
-            shares2.as_slice(),
-            &mut prunedsend.as_mut_slice(),
-        )?;
-
-        //R and phi commitment, plus broadcast RO sync
-        let ri = Secp::scalar_table_multi(&precomp::P256_TABLE[..], &ui).affine();
-        let mut pad_raw = [0u8; SecpOrd::NBYTES + RO_TAG_SIZE];
-        let mut ri_raw = [0u8; Secp::NBYTES + RO_TAG_SIZE];
-        kipad.to_bytes(&mut pad_raw[RO_TAG_SIZE..]);
-        ri.to_bytes(&mut ri_raw[RO_TAG_SIZE..]);
-
-        let mut hashout = [0u8; HASH_SIZE];
-        let mut doublecom = vec![[0u8; 2 * HASH_SIZE]; self.threshold];
-        ri_raw[0..RO_TAG_SIZE].copy_from_slice(&self.ro.next_broadcast_tag());
-        pad_raw[0..RO_TAG_SIZE].copy_from_slice(&self.ro.next_broadcast_tag());
-        hash(&mut hashout, &ri_raw);
-        doublecom[prunedplayerindex][HASH_SIZE..2 * HASH_SIZE].copy_from_slice(&hashout[..]);
-        hash(&mut hashout, &pad_raw);
-        doublecom[prunedplayerindex][0..HASH_SIZE].copy_from_slice(&hashout[..]);
-
-        // synchronize the random oracles
-        let mut sroindex_raw = [0u8; 8];
-        LittleEndian::write_u64(&mut sroindex_raw, sroindex);
-
-        for ii in 0..self.threshold {
-            if ii != prunedplayerindex {
-                // message log(n)+2 send
-                prunedsend[ii].as_mut().unwrap().write(&sroindex_raw)?;
-                prunedsend[ii]
+        for &ii in remaining.iter() {
+            if ii != self.playerindex {
+                let mut other_announce_raw = [0u8; 16];
+                recv[ii]
                     .as_mut()
                     .unwrap()
-                    .write(&doublecom[prunedplayerindex])?;
-                prunedsend[ii].as_mut().unwrap().flush()?;
+                    .read_exact(&mut other_announce_raw)?;
+                if other_announce_raw != announce_raw {
+                    return Err(MPECDSAError::Blame(BlameError::new(
+                        ii,
+                        ProtocolPhase::Remove,
+                        "announced a different party to remove, or a different epoch",
+                    )));
+                }
             }
         }
 
-        //secret key multiplication, step two
-        // message log(n)+2 recv
-        let wi = mpswapmul_recv(
-            &[(vi, zi)],
-            prunedplayerindex,
-            shares2.as_slice(),
-            &mut prunedrecv.as_mut_slice(),
-        )?[0];
-
-        // receive commitments to kjpad, rj, poks
-        for ii in 0..self.threshold {
-            if ii != prunedplayerindex {
-                // message log(n)+2 recv
-                prunedrecv[ii]
-                    .as_mut()
-                    .unwrap()
-                    .read_exact(&mut sroindex_raw)?;
-                self.ro.advance_counterparty_broadcast_counter(
-                    counterparties[ii],
-                    LittleEndian::read_u64(&sroindex_raw),
-                )?;
-                prunedrecv[ii]
+        // deal a random polynomial with a zero constant term, and commit to its other coefficients
+        let mut coefficients: Vec<C::Scalar> = Vec::with_capacity(self.threshold - 1);
+        let mut coefficients_com_raw = vec![0u8; (self.threshold - 1) * POINT_SIZE];
+        for jj in 0..(self.threshold - 1) {
+            let coefficient = C::Scalar::rand(rng);
+            C::Point::scalar_table_multi_ct(C::gen_table(), &coefficient)
+                .affine()
+                .to_wire_bytes(
+                    &mut coefficients_com_raw[(jj * POINT_SIZE)..((jj + 1) * POINT_SIZE)],
+                );
+            coefficients.push(coefficient);
+        }
+        let mut coms_hashes = vec![[0u8; HASH_SIZE]; remaining.len()];
+        hash(&mut coms_hashes[mydealerindex], &coefficients_com_raw);
+        let mut offset = C::Scalar::ZERO;
+        for &ii in remaining.iter() {
+            let x = C::Scalar::from_native((ii + 1) as u64);
+            let mut poly_frag = C::Scalar::ZERO;
+            for coefficient in coefficients.iter().rev() {
+                poly_frag = poly_frag.add(coefficient).mul(&x);
+            }
+            if ii == self.playerindex {
+                offset = poly_frag;
+            } else {
+                let mut poly_frag_raw = [0u8; SCALAR_SIZE];
+                poly_frag.to_bytes(&mut poly_frag_raw);
+                send[ii]
                     .as_mut()
                     .unwrap()
-                    .read_exact(&mut doublecom[ii])?;
+                    .write_all(&coefficients_com_raw)?;
+                send[ii].as_mut().unwrap().write_all(&poly_frag_raw)?;
+                send[ii].as_mut().unwrap().flush()?;
             }
         }
+        for coefficient in coefficients.iter_mut() {
+            wipe(coefficient, C::Scalar::ZERO);
+        }
 
-        // release ri + pok
-        for ii in 0..self.threshold {
-            if ii != prunedplayerindex {
-                // message log(n)+3 send
-                prunedsend[ii]
+        // receive and verify the values dealt to us
+        for (dd, &ii) in remaining.iter().enumerate() {
+            if ii != self.playerindex {
+                let mut other_coefficients_com_raw = vec![0u8; (self.threshold - 1) * POINT_SIZE];
+                let mut poly_frag_raw = [0u8; SCALAR_SIZE];
+                recv[ii]
                     .as_mut()
                     .unwrap()
-                    .write(&ri_raw[RO_TAG_SIZE..])?;
-                prunedsend[ii].as_mut().unwrap().flush()?;
+                    .read_exact(&mut other_coefficients_com_raw)?;
+                recv[ii].as_mut().unwrap().read_exact(&mut poly_frag_raw)?;
+                let poly_frag = C::Scalar::from_bytes(&poly_frag_raw);
+
+                let mut coefficients_com = Vec::with_capacity(self.threshold);
+                coefficients_com.push(C::Point::INF);
+                for jj in 0..(self.threshold - 1) {
+                    coefficients_com.push(C::Point::from_wire_bytes(
+                        &other_coefficients_com_raw[(jj * POINT_SIZE)..((jj + 1) * POINT_SIZE)],
+                        ii,
+                        ProtocolPhase::Remove,
+                    )?);
+                }
+                if C::Point::scalar_table_multi_ct(C::gen_table(), &poly_frag).affine()
+                    != feldman_eval::<C>(&coefficients_com, self.playerindex)
+                {
+                    return Err(MPECDSAError::Blame(BlameError::new(
+                        ii,
+                        ProtocolPhase::Remove,
+                        "dealt a value inconsistent with its commitments",
+                    )));
+                }
+                hash(&mut coms_hashes[dd], &other_coefficients_com_raw);
+                offset = offset.add(&poly_frag);
             }
         }
 
-        // receive rj + pok and verify against commitment
-        let mut r = ri;
-        let mut rjs = vec![Secp::INF; self.threshold];
-        for ii in 0..self.threshold {
-            if ii != prunedplayerindex {
-                // message log(n)+3 recv
-                prunedrecv[ii]
+        // make sure that every party saw the same commitments from each dealer
+        let coms_hashes_raw = coms_hashes.concat();
+        for &ii in remaining.iter() {
+            if ii != self.playerindex {
+                send[ii].as_mut().unwrap().write_all(&coms_hashes_raw)?;
+                send[ii].as_mut().unwrap().flush()?;
+            }
+        }
+        for &ii in remaining.iter() {
+            if ii != self.playerindex {
+                let mut other_coms_hashes_raw = vec![0u8; remaining.len() * HASH_SIZE];
+                recv[ii]
                     .as_mut()
                     .unwrap()
-                    .read_exact(&mut ri_raw[RO_TAG_SIZE..])?;
-                ri_raw[0..RO_TAG_SIZE]
-                    .copy_from_slice(&self.ro.next_counterparty_broadcast_tag(ii)?);
-                hash(&mut hashout, &ri_raw);
-                if hashout != doublecom[ii][HASH_SIZE..2 * HASH_SIZE] {
-                    return Err(MPECDSAError::Proof(ProofError::new(&format!(
-                        "Player {} failed to decommit R",
-                        counterparties[ii]
-                    ))));
-                }
-                rjs[ii] = Secp::from_bytes(&ri_raw[RO_TAG_SIZE..]);
-                r = Secp::op(&r, &rjs[ii]).affine();
+                    .read_exact(&mut other_coms_hashes_raw)?;
+                check_commitment_echo(
+                    &coms_hashes,
+                    &other_coms_hashes_raw,
+                    &remaining,
+                    self.playerindex,
+                    ii,
+                    ProtocolPhase::Remove,
+                )?;
             }
         }
 
-        // message log(n)+3 recv
+        self.poly_point = self.poly_point.add(&offset);
+        self.multiplier[removed] = MulPlayer::Null;
+        self.epoch += 1;
+        self.refresh_epoch = self.epoch;
+        self.refreshed.clear();
+        Ok(())
+    }
 
-        let r_table = Secp::precomp_table(&r);
-        let mut checkpt1 = Secp::scalar_table_multi(&r_table[..], &vi).affine();
-        let mut checkpt2 = Secp::op(
-            &Secp::scalar_table_multi(&self.pktable[..], &vi),
-            &Secp::scalar_table_multi(&precomp::P256_TABLE[..], &wi).neg(),
-        )
-        .affine();
-        let mut checkpt3 = Secp::scalar_table_multi(&r_table[..], &wi).affine();
+    // destroys the share and multiplier state, once they have been replaced by a resharing
+    fn wipe(&mut self) {
+        wipe(&mut self.poly_point, C::Scalar::ZERO);
+        for mulinstance in self.multiplier.iter_mut() {
+            mulinstance.wipe();
+        }
+    }
 
-        let mut checkpt123_raw = [0u8; 3 * Secp::NBYTES + RO_TAG_SIZE];
-        checkpt1.to_bytes(&mut checkpt123_raw[RO_TAG_SIZE..(Secp::NBYTES + RO_TAG_SIZE)]);
-        checkpt2.to_bytes(
-            &mut checkpt123_raw[(Secp::NBYTES + RO_TAG_SIZE)..(2 * Secp::NBYTES + RO_TAG_SIZE)],
-        );
-        checkpt3.to_bytes(
-            &mut checkpt123_raw[(2 * Secp::NBYTES + RO_TAG_SIZE)..(3 * Secp::NBYTES + RO_TAG_SIZE)],
-        );
-        let mut checkpt123_coms = vec![[0u8; HASH_SIZE]; self.threshold];
-        checkpt123_raw[0..RO_TAG_SIZE].copy_from_slice(&self.ro.next_broadcast_tag());
-        hash(&mut checkpt123_coms[prunedplayerindex], &checkpt123_raw);
-        // send commitment
-        for ii in 0..self.threshold {
-            if ii != prunedplayerindex {
-                // message log(n)+4 send
-                prunedsend[ii]
-                    .as_mut()
-                    .unwrap()
-                    .write(&checkpt123_coms[prunedplayerindex])?;
-                prunedsend[ii].as_mut().unwrap().flush()?;
-            }
-        }
-
-        // receive commitments checkpts
-        for ii in 0..self.threshold {
-            if ii != prunedplayerindex {
-                // message log(n)+4 recv
-                prunedrecv[ii]
-                    .as_mut()
-                    .unwrap()
-                    .read_exact(&mut checkpt123_coms[ii])?;
-            }
-        }
-
-        // release kipad and checkpts
-        for ii in 0..self.threshold {
-            if ii != prunedplayerindex {
-                // message log(n)+5 send
-                prunedsend[ii]
-                    .as_mut()
-                    .unwrap()
-                    .write(&pad_raw[(RO_TAG_SIZE)..])?;
-                prunedsend[ii]
-                    .as_mut()
-                    .unwrap()
-                    .write(&checkpt123_raw[(RO_TAG_SIZE)..])?;
-                prunedsend[ii].as_mut().unwrap().flush()?;
-            }
-        }
-
-        // receive kjpad and verify against commitment
-        let mut kpad = kipad;
-        for ii in 0..self.threshold {
-            if ii != prunedplayerindex {
-                let mut comcomp = [0u8; HASH_SIZE];
-                pad_raw[0..RO_TAG_SIZE]
-                    .copy_from_slice(&self.ro.next_counterparty_broadcast_tag(ii)?);
-                // message log(n)+5 recv
-                prunedrecv[ii]
-                    .as_mut()
-                    .unwrap()
-                    .read_exact(&mut pad_raw[RO_TAG_SIZE..])?;
-                hash(&mut comcomp, &pad_raw);
-                let kjpad = SecpOrd::from_bytes(&pad_raw[RO_TAG_SIZE..]);
-                if comcomp == doublecom[ii][0..HASH_SIZE] {
-                    kpad = kpad.mul(&kjpad);
-                } else {
-                    return Err(MPECDSAError::Proof(ProofError::new(&format!(
-                        "Player {} failed to decommit multiplication pad",
-                        counterparties[ii]
-                    ))));
-                }
-
-                checkpt123_raw[0..RO_TAG_SIZE]
-                    .copy_from_slice(&self.ro.next_counterparty_broadcast_tag(ii)?);
-                // message log(n)+5 recv
-                prunedrecv[ii]
-                    .as_mut()
-                    .unwrap()
-                    .read_exact(&mut checkpt123_raw[RO_TAG_SIZE..])?;
-                hash(&mut comcomp, &checkpt123_raw);
-                if comcomp == checkpt123_coms[ii] {
-                    let checkpt1_frag = Secp::from_bytes(
-                        &checkpt123_raw[RO_TAG_SIZE..(Secp::NBYTES + RO_TAG_SIZE)],
-                    );
-                    let checkpt2_frag = Secp::from_bytes(
-                        &checkpt123_raw
-                            [(Secp::NBYTES + RO_TAG_SIZE)..(2 * Secp::NBYTES + RO_TAG_SIZE)],
-                    );
-                    let checkpt3_frag = Secp::from_bytes(
-                        &checkpt123_raw
-                            [(2 * Secp::NBYTES + RO_TAG_SIZE)..(3 * Secp::NBYTES + RO_TAG_SIZE)],
-                    );
-                    checkpt1 = Secp::op(&checkpt1, &checkpt1_frag).affine();
-                    checkpt2 = Secp::op(&checkpt2, &checkpt2_frag).affine();
-                    checkpt3 = Secp::op(&checkpt3, &checkpt3_frag).affine();
-                } else {
-                    return Err(MPECDSAError::Proof(ProofError::new(&format!(
-                        "Player {} failed to decommit consistency checks",
-                        counterparties[ii]
+    fn check_counterparties(&self, counterparties: &[usize]) -> Result<(), MPECDSAError> {
+        for &ii in counterparties.iter() {
+            if ii < self.multiplier.len() && ii != self.playerindex {
+                if let MulPlayer::Null = self.multiplier[ii] {
+                    return Err(MPECDSAError::General(GeneralError::new(&format!(
+                        "Player {} has been removed from the group",
+                        ii
                     ))));
                 }
             }
         }
+        Ok(())
+    }
 
-        if kpad == SecpOrd::ZERO {
-            return Err(MPECDSAError::Proof(ProofError::new(
-                &"Multicplication pad value was zero",
-            )));
-        }
-
-        if checkpt1.affine() != Secp::scalar_table_multi(&precomp::P256_TABLE[..], &kpad).affine() {
-            return Err(MPECDSAError::Proof(ProofError::new(
-                &"First consistency check failed",
+    // checks that the counterparties and this party together form a quorum, and returns the
+    // quorum in order of index
+    fn quorum(&self, counterparties: &[usize]) -> Result<Vec<usize>, MPECDSAError> {
+        if counterparties.len() != (self.threshold - 1) {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Number of counterparties does not match threshold.",
             )));
         }
-
-        if !checkpt2.is_infinity() {
-            return Err(MPECDSAError::Proof(ProofError::new(
-                &"Second consistency check failed",
+        self.check_counterparties(counterparties)?;
+        if counterparties.contains(&self.playerindex) {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Tried to sign with self as counterparty.",
             )));
         }
 
-        if checkpt3 != Secp::scalar_table_multi(&self.pktable, &kpad).affine() {
-            return Err(MPECDSAError::Proof(ProofError::new(
-                &"Third consistency check failed",
+        let mut parties: Vec<usize> = counterparties.to_vec();
+        parties.push(self.playerindex);
+        parties.sort();
+        parties.dedup();
+        if parties.len() != self.threshold {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Counterparties are not distinct.",
             )));
         }
+        Ok(parties)
+    }
 
-        // hash the message
-        let mut z = [0; HASH_SIZE];
-        ecdsa_hash(&mut z, msg);
-        let z = SecpOrd::from_bytes(&z);
-
-        let mut rxb = [0u8; SecpOrd::NBYTES];
-        r.x.to_bytes(&mut rxb);
-        let rx = SecpOrd::from_bytes(&rxb);
-
-        let wiaug = wi.mul(&kpad.inv());
-        let mut sig = z.mul(&vi).mul(&kpad.inv()).add(&wiaug.mul(&rx));
-        let mut sig_frag_raw = [0u8; SecpOrd::NBYTES];
-        sig.to_bytes(&mut sig_frag_raw);
-
+    // exchanges broadcast counters with the rest of the quorum, so that the random oracles agree
+    fn sync_ro<TR: Read, TW: Write>(
+        &mut self,
+        prunedplayerindex: usize,
+        recv: &mut [&mut Option<TR>],
+        send: &mut [&mut Option<TW>],
+    ) -> Result<(), MPECDSAError> {
+        let mut sroindex_raw = [0u8; 8];
+        LittleEndian::write_u64(&mut sroindex_raw, self.ro.current_broadcast_counter());
         for ii in 0..self.threshold {
             if ii != prunedplayerindex {
-                // message log(n)+6 send
-                prunedsend[ii].as_mut().unwrap().write(&sig_frag_raw)?;
-                prunedsend[ii].as_mut().unwrap().flush()?;
+                send[ii].as_mut().unwrap().write_all(&sroindex_raw)?;
+                send[ii].as_mut().unwrap().flush()?;
             }
         }
-
         for ii in 0..self.threshold {
             if ii != prunedplayerindex {
-                // message log(n)+6 recv
-                prunedrecv[ii]
-                    .as_mut()
-                    .unwrap()
-                    .read_exact(&mut sig_frag_raw)?;
-                let sig_frag = SecpOrd::from_bytes(&sig_frag_raw);
-                sig = sig.add(&sig_frag);
+                recv[ii].as_mut().unwrap().read_exact(&mut sroindex_raw)?;
+                self.ro.advance_counterparty_broadcast_counter(
+                    ii,
+                    LittleEndian::read_u64(&sroindex_raw),
+                )?;
             }
         }
+        Ok(())
+    }
 
-        if ecdsa::ecdsa_verify_with_tables(
-            msg,
-            (&rx, &sig),
-            &precomp::P256_TABLE,
-            &self.pktable[..],
-        ) {
-            Ok((rx, sig))
-        } else {
-            Err(MPECDSAError::Proof(ProofError::new(
-                "Signature verification failed for ECDSA signing",
-            )))
-        }
+    pub fn sign<TR: Read + Send, TW: Write + Send>(
+        &mut self,
+        counterparties: &[usize],
+        msg: &[u8],
+        rng: &mut dyn Rng,
+        recv: &mut [Option<TR>],
+        send: &mut [Option<TW>],
+    ) -> Result<Option<Signature<C>>, MPECDSAError> {
+        let mut digest = [0u8; HASH_SIZE];
+        ecdsa_hash(&mut digest, msg);
+        self.sign_prehashed(counterparties, &digest, rng, recv, send)
     }
 
-    fn sign2t_alice<TR: Read, TW: Write + Send>(
+    // Signs a digest computed by the caller, such as a keccak-256 hash or a bitcoin sighash,
+    // rather than the sha-256 hash of a message.
+    pub fn sign_prehashed<TR: Read + Send, TW: Write + Send>(
         &mut self,
-        counterparty: usize,
+        counterparties: &[usize],
+        digest: &[u8; HASH_SIZE],
+        rng: &mut dyn Rng,
+        recv: &mut [Option<TR>],
+        send: &mut [Option<TW>],
+    ) -> Result<Option<Signature<C>>, MPECDSAError> {
+        let req = SignRequest {
+            digest,
+            key: None,
+            tag: None,
+            returnsig: false,
+        };
+        self.sign_with_key(counterparties, &req, rng, recv, send)
+    }
+
+    // Like sign, but in the 2-of-n case it includes a final flight in which the higher-indexed
+    // party returns the signature, so that both parties can verify and publish it. Every party
+    // must call this rather than sign.
+    pub fn sign_with_return<TR: Read + Send, TW: Write + Send>(
+        &mut self,
+        counterparties: &[usize],
         msg: &[u8],
         rng: &mut dyn Rng,
-        recv: &mut TR,
-        send: &mut TW,
-    ) -> Result<(), MPECDSAError> {
-        let res = self.sign2t_and_gen_refresh_alice(counterparty, msg, None, rng, recv, send);
-        if res.is_ok() {
-            Ok(())
+        recv: &mut [Option<TR>],
+        send: &mut [Option<TW>],
+    ) -> Result<Signature<C>, MPECDSAError> {
+        let mut digest = [0u8; HASH_SIZE];
+        ecdsa_hash(&mut digest, msg);
+        let req = SignRequest {
+            digest: &digest,
+            key: None,
+            tag: None,
+            returnsig: true,
+        };
+        let sig = self.sign_with_key(counterparties, &req, rng, recv, send)?;
+        Ok(sig.expect("every party learns the signature when it is returned"))
+    }
+
+    fn sign_with_key<TR: Read + Send, TW: Write + Send>(
+        &mut self,
+        counterparties: &[usize],
+        req: &SignRequest<C>,
+        rng: &mut dyn Rng,
+        recv: &mut [Option<TR>],
+        send: &mut [Option<TW>],
+    ) -> Result<Option<Signature<C>>, MPECDSAError> {
+        let parties = self.quorum(counterparties)?;
+
+        if self.threshold == 2 {
+            let counterparty = counterparties[0];
+            if self.playerindex > counterparty {
+                Ok(Some(self.sign2t_bob(
+                    counterparty,
+                    req,
+                    rng,
+                    &mut recv[counterparty].as_mut().unwrap(),
+                    &mut send[counterparty].as_mut().unwrap(),
+                )?))
+            } else {
+                self.sign2t_alice(
+                    counterparty,
+                    req,
+                    rng,
+                    &mut recv[counterparty].as_mut().unwrap(),
+                    &mut send[counterparty].as_mut().unwrap(),
+                )
+            }
         } else {
-            Err(res.unwrap_err())
+            Ok(Some(self.sign_threshold(&parties, req, rng, recv, send)?))
         }
     }
 
-    fn sign2t_and_gen_refresh_alice<TR: Read, TW: Write + Send>(
+    pub fn sign_and_gen_refresh<TR: Read + Send, TW: Write + Send>(
         &mut self,
-        counterparty: usize,
+        counterparties: &[usize],
         msg: &[u8],
-        tag: Option<&[u8]>,
+        tag: &[u8],
         rng: &mut dyn Rng,
-        recv: &mut TR,
-        send: &mut TW,
-    ) -> Result<Option<ProactiveRefreshPackage>, MPECDSAError> {
-        let (parties, prunedcpindex) = if self.playerindex > counterparty {
-            ([counterparty, self.playerindex], 0)
-        } else {
-            ([self.playerindex, counterparty], 1)
+        recv: &mut [Option<TR>],
+        send: &mut [Option<TW>],
+    ) -> Result<(Option<Signature<C>>, ProactiveRefreshPackage<C>), MPECDSAError> {
+        let mut digest = [0u8; HASH_SIZE];
+        ecdsa_hash(&mut digest, msg);
+        let req = SignRequest {
+            digest: &digest,
+            key: None,
+            tag: Some(tag),
+            returnsig: false,
         };
-        self.ro.apply_subgroup_list(&parties)?;
-        let sroindex = self.ro.current_broadcast_counter();
-        let mut sroindex_raw = [0u8; 8];
-        // precompute things you won't need till later
+        let parties = self.quorum(counterparties)?;
 
-        // alice's instance key is of a special form for the two round version:
-        // k_a = H(k'_a*G)+k'_a
-        // this prevents her from choosing the value conveniently
-        let kaprime = SecpOrd::rand(rng);
-        let kapad = SecpOrd::rand(rng);
+        if self.threshold == 2 {
+            let counterparty = counterparties[0];
 
-        // hash the message
-        let mut z = [0; HASH_SIZE];
-        ecdsa_hash(&mut z, msg);
-        let z = SecpOrd::from_bytes(&z);
+            if self.playerindex > counterparty {
+                let (sig, p) = self.sign2t_and_gen_refresh_bob(
+                    counterparty,
+                    &req,
+                    rng,
+                    recv[counterparty].as_mut().unwrap(),
+                    send[counterparty].as_mut().unwrap(),
+                )?;
+                Ok((Some(sig), p.unwrap()))
+            } else {
+                let (sig, p) = self.sign2t_and_gen_refresh_alice(
+                    counterparty,
+                    &req,
+                    rng,
+                    recv[counterparty].as_mut().unwrap(),
+                    send[counterparty].as_mut().unwrap(),
+                )?;
+                Ok((sig, p.unwrap()))
+            }
+        } else {
+            let (sig, p) = self.sign_threshold_and_gen_refresh(&parties, &req, rng, recv, send)?;
+            Ok((Some(sig), p.unwrap()))
+        }
+    }
 
-        // calculate lagrange coefficient
-        let mut coef = SecpOrd::from_native((counterparty + 1) as u64);
-        coef = coef.mul(
-            &(SecpOrd::from_native((counterparty + 1) as u64)
-                .sub(&SecpOrd::from_native((self.playerindex + 1) as u64)))
-            .inv(),
-        );
-        let t0a = coef.mul(&self.poly_point);
+    // Runs every message-independent round of the signing protocol, so that it can be done ahead of time.
+    // The resulting presignature is consumed by finish_sign, which needs only one more round.
+    pub fn presign<TR: Read + Send, TW: Write + Send>(
+        &mut self,
+        counterparties: &[usize],
+        rng: &mut dyn Rng,
+        recv: &mut [Option<TR>],
+        send: &mut [Option<TW>],
+    ) -> Result<PreSignature<C>, MPECDSAError> {
+        let parties = self.quorum(counterparties)?;
+        self.presign_threshold(&parties, rng, recv, send)
+    }
 
-        let multiplier = match self.multiplier[counterparty] {
-            MulPlayer::Sender(ref multiplier) => multiplier,
-            _ => {
-                return Err(MPECDSAError::General(GeneralError::new(
-                    "Alice was given Recver half of multiplier protocol.",
-                )))
-            }
+    pub fn finish_sign<TR: Read + Send, TW: Write + Send>(
+        &mut self,
+        presig: PreSignature<C>,
+        msg: &[u8],
+        rng: &mut dyn Rng,
+        recv: &mut [Option<TR>],
+        send: &mut [Option<TW>],
+    ) -> Result<Signature<C>, MPECDSAError> {
+        if presig.playerindex != self.playerindex {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Presignature belongs to a different player",
+            )));
+        }
+        if presig.pk != self.pk || presig.epoch != self.epoch {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Presignature was made for a different key, or before the shares were refreshed",
+            )));
+        }
+        self.check_counterparties(&presig.parties)?;
+
+        let mut digest = [0u8; HASH_SIZE];
+        ecdsa_hash(&mut digest, msg);
+        let req = SignRequest {
+            digest: &digest,
+            key: None,
+            tag: None,
+            returnsig: false,
         };
+        let (sig, _) = self.finish_sign_threshold(presig, &req, rng, recv, send)?;
+        Ok(sig)
+    }
 
-        // online phase
-        recv.read_exact(&mut sroindex_raw)?;
-        self.ro.advance_counterparty_broadcast_counter(
-            prunedcpindex,
-            LittleEndian::read_u64(&sroindex_raw),
+    // Generates a refresh package without signing anything, so that shares can be rotated on a schedule.
+    // The package is bound to a dummy R (the point at infinity) rather than to a signing session.
+    pub fn refresh<TR: Read + Send, TW: Write + Send>(
+        &mut self,
+        counterparties: &[usize],
+        tag: &[u8],
+        rng: &mut dyn Rng,
+        recv: &mut [Option<TR>],
+        send: &mut [Option<TW>],
+    ) -> Result<ProactiveRefreshPackage<C>, MPECDSAError> {
+        let parties = self.quorum(counterparties)?;
+        self.ro.apply_subgroup_list(&parties)?;
+        let prunedplayerindex = parties.iter().position(|&x| x == self.playerindex).unwrap();
+        let mut prunedrecv = prune(&parties, recv);
+        let mut prunedsend = prune(&parties, send);
+        self.sync_ro(
+            prunedplayerindex,
+            prunedrecv.as_mut_slice(),
+            prunedsend.as_mut_slice(),
         )?;
-        let dro = self.ro.get_dyadic_tagger(prunedcpindex).unwrap();
 
-        // recv D_b from bob
-        let mut dbraw = [0u8; Secp::NBYTES];
-        recv.read_exact(&mut dbraw)?;
-        let db = Secp::from_bytes(&dbraw);
-        let dbtable = Secp::precomp_table(&db);
+        self.gen_refresh_threshold(
+            &C::Point::INF,
+            tag,
+            &parties,
+            rng,
+            prunedrecv.as_mut_slice(),
+            prunedsend.as_mut_slice(),
+        )
+    }
 
-        let rprime = Secp::scalar_table_multi(&dbtable[..], &kaprime).affine();
-        let mut rprimeraw = [0u8; Secp::NBYTES + RO_TAG_SIZE];
-        rprime.to_bytes(&mut rprimeraw[RO_TAG_SIZE..]);
-        rprimeraw[0..RO_TAG_SIZE].copy_from_slice(&dro.next_dyadic_tag());
-        let mut kaoffsetraw = [0u8; HASH_SIZE];
-        hash(&mut kaoffsetraw, &rprimeraw);
-        let kaoffset = SecpOrd::from_bytes(&kaoffsetraw);
-        let ka = kaoffset.add(&kaprime);
+    fn sign_threshold<TR: Read + Send, TW: Write + Send>(
+        &mut self,
+        counterparties: &[usize],
+        req: &SignRequest<C>,
+        rng: &mut dyn Rng,
+        recv: &mut [Option<TR>],
+        send: &mut [Option<TW>],
+    ) -> Result<Signature<C>, MPECDSAError> {
+        let (sig, _) = self.sign_threshold_and_gen_refresh(counterparties, req, rng, recv, send)?;
+        Ok(sig)
+    }
+
+    fn sign_threshold_and_gen_refresh<TR: Read + Send, TW: Write + Send>(
+        &mut self,
+        counterparties: &[usize],
+        req: &SignRequest<C>,
+        rng: &mut dyn Rng,
+        recv: &mut [Option<TR>],
+        send: &mut [Option<TW>],
+    ) -> Result<(Signature<C>, Option<ProactiveRefreshPackage<C>>), MPECDSAError> {
+        let presig = self.presign_threshold(counterparties, rng, recv, send)?;
+        self.finish_sign_threshold(presig, req, rng, recv, send)
+    }
+
+    fn presign_threshold<TR: Read + Send, TW: Write + Send>(
+        &mut self,
+        counterparties: &[usize],
+        rng: &mut dyn Rng,
+        recv: &mut [Option<TR>],
+        send: &mut [Option<TW>],
+    ) -> Result<PreSignature<C>, MPECDSAError> {
+        self.ro.apply_subgroup_list(counterparties)?;
+        let sroindex = self.ro.current_broadcast_counter();
+
+        let ki = C::Scalar::rand(rng);
+        let kipad = C::Scalar::rand(rng);
+        let kii = ki.inv();
+        let kipadki = kii.mul(&kipad);
+
+        // create reduced sets of resources for the multipliers
+        let mut prunedrecv = prune(counterparties, recv);
+        let mut prunedsend = prune(counterparties, send);
+        let mut prunedmultiplier: Vec<&mul::MulPlayer<C>> = self
+            .multiplier
+            .iter()
+            .enumerate()
+            .filter_map(|(index, val)| {
+                if counterparties.contains(&index) {
+                    Some(val)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        let tempplayeri = self.playerindex;
+        let prunedplayerindex = counterparties
+            .iter()
+            .position(|&x| x == tempplayeri)
+            .unwrap();
+
+        //instance key and inverse instance key multiplication
+
+        let threadcount = match std::env::var_os("RAYON_NUM_THREADS") {
+            Some(val) => {
+                let val = val.into_string().unwrap().parse().unwrap();
+                if val > 0 {
+                    val
+                } else {
+                    counterparties.len()
+                }
+            }
+            None => counterparties.len(),
+        };
+
+        let rayonpool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threadcount)
+            .build()
+            .unwrap();
+
+        // message 1 send+recv, message 2 send
+        let prodshares = mprmul_round_one(
+            4,
+            prunedplayerindex,
+            &mut prunedmultiplier,
+            &self.ro,
+            rng,
+            &mut prunedrecv.as_mut_slice(),
+            &mut prunedsend.as_mut_slice(),
+            &rayonpool,
+        )?;
+
+        let mut helpfulsendbuffer = vec![Some(Cursor::new(Vec::new())); counterparties.len()];
+
+        {
+            let prodshares1: Vec<&[C::Scalar]> = prodshares
+                .iter()
+                .map(|x| if x.0.len() > 0 { &x.0[0..2] } else { &x.0[..] })
+                .collect();
+
+            // message 2 send
+            mpmul_first(
+                &[ki, kipadki],
+                prunedplayerindex,
+                prodshares1.as_slice(),
+                helpfulsendbuffer
+                    .iter_mut()
+                    .collect::<Vec<_>>()
+                    .as_mut_slice(),
+            )?;
+        }
+
+        let helpfulsentbuffer = helpfulsendbuffer
+            .into_iter()
+            .map(|x| x.unwrap().into_inner())
+            .collect();
+
+        // message 2 recv
+        let linshares = mprmul_round_two(
+            prunedplayerindex,
+            &prodshares,
+            &mut prunedmultiplier,
+            &self.ro,
+            rng,
+            &mut prunedrecv.as_mut_slice(),
+            &mut prunedsend.as_mut_slice(),
+            &rayonpool,
+            Some(helpfulsentbuffer),
+        )?;
+
+        let shares: Vec<Vec<(C::Scalar, C::Scalar)>> = prodshares
+            .into_iter()
+            .zip(linshares.into_iter())
+            .map(|(prodel, linel)| prodel.0.into_iter().zip(linel.into_iter()).collect())
+            .collect();
+
+        let shares1: Vec<&[(C::Scalar, C::Scalar)]> = shares
+            .iter()
+            .map(|x| if x.len() > 0 { &x[0..2] } else { &x[..] })
+            .collect();
+
+        let shares2: Vec<&[(C::Scalar, C::Scalar)]> = shares
+            .iter()
+            .map(|x| if x.len() > 0 { &x[2..4] } else { &x[..] })
+            .collect();
+
+        // message 2 recv, message 3 to log(n)+1 send+recv
+        let mulresult = mpmul_rest(
+            &[ki, kipadki],
+            prunedplayerindex,
+            shares1.as_slice(),
+            &mut prunedrecv.as_mut_slice(),
+            &mut prunedsend.as_mut_slice(),
+        )?;
+        let ui = mulresult[0];
+        let vi = mulresult[1];
+
+        let zi = self.poly_point.mul(&lagrange_coefficient::<C>(
+            counterparties,
+            prunedplayerindex,
+        ));
+
+        //secret key multiplication, step one
+        // message log(n)+2 send
+        mpswapmul_send(
+            &[(vi, zi)],
+            prunedplayerindex,
+            shares2.as_slice(),
+            &mut prunedsend.as_mut_slice(),
+        )?;
+
+        //R and phi commitment, plus broadcast RO sync
+        let ri = C::Point::scalar_table_multi_ct(C::gen_table(), &ui).affine();
+        let mut pad_raw = [0u8; SCALAR_SIZE + RO_TAG_SIZE];
+        let mut ri_raw = [0u8; POINT_SIZE + RO_TAG_SIZE];
+        kipad.to_bytes(&mut pad_raw[RO_TAG_SIZE..]);
+        ri.to_wire_bytes(&mut ri_raw[RO_TAG_SIZE..]);
+
+        let mut hashout = [0u8; HASH_SIZE];
+        let mut doublecom = vec![[0u8; 2 * HASH_SIZE]; self.threshold];
+        ri_raw[0..RO_TAG_SIZE].copy_from_slice(&self.ro.next_broadcast_tag());
+        pad_raw[0..RO_TAG_SIZE].copy_from_slice(&self.ro.next_broadcast_tag());
+        hash(&mut hashout, &ri_raw);
+        doublecom[prunedplayerindex][HASH_SIZE..2 * HASH_SIZE].copy_from_slice(&hashout[..]);
+        hash(&mut hashout, &pad_raw);
+        doublecom[prunedplayerindex][0..HASH_SIZE].copy_from_slice(&hashout[..]);
+
+        // synchronize the random oracles
+        let mut sroindex_raw = [0u8; 8];
+        LittleEndian::write_u64(&mut sroindex_raw, sroindex);
+
+        for ii in 0..self.threshold {
+            if ii != prunedplayerindex {
+                // message log(n)+2 send
+                prunedsend[ii].as_mut().unwrap().write_all(&sroindex_raw)?;
+                prunedsend[ii]
+                    .as_mut()
+                    .unwrap()
+                    .write_all(&doublecom[prunedplayerindex])?;
+                prunedsend[ii].as_mut().unwrap().flush()?;
+            }
+        }
+
+        //secret key multiplication, step two
+        // message log(n)+2 recv
+        let wi = mpswapmul_recv(
+            &[(vi, zi)],
+            prunedplayerindex,
+            shares2.as_slice(),
+            &mut prunedrecv.as_mut_slice(),
+        )?[0];
+
+        // receive commitments to kjpad, rj, poks
+        for ii in 0..self.threshold {
+            if ii != prunedplayerindex {
+                // message log(n)+2 recv
+                prunedrecv[ii]
+                    .as_mut()
+                    .unwrap()
+                    .read_exact(&mut sroindex_raw)?;
+                self.ro.advance_counterparty_broadcast_counter(
+                    ii,
+                    LittleEndian::read_u64(&sroindex_raw),
+                )?;
+                prunedrecv[ii]
+                    .as_mut()
+                    .unwrap()
+                    .read_exact(&mut doublecom[ii])?;
+            }
+        }
+
+        // release ri + pok
+        for ii in 0..self.threshold {
+            if ii != prunedplayerindex {
+                // message log(n)+3 send
+                prunedsend[ii]
+                    .as_mut()
+                    .unwrap()
+                    .write_all(&ri_raw[RO_TAG_SIZE..])?;
+                prunedsend[ii].as_mut().unwrap().flush()?;
+            }
+        }
+
+        // receive rj + pok and verify against commitment
+        let mut r = ri;
+        let mut rjs = vec![C::Point::INF; self.threshold];
+        for ii in 0..self.threshold {
+            if ii != prunedplayerindex {
+                // message log(n)+3 recv
+                prunedrecv[ii]
+                    .as_mut()
+                    .unwrap()
+                    .read_exact(&mut ri_raw[RO_TAG_SIZE..])?;
+                ri_raw[0..RO_TAG_SIZE]
+                    .copy_from_slice(&self.ro.next_counterparty_broadcast_tag(ii)?);
+                hash(&mut hashout, &ri_raw);
+                if hashout != doublecom[ii][HASH_SIZE..2 * HASH_SIZE] {
+                    return Err(MPECDSAError::Blame(BlameError::new(
+                        counterparties[ii],
+                        ProtocolPhase::Sign,
+                        "failed to decommit R",
+                    )));
+                }
+                rjs[ii] = C::Point::from_wire_bytes_finite(
+                    &ri_raw[RO_TAG_SIZE..],
+                    counterparties[ii],
+                    ProtocolPhase::Sign,
+                )?;
+                r = C::Point::op(&r, &rjs[ii]).affine();
+            }
+        }
+
+        // message log(n)+3 recv
+
+        let r_table = C::Point::precomp_table(&r);
+        let mut checkpt1 = C::Point::scalar_table_multi_ct(&r_table[..], &vi).affine();
+        let mut checkpt2 = C::Point::op(
+            &C::Point::scalar_table_multi_ct(&self.pktable[..], &vi),
+            &C::Point::scalar_table_multi_ct(C::gen_table(), &wi).neg(),
+        )
+        .affine();
+        let mut checkpt3 = C::Point::scalar_table_multi_ct(&r_table[..], &wi).affine();
+
+        let mut checkpt123_raw = [0u8; 3 * POINT_SIZE + RO_TAG_SIZE];
+        checkpt1.to_wire_bytes(&mut checkpt123_raw[RO_TAG_SIZE..(POINT_SIZE + RO_TAG_SIZE)]);
+        checkpt2.to_wire_bytes(
+            &mut checkpt123_raw[(POINT_SIZE + RO_TAG_SIZE)..(2 * POINT_SIZE + RO_TAG_SIZE)],
+        );
+        checkpt3.to_wire_bytes(
+            &mut checkpt123_raw[(2 * POINT_SIZE + RO_TAG_SIZE)..(3 * POINT_SIZE + RO_TAG_SIZE)],
+        );
+        let mut checkpt123_coms = vec![[0u8; HASH_SIZE]; self.threshold];
+        checkpt123_raw[0..RO_TAG_SIZE].copy_from_slice(&self.ro.next_broadcast_tag());
+        hash(&mut checkpt123_coms[prunedplayerindex], &checkpt123_raw);
+        // send commitment
+        for ii in 0..self.threshold {
+            if ii != prunedplayerindex {
+                // message log(n)+4 send
+                prunedsend[ii]
+                    .as_mut()
+                    .unwrap()
+                    .write_all(&checkpt123_coms[prunedplayerindex])?;
+                prunedsend[ii].as_mut().unwrap().flush()?;
+            }
+        }
+
+        // receive commitments checkpts
+        for ii in 0..self.threshold {
+            if ii != prunedplayerindex {
+                // message log(n)+4 recv
+                prunedrecv[ii]
+                    .as_mut()
+                    .unwrap()
+                    .read_exact(&mut checkpt123_coms[ii])?;
+            }
+        }
+
+        // release kipad and checkpts
+        for ii in 0..self.threshold {
+            if ii != prunedplayerindex {
+                // message log(n)+5 send
+                prunedsend[ii]
+                    .as_mut()
+                    .unwrap()
+                    .write_all(&pad_raw[(RO_TAG_SIZE)..])?;
+                prunedsend[ii]
+                    .as_mut()
+                    .unwrap()
+                    .write_all(&checkpt123_raw[(RO_TAG_SIZE)..])?;
+                prunedsend[ii].as_mut().unwrap().flush()?;
+            }
+        }
+
+        // receive kjpad and verify against commitment
+        let mut kpad = kipad;
+        for ii in 0..self.threshold {
+            if ii != prunedplayerindex {
+                let mut comcomp = [0u8; HASH_SIZE];
+                pad_raw[0..RO_TAG_SIZE]
+                    .copy_from_slice(&self.ro.next_counterparty_broadcast_tag(ii)?);
+                // message log(n)+5 recv
+                prunedrecv[ii]
+                    .as_mut()
+                    .unwrap()
+                    .read_exact(&mut pad_raw[RO_TAG_SIZE..])?;
+                hash(&mut comcomp, &pad_raw);
+                let kjpad = C::Scalar::from_bytes(&pad_raw[RO_TAG_SIZE..]);
+                if comcomp == doublecom[ii][0..HASH_SIZE] {
+                    kpad = kpad.mul(&kjpad);
+                } else {
+                    return Err(MPECDSAError::Blame(BlameError::new(
+                        counterparties[ii],
+                        ProtocolPhase::Sign,
+                        "failed to decommit multiplication pad",
+                    )));
+                }
+
+                checkpt123_raw[0..RO_TAG_SIZE]
+                    .copy_from_slice(&self.ro.next_counterparty_broadcast_tag(ii)?);
+                // message log(n)+5 recv
+                prunedrecv[ii]
+                    .as_mut()
+                    .unwrap()
+                    .read_exact(&mut checkpt123_raw[RO_TAG_SIZE..])?;
+                hash(&mut comcomp, &checkpt123_raw);
+                if comcomp == checkpt123_coms[ii] {
+                    let checkpt1_frag = C::Point::from_wire_bytes(
+                        &checkpt123_raw[RO_TAG_SIZE..(POINT_SIZE + RO_TAG_SIZE)],
+                        counterparties[ii],
+                        ProtocolPhase::Sign,
+                    )?;
+                    let checkpt2_frag = C::Point::from_wire_bytes(
+                        &checkpt123_raw[(POINT_SIZE + RO_TAG_SIZE)..(2 * POINT_SIZE + RO_TAG_SIZE)],
+                        counterparties[ii],
+                        ProtocolPhase::Sign,
+                    )?;
+                    let checkpt3_frag = C::Point::from_wire_bytes(
+                        &checkpt123_raw
+                            [(2 * POINT_SIZE + RO_TAG_SIZE)..(3 * POINT_SIZE + RO_TAG_SIZE)],
+                        counterparties[ii],
+                        ProtocolPhase::Sign,
+                    )?;
+                    checkpt1 = C::Point::op(&checkpt1, &checkpt1_frag).affine();
+                    checkpt2 = C::Point::op(&checkpt2, &checkpt2_frag).affine();
+                    checkpt3 = C::Point::op(&checkpt3, &checkpt3_frag).affine();
+                } else {
+                    return Err(MPECDSAError::Blame(BlameError::new(
+                        counterparties[ii],
+                        ProtocolPhase::Sign,
+                        "failed to decommit consistency checks",
+                    )));
+                }
+            }
+        }
+
+        if kpad == C::Scalar::ZERO {
+            return Err(MPECDSAError::Proof(ProofError::new(
+                &"Multicplication pad value was zero",
+            )));
+        }
+
+        if checkpt1.affine() != C::Point::scalar_table_multi(C::gen_table(), &kpad).affine() {
+            return Err(MPECDSAError::Proof(ProofError::new(
+                &"First consistency check failed",
+            )));
+        }
+
+        if !checkpt2.is_infinity() {
+            return Err(MPECDSAError::Proof(ProofError::new(
+                &"Second consistency check failed",
+            )));
+        }
+
+        if checkpt3 != C::Point::scalar_table_multi(&self.pktable, &kpad).affine() {
+            return Err(MPECDSAError::Proof(ProofError::new(
+                &"Third consistency check failed",
+            )));
+        }
+
+        let kpadinv = kpad.inv();
+        Ok(PreSignature {
+            parties: counterparties.to_vec(),
+            playerindex: self.playerindex,
+            pk: self.pk,
+            epoch: self.epoch,
+            r,
+            kinv: vi.mul(&kpadinv),
+            skkinv: wi.mul(&kpadinv),
+        })
+    }
+
+    fn finish_sign_threshold<TR: Read + Send, TW: Write + Send>(
+        &mut self,
+        presig: PreSignature<C>,
+        req: &SignRequest<C>,
+        rng: &mut dyn Rng,
+        recv: &mut [Option<TR>],
+        send: &mut [Option<TW>],
+    ) -> Result<(Signature<C>, Option<ProactiveRefreshPackage<C>>), MPECDSAError> {
+        let counterparties = &presig.parties[..];
+        self.ro.apply_subgroup_list(counterparties)?;
+        let sroindex = self.ro.current_broadcast_counter();
+
+        let mut prunedrecv = prune(counterparties, recv);
+        let mut prunedsend = prune(counterparties, send);
+        let tempplayeri = self.playerindex;
+        let prunedplayerindex = counterparties
+            .iter()
+            .position(|&x| x == tempplayeri)
+            .unwrap();
+        let r = presig.r;
+
+        let z = C::Scalar::from_bytes(req.digest);
+
+        let mut rxb = [0u8; SCALAR_SIZE];
+        r.x().to_bytes(&mut rxb);
+        let rx = C::Scalar::from_bytes(&rxb);
+
+        let skkinv = presig
+            .skkinv
+            .add(&derived_tweak::<C>(req.key).mul(&presig.kinv));
+        let mut sig = z.mul(&presig.kinv).add(&skkinv.mul(&rx));
+        let mut sig_frag_raw = [0u8; SCALAR_SIZE];
+        sig.to_bytes(&mut sig_frag_raw);
+
+        // the presignature may have been made long ago, so the random oracles are
+        // synchronized again alongside the signature fragments
+        let mut sroindex_raw = [0u8; 8];
+        LittleEndian::write_u64(&mut sroindex_raw, sroindex);
+
+        for ii in 0..self.threshold {
+            if ii != prunedplayerindex {
+                // message log(n)+6 send
+                prunedsend[ii].as_mut().unwrap().write_all(&sroindex_raw)?;
+                prunedsend[ii].as_mut().unwrap().write_all(&sig_frag_raw)?;
+                prunedsend[ii].as_mut().unwrap().flush()?;
+            }
+        }
+
+        for ii in 0..self.threshold {
+            if ii != prunedplayerindex {
+                // message log(n)+6 recv
+                prunedrecv[ii]
+                    .as_mut()
+                    .unwrap()
+                    .read_exact(&mut sroindex_raw)?;
+                self.ro.advance_counterparty_broadcast_counter(
+                    ii,
+                    LittleEndian::read_u64(&sroindex_raw),
+                )?;
+                prunedrecv[ii]
+                    .as_mut()
+                    .unwrap()
+                    .read_exact(&mut sig_frag_raw)?;
+                let sig_frag = C::Scalar::from_bytes(&sig_frag_raw);
+                sig = sig.add(&sig_frag);
+            }
+        }
+
+        if !verify_with_key::<C>(req.digest, (&rx, &sig), &self.pktable[..], req.key) {
+            return Err(MPECDSAError::Proof(ProofError::new(
+                "Signature verification failed for ECDSA signing",
+            )));
+        }
+
+        // optional: proactive refresh
+        let refreshpackage = if let Some(tag) = req.tag {
+            Some(self.gen_refresh_threshold(
+                &r,
+                tag,
+                counterparties,
+                rng,
+                prunedrecv.as_mut_slice(),
+                prunedsend.as_mut_slice(),
+            )?)
+        } else {
+            None
+        };
+
+        Ok((
+            ecdsa::Signature {
+                r: rx,
+                s: sig,
+                recid: ecdsa::recovery_id(&r),
+            }
+            .normalize_s(),
+            refreshpackage,
+        ))
+    }
+
+    fn sign2t_alice<TR: Read, TW: Write + Send>(
+        &mut self,
+        counterparty: usize,
+        req: &SignRequest<C>,
+        rng: &mut dyn Rng,
+        recv: &mut TR,
+        send: &mut TW,
+    ) -> Result<Option<Signature<C>>, MPECDSAError> {
+        let res = self.sign2t_and_gen_refresh_alice(counterparty, req, rng, recv, send);
+        if let Ok((sig, _)) = res {
+            Ok(sig)
+        } else {
+            Err(res.unwrap_err())
+        }
+    }
+
+    fn sign2t_and_gen_refresh_alice<TR: Read, TW: Write + Send>(
+        &mut self,
+        counterparty: usize,
+        req: &SignRequest<C>,
+        rng: &mut dyn Rng,
+        recv: &mut TR,
+        send: &mut TW,
+    ) -> Result<SignOutput<C>, MPECDSAError> {
+        let (parties, prunedcpindex) = if self.playerindex > counterparty {
+            ([counterparty, self.playerindex], 0)
+        } else {
+            ([self.playerindex, counterparty], 1)
+        };
+        self.ro.apply_subgroup_list(&parties)?;
+        let sroindex = self.ro.current_broadcast_counter();
+        let mut sroindex_raw = [0u8; 8];
+        // precompute things you won't need till later
+
+        // alice's instance key is of a special form for the two round version:
+        // k_a = H(k'_a*G)+k'_a
+        // this prevents her from choosing the value conveniently
+        let kaprime = C::Scalar::rand(rng);
+        let kapad = C::Scalar::rand(rng);
+
+        let z = C::Scalar::from_bytes(req.digest);
+
+        // calculate lagrange coefficient
+        let mut coef = C::Scalar::from_native((counterparty + 1) as u64);
+        coef = coef.mul(
+            &(C::Scalar::from_native((counterparty + 1) as u64)
+                .sub(&C::Scalar::from_native((self.playerindex + 1) as u64)))
+            .inv(),
+        );
+        let t0a = coef.mul(&self.poly_point);
+
+        let multiplier = match self.multiplier[counterparty] {
+            MulPlayer::Sender(ref multiplier) => multiplier,
+            _ => {
+                return Err(MPECDSAError::General(GeneralError::new(
+                    "Alice was given Recver half of multiplier protocol.",
+                )))
+            }
+        };
+
+        // online phase
+        recv.read_exact(&mut sroindex_raw)?;
+        self.ro.advance_counterparty_broadcast_counter(
+            prunedcpindex,
+            LittleEndian::read_u64(&sroindex_raw),
+        )?;
+        let dro = self.ro.get_dyadic_tagger(prunedcpindex).unwrap();
+
+        // recv D_b from bob
+        let mut dbraw = [0u8; POINT_SIZE];
+        recv.read_exact(&mut dbraw)?;
+        let db = C::Point::from_wire_bytes_finite(&dbraw, counterparty, ProtocolPhase::Sign)?;
+        let dbtable = C::Point::precomp_table(&db);
+
+        let rprime = C::Point::scalar_table_multi_ct(&dbtable[..], &kaprime).affine();
+        let mut rprimeraw = [0u8; POINT_SIZE + RO_TAG_SIZE];
+        rprime.to_wire_bytes(&mut rprimeraw[RO_TAG_SIZE..]);
+        rprimeraw[0..RO_TAG_SIZE].copy_from_slice(&dro.next_dyadic_tag());
+        let mut kaoffsetraw = [0u8; HASH_SIZE];
+        hash(&mut kaoffsetraw, &rprimeraw);
+        let kaoffset = C::Scalar::from_bytes(&kaoffsetraw);
+        let ka = kaoffset.add(&kaprime);
 
         let kai = ka.inv();
         let t0ai = kai.mul(&t0a);
 
         // compute R = k_a*k_b*G, and get the x coordinate
         // do this early to save time later and give bob a chance to start the extensions
-        let r = Secp::scalar_table_multi(&dbtable[..], &ka).affine();
-        let mut rxb = [0u8; SecpOrd::NBYTES];
-        r.x.to_bytes(&mut rxb);
-        let rx = SecpOrd::from_bytes(&rxb);
-        let r_table = Secp::precomp_table(&r);
-        let kapadda = Secp::scalar_table_multi(&precomp::P256_TABLE[..], &ka.mul(&kapad));
+        let r = C::Point::scalar_table_multi_ct(&dbtable[..], &ka).affine();
+        let mut rxb = [0u8; SCALAR_SIZE];
+        r.x().to_bytes(&mut rxb);
+        let rx = C::Scalar::from_bytes(&rxb);
+        let r_table = C::Point::precomp_table(&r);
+        let kapadda = C::Point::scalar_table_multi_ct(C::gen_table(), &ka.mul(&kapad));
 
         // Prove knowledge of ka for R; hardcoded fiat-shamir so we can do preprocessing
-        let kaproof_randcommitted = SecpOrd::rand(rng);
-        let mut kaproof_buf = [0u8; 2 * Secp::NBYTES + SecpOrd::NBYTES + RO_TAG_SIZE];
-        let kaproof_randcommitment = Secp::scalar_table_multi(&dbtable[..], &kaproof_randcommitted);
-        kaproof_randcommitment.to_bytes(
-            &mut kaproof_buf[(RO_TAG_SIZE + Secp::NBYTES)..(RO_TAG_SIZE + 2 * Secp::NBYTES)],
+        let kaproof_randcommitted = C::Scalar::rand(rng);
+        let mut kaproof_buf = [0u8; 2 * POINT_SIZE + SCALAR_SIZE + RO_TAG_SIZE];
+        let kaproof_randcommitment =
+            C::Point::scalar_table_multi_ct(&dbtable[..], &kaproof_randcommitted);
+        kaproof_randcommitment.to_wire_bytes(
+            &mut kaproof_buf[(RO_TAG_SIZE + POINT_SIZE)..(RO_TAG_SIZE + 2 * POINT_SIZE)],
         );
-        r.to_bytes(&mut kaproof_buf[RO_TAG_SIZE..(RO_TAG_SIZE + Secp::NBYTES)]);
+        r.to_wire_bytes(&mut kaproof_buf[RO_TAG_SIZE..(RO_TAG_SIZE + POINT_SIZE)]);
         kaproof_buf[0..RO_TAG_SIZE].copy_from_slice(&dro.next_dyadic_tag());
         let mut kaproof_challenge = [0u8; HASH_SIZE];
         hash(
             &mut kaproof_challenge,
-            &kaproof_buf[0..(2 * Secp::NBYTES + RO_TAG_SIZE)],
+            &kaproof_buf[0..(2 * POINT_SIZE + RO_TAG_SIZE)],
         );
-        let kaproof_challenge = SecpOrd::from_bytes(&kaproof_challenge[..]);
+        let kaproof_challenge = C::Scalar::from_bytes(&kaproof_challenge[..]);
         let kaproof_z = ka.mul(&kaproof_challenge).add(&kaproof_randcommitted);
-        kaproof_z.to_bytes(&mut kaproof_buf[(RO_TAG_SIZE + 2 * Secp::NBYTES)..]);
+        kaproof_z.to_bytes(&mut kaproof_buf[(RO_TAG_SIZE + 2 * POINT_SIZE)..]);
 
         // generate OT extensions for two multiplications (input independent for alice)
         let extensions = multiplier.mul_extend(2, &dro, recv)?;
@@ -1443,15 +3180,15 @@ impl ThresholdSigner {
 
         let mut bufsend = BufWriter::new(send);
         LittleEndian::write_u64(&mut sroindex_raw, sroindex);
-        bufsend.write(&sroindex_raw)?;
+        bufsend.write_all(&sroindex_raw)?;
 
         // alice sends D'_a = k'_a*G rather than D_a so that bob can check her work
-        bufsend.write(&rprimeraw[RO_TAG_SIZE..])?;
-        bufsend.write(&kaproof_buf[(RO_TAG_SIZE + Secp::NBYTES)..])?;
+        bufsend.write_all(&rprimeraw[RO_TAG_SIZE..])?;
+        bufsend.write_all(&kaproof_buf[(RO_TAG_SIZE + POINT_SIZE)..])?;
         bufsend.flush()?;
 
         // optional: proactive refresh
-        let (refreshpackage, mut bufsend) = if let Some(tag) = tag {
+        let (refreshpackage, mut bufsend) = if let Some(tag) = req.tag {
             let send = bufsend
                 .into_inner()
                 .map_err(|_| MPECDSAError::General(GeneralError::new("Buffer unwrap error")))
@@ -1480,64 +3217,81 @@ impl ThresholdSigner {
         let t2a = t2aa.add(&t2ba);
 
         // compute check value Gamma_1 for alice
-        let gamma1 = Secp::op(
-            &Secp::op(
-                &Secp::scalar_table_multi(&r_table[..], &t1a.neg()),
+        let gamma1 = C::Point::op(
+            &C::Point::op(
+                &C::Point::scalar_table_multi_ct(&r_table[..], &t1a.neg()),
                 &kapadda,
             ),
-            &Secp::gen(),
+            &C::Point::gen(),
         )
         .affine();
-        let mut gamma1raw = [0u8; Secp::NBYTES + RO_TAG_SIZE];
-        gamma1.to_bytes(&mut gamma1raw[RO_TAG_SIZE..]);
+        let mut gamma1raw = [0u8; POINT_SIZE + RO_TAG_SIZE];
+        gamma1.to_wire_bytes(&mut gamma1raw[RO_TAG_SIZE..]);
         gamma1raw[0..RO_TAG_SIZE].copy_from_slice(&dro.next_dyadic_tag());
         let mut enckey = [0u8; HASH_SIZE];
         hash(&mut enckey, &gamma1raw);
-        let mut kapadraw = [0u8; SecpOrd::NBYTES];
+        let mut kapadraw = [0u8; SCALAR_SIZE];
         kapad.to_bytes(&mut kapadraw);
-        for ii in 0..SecpOrd::NBYTES {
+        for ii in 0..SCALAR_SIZE {
             kapadraw[ii] ^= enckey[ii];
         }
-        bufsend.write(&kapadraw)?;
+        bufsend.write_all(&kapadraw)?;
         bufsend.flush()?;
 
         // compute signature share m_a for alice
-        let mut ma = [0u8; SecpOrd::NBYTES];
-        let m_a = t1a.mul(&z).add(&t2a.mul(&rx));
+        let mut ma = [0u8; SCALAR_SIZE];
+        let t2atweaked = t2a.add(&derived_tweak::<C>(req.key).mul(&t1a));
+        let m_a = t1a.mul(&z).add(&t2atweaked.mul(&rx));
         m_a.to_bytes(&mut ma);
 
         // compute check value Gamma_2, and encrypt m_a with H(Gamma_2)
-        let t2ag = Secp::scalar_table_multi(&precomp::P256_TABLE[..], &t2a.neg());
-        let t1apk = Secp::scalar_table_multi(&self.pktable[..], &t1a);
-        let gamma2 = Secp::op(&t2ag, &t1apk).affine();
-        let mut gamma2raw = [0u8; Secp::NBYTES + RO_TAG_SIZE];
-        gamma2.to_bytes(&mut gamma2raw[RO_TAG_SIZE..]);
+        let t2ag = C::Point::scalar_table_multi_ct(C::gen_table(), &t2a.neg());
+        let t1apk = C::Point::scalar_table_multi_ct(&self.pktable[..], &t1a);
+        let gamma2 = C::Point::op(&t2ag, &t1apk).affine();
+        let mut gamma2raw = [0u8; POINT_SIZE + RO_TAG_SIZE];
+        gamma2.to_wire_bytes(&mut gamma2raw[RO_TAG_SIZE..]);
         gamma2raw[0..RO_TAG_SIZE].copy_from_slice(&dro.next_dyadic_tag());
         hash(&mut enckey, &gamma2raw);
-        for ii in 0..SecpOrd::NBYTES {
+        for ii in 0..SCALAR_SIZE {
             ma[ii] ^= enckey[ii];
         }
 
         // send encrypted signature share
-        bufsend.write(&ma)?;
+        bufsend.write_all(&ma)?;
         bufsend.flush()?;
 
         // end second message (alice to bob)
 
-        Ok(refreshpackage)
+        // optionally, receive the signature from bob, and check it
+        let sig = if req.returnsig {
+            Some(recv_signature::<C, _>(
+                req.digest,
+                counterparty,
+                &self.pk,
+                req.key,
+                ecdsa::recovery_id(&r),
+                recv,
+            )?)
+        } else {
+            None
+        };
+
+        // end third message (bob to alice)
+
+        Ok((sig, refreshpackage))
     }
 
     fn sign2t_bob<TR: Read, TW: Write + Send>(
         &mut self,
         counterparty: usize,
-        msg: &[u8],
+        req: &SignRequest<C>,
         rng: &mut dyn Rng,
         recv: &mut TR,
         send: &mut TW,
-    ) -> Result<(SecpOrd, SecpOrd), MPECDSAError> {
-        let res = self.sign2t_and_gen_refresh_bob(counterparty, msg, None, rng, recv, send);
-        if let Ok((r0, r1, _)) = res {
-            Ok((r0, r1))
+    ) -> Result<Signature<C>, MPECDSAError> {
+        let res = self.sign2t_and_gen_refresh_bob(counterparty, req, rng, recv, send);
+        if let Ok((sig, _)) = res {
+            Ok(sig)
         } else {
             Err(res.unwrap_err())
         }
@@ -1546,12 +3300,11 @@ impl ThresholdSigner {
     fn sign2t_and_gen_refresh_bob<TR: Read, TW: Write>(
         &mut self,
         counterparty: usize,
-        msg: &[u8],
-        tag: Option<&[u8]>,
+        req: &SignRequest<C>,
         rng: &mut dyn Rng,
         recv: &mut TR,
         send: &mut TW,
-    ) -> Result<(SecpOrd, SecpOrd, Option<ProactiveRefreshPackage>), MPECDSAError> {
+    ) -> Result<(Signature<C>, Option<ProactiveRefreshPackage<C>>), MPECDSAError> {
         let (parties, prunedcpindex) = if self.playerindex > counterparty {
             ([counterparty, self.playerindex], 0)
         } else {
@@ -1563,7 +3316,7 @@ impl ThresholdSigner {
         LittleEndian::write_u64(&mut sroindex_raw, sroindex);
 
         let mut bufsend = BufWriter::new(send);
-        bufsend.write(&sroindex_raw)?;
+        bufsend.write_all(&sroindex_raw)?;
 
         let multiplier = match self.multiplier[counterparty] {
             MulPlayer::Recver(ref multiplier) => multiplier,
@@ -1576,18 +3329,18 @@ impl ThresholdSigner {
         // no precomputation - we want to begin writing as soon as possible
 
         // choose k_b, calc D_b = k_b*G, send D_b
-        let kb = SecpOrd::rand(rng);
-        let db = Secp::scalar_table_multi(&precomp::P256_TABLE[..], &kb);
-        let mut dbraw = [0u8; Secp::NBYTES];
-        db.to_bytes(&mut dbraw);
-        bufsend.write(&dbraw)?;
+        let kb = C::Scalar::rand(rng);
+        let db = C::Point::scalar_table_multi_ct(C::gen_table(), &kb);
+        let mut dbraw = [0u8; POINT_SIZE];
+        db.to_wire_bytes(&mut dbraw);
+        bufsend.write_all(&dbraw)?;
         bufsend.flush()?;
 
         // calculate lagrange coefficient
-        let mut coef = SecpOrd::from_native((counterparty + 1) as u64);
+        let mut coef = C::Scalar::from_native((counterparty + 1) as u64);
         coef = coef.mul(
-            &(SecpOrd::from_native((counterparty + 1) as u64)
-                .sub(&SecpOrd::from_native((self.playerindex + 1) as u64)))
+            &(C::Scalar::from_native((counterparty + 1) as u64)
+                .sub(&C::Scalar::from_native((self.playerindex + 1) as u64)))
             .inv(),
         );
         let t0b = coef.mul(&self.poly_point);
@@ -1611,65 +3364,68 @@ impl ThresholdSigner {
         )?;
 
         // receive D'_a from alice, calculate D_a as D_a = H(D'_a)*G + D'_a
-        let mut rprimeraw = [0u8; Secp::NBYTES + RO_TAG_SIZE];
+        let mut rprimeraw = [0u8; POINT_SIZE + RO_TAG_SIZE];
         recv.read_exact(&mut rprimeraw[RO_TAG_SIZE..])?;
         rprimeraw[0..RO_TAG_SIZE].copy_from_slice(&rprime_tag);
-        let rprime = Secp::from_bytes(&rprimeraw[RO_TAG_SIZE..]);
+        let rprime = C::Point::from_wire_bytes_finite(
+            &rprimeraw[RO_TAG_SIZE..],
+            counterparty,
+            ProtocolPhase::Sign,
+        )?;
         let mut kaoffsetraw = [0u8; HASH_SIZE];
         hash(&mut kaoffsetraw, &rprimeraw);
-        let kaoffset = SecpOrd::from_bytes(&kaoffsetraw);
-        let kbkaoffsetg = Secp::scalar_table_multi(&precomp::P256_TABLE[..], &kb.mul(&kaoffset));
+        let kaoffset = C::Scalar::from_bytes(&kaoffsetraw);
+        let kbkaoffsetg = C::Point::scalar_table_multi_ct(C::gen_table(), &kb.mul(&kaoffset));
 
         // compute R = k_a*k_b*G, and get the x coordinate
-        let r = Secp::op(&kbkaoffsetg, &rprime).affine();
-        let mut rxb = [0u8; SecpOrd::NBYTES];
-        r.x.to_bytes(&mut rxb);
-        let rx = SecpOrd::from_bytes(&rxb);
-        let r_table = Secp::precomp_table(&r);
+        let r = C::Point::op(&kbkaoffsetg, &rprime).affine();
+        let mut rxb = [0u8; SCALAR_SIZE];
+        r.x().to_bytes(&mut rxb);
+        let rx = C::Scalar::from_bytes(&rxb);
+        let r_table = C::Point::precomp_table(&r);
 
         // verify alice's PoK of k_a for R
-        let mut kaproof_buf = [0u8; 2 * Secp::NBYTES + SecpOrd::NBYTES + RO_TAG_SIZE];
+        let mut kaproof_buf = [0u8; 2 * POINT_SIZE + SCALAR_SIZE + RO_TAG_SIZE];
         kaproof_buf[0..RO_TAG_SIZE].copy_from_slice(&kaproof_tag);
-        r.to_bytes(&mut kaproof_buf[RO_TAG_SIZE..(Secp::NBYTES + RO_TAG_SIZE)]);
-        recv.read_exact(&mut kaproof_buf[(RO_TAG_SIZE + Secp::NBYTES)..])?;
-        let kaproof_randcommitment = Secp::from_bytes(
-            &kaproof_buf[(RO_TAG_SIZE + Secp::NBYTES)..(RO_TAG_SIZE + 2 * Secp::NBYTES)],
-        );
-        let kaproof_z = SecpOrd::from_bytes(&kaproof_buf[(RO_TAG_SIZE + 2 * Secp::NBYTES)..]);
+        r.to_wire_bytes(&mut kaproof_buf[RO_TAG_SIZE..(POINT_SIZE + RO_TAG_SIZE)]);
+        recv.read_exact(&mut kaproof_buf[(RO_TAG_SIZE + POINT_SIZE)..])?;
+        let kaproof_randcommitment = C::Point::from_wire_bytes(
+            &kaproof_buf[(RO_TAG_SIZE + POINT_SIZE)..(RO_TAG_SIZE + 2 * POINT_SIZE)],
+            counterparty,
+            ProtocolPhase::Sign,
+        )?;
+        let kaproof_z = C::Scalar::from_bytes(&kaproof_buf[(RO_TAG_SIZE + 2 * POINT_SIZE)..]);
         let mut kaproof_challenge = [0u8; HASH_SIZE];
         hash(
             &mut kaproof_challenge,
-            &kaproof_buf[0..(2 * Secp::NBYTES + RO_TAG_SIZE)],
+            &kaproof_buf[0..(2 * POINT_SIZE + RO_TAG_SIZE)],
         );
-        let kaproof_challenge = SecpOrd::from_bytes(&kaproof_challenge[..]);
-        let kaproof_lhs = Secp::op(
-            &Secp::scalar_table_multi(&r_table[..], &kaproof_challenge),
+        let kaproof_challenge = C::Scalar::from_bytes(&kaproof_challenge[..]);
+        let kaproof_lhs = C::Point::op(
+            &C::Point::scalar_table_multi(&r_table[..], &kaproof_challenge),
             &kaproof_randcommitment,
         )
         .affine();
         let kaproof_rhs =
-            Secp::scalar_table_multi(&precomp::P256_TABLE[..], &kaproof_z.mul(&kb)).affine();
+            C::Point::scalar_table_multi_ct(C::gen_table(), &kaproof_z.mul(&kb)).affine();
         if kaproof_lhs != kaproof_rhs {
-            return Err(MPECDSAError::Proof(ProofError::new(
+            return Err(MPECDSAError::Blame(BlameError::new(
+                counterparty,
+                ProtocolPhase::Sign,
                 "Proof of Knowledge failed for ECDSA signing (alice cheated)",
             )));
         }
 
         // optional: proactive refresh
-        let refreshpackage = if let Some(tag) = tag {
-            let send = bufsend
-                .into_inner()
-                .map_err(|_| MPECDSAError::General(GeneralError::new("Buffer unwrap error")))
-                .unwrap();
+        let refreshpackage = if let Some(tag) = req.tag {
+            bufsend.flush()?;
+            let send: &mut TW = bufsend.get_mut();
             Some(self.gen_refresh_2t(&r, tag, counterparty, prunedcpindex, rng, recv, send)?)
         } else {
             None
         };
 
-        // hash message
-        let mut z = [0u8; HASH_SIZE];
-        ecdsa_hash(&mut z, msg);
-        let z = SecpOrd::from_bytes(&z);
+        let z = C::Scalar::from_bytes(req.digest);
 
         // perform multiplications using the extensions we just generated
         let t12 = multiplier.mul_transfer(
@@ -1684,40 +3440,41 @@ impl ThresholdSigner {
         let t2ab = t12[1];
         let t2bb = t12[2];
         let t2b = t2ab.add(&t2bb);
-        let gamma1 = Secp::scalar_table_multi(&r_table[..], &t1b).affine(); // start calculating gamma_b early, to give the sender extra time
-        let mut gamma1raw = [0u8; Secp::NBYTES + RO_TAG_SIZE];
-        gamma1.to_bytes(&mut gamma1raw[RO_TAG_SIZE..]);
+        let gamma1 = C::Point::scalar_table_multi_ct(&r_table[..], &t1b).affine(); // start calculating gamma_b early, to give the sender extra time
+        let mut gamma1raw = [0u8; POINT_SIZE + RO_TAG_SIZE];
+        gamma1.to_wire_bytes(&mut gamma1raw[RO_TAG_SIZE..]);
         gamma1raw[0..RO_TAG_SIZE].copy_from_slice(&dro.next_dyadic_tag());
         let mut enckey = [0u8; HASH_SIZE];
         hash(&mut enckey, &gamma1raw);
 
         // compute the first check messages Gamma_1, and decrypt the pad
-        let mut kapadraw = [0u8; SecpOrd::NBYTES];
+        let mut kapadraw = [0u8; SCALAR_SIZE];
         recv.read_exact(&mut kapadraw)?;
-        for ii in 0..SecpOrd::NBYTES {
+        for ii in 0..SCALAR_SIZE {
             kapadraw[ii] ^= enckey[ii];
         }
-        let kapad = SecpOrd::from_bytes(&kapadraw);
+        let kapad = C::Scalar::from_bytes(&kapadraw);
 
         let t1baug = t1b.sub(&kbi.mul(&kapad));
-        let t2bg = Secp::scalar_table_multi(&precomp::P256_TABLE[..], &t2b);
-        let t1bpk = Secp::scalar_table_multi(&self.pktable[..], &t1baug.neg());
-        let gamma2 = Secp::op(&t2bg, &t1bpk).affine();
-        let mut gamma2raw = [0u8; Secp::NBYTES + RO_TAG_SIZE];
-        gamma2.to_bytes(&mut gamma2raw[RO_TAG_SIZE..]);
+        let t2bg = C::Point::scalar_table_multi_ct(C::gen_table(), &t2b);
+        let t1bpk = C::Point::scalar_table_multi_ct(&self.pktable[..], &t1baug.neg());
+        let gamma2 = C::Point::op(&t2bg, &t1bpk).affine();
+        let mut gamma2raw = [0u8; POINT_SIZE + RO_TAG_SIZE];
+        gamma2.to_wire_bytes(&mut gamma2raw[RO_TAG_SIZE..]);
         gamma2raw[0..RO_TAG_SIZE].copy_from_slice(&dro.next_dyadic_tag());
         hash(&mut enckey, &gamma2raw);
 
         // compute bob's signature share m_b
-        let m_b = t1baug.mul(&z).add(&t2b.mul(&rx));
+        let t2btweaked = t2b.add(&derived_tweak::<C>(req.key).mul(&t1baug));
+        let m_b = t1baug.mul(&z).add(&t2btweaked.mul(&rx));
 
         // receive alice's signature share m_a, and decrypt using expected key
-        let mut ma = [0u8; SecpOrd::NBYTES];
+        let mut ma = [0u8; SCALAR_SIZE];
         recv.read_exact(&mut ma)?;
-        for ii in 0..SecpOrd::NBYTES {
+        for ii in 0..SCALAR_SIZE {
             ma[ii] ^= enckey[ii];
         }
-        let m_a = SecpOrd::from_bytes(&ma);
+        let m_a = C::Scalar::from_bytes(&ma);
 
         // reconstruct signature
         let s = m_a.add(&m_b);
@@ -1725,11 +3482,20 @@ impl ThresholdSigner {
         // end second message (alice to bob)
 
         // verify signature. Abort if it's incorrect.
-        if ecdsa::ecdsa_verify_with_tables(msg, (&rx, &s), &precomp::P256_TABLE, &self.pktable[..])
-        {
-            Ok((rx, s, refreshpackage))
+        if verify_with_key::<C>(req.digest, (&rx, &s), &self.pktable[..], req.key) {
+            let sig = ecdsa::Signature {
+                r: rx,
+                s,
+                recid: ecdsa::recovery_id(&r),
+            };
+            if req.returnsig {
+                send_signature::<C, _>(&sig, &mut bufsend)?;
+            }
+            Ok((sig.normalize_s(), refreshpackage))
         } else {
-            Err(MPECDSAError::Proof(ProofError::new(
+            Err(MPECDSAError::Blame(BlameError::new(
+                counterparty,
+                ProtocolPhase::Sign,
                 "Signature verification failed for ECDSA signing (alice cheated)",
             )))
         }
@@ -1737,40 +3503,40 @@ impl ThresholdSigner {
 
     fn gen_refresh_2t<TR: Read, TW: Write>(
         &self,
-        R: &Secp,
+        R: &C::Point,
         tag: &[u8],
         counterparty: usize,
         prunedcpindex: usize,
         rng: &mut dyn Rng,
         recv: &mut TR,
         send: &mut TW,
-    ) -> Result<ProactiveRefreshPackage, MPECDSAError> {
-        let my_coin = SecpOrd::rand(rng);
-        let (my_nonce_dl, my_nonce) = Secp::rand(rng);
-        let mut coin_raw = [0u8; SecpOrd::NBYTES + RO_TAG_SIZE];
-        let mut nonce_raw = [0u8; Secp::NBYTES];
+    ) -> Result<ProactiveRefreshPackage<C>, MPECDSAError> {
+        let my_coin = C::Scalar::rand(rng);
+        let (my_nonce_dl, my_nonce) = C::Point::rand(rng);
+        let mut coin_raw = [0u8; SCALAR_SIZE + RO_TAG_SIZE];
+        let mut nonce_raw = [0u8; POINT_SIZE];
         let mut coincom = [0u8; HASH_SIZE];
         my_coin.to_bytes(&mut coin_raw[RO_TAG_SIZE..]);
-        my_nonce.to_bytes(&mut nonce_raw);
+        my_nonce.to_wire_bytes(&mut nonce_raw);
         coin_raw[0..RO_TAG_SIZE].copy_from_slice(&self.ro.next_broadcast_tag()[..]);
         hash(&mut coincom, &coin_raw);
-        let (mut prfcom, proof) = prove_dl_fs_to_com(
+        let (mut prfcom, proof) = prove_dl_fs_to_com::<C>(
             &my_nonce_dl,
             &my_nonce,
             &ModelessGroupROTagger::new(&self.ro, false),
             rng,
         )
         .unwrap();
-        send.write(&coincom)?;
-        send.write(&prfcom)?;
+        send.write_all(&coincom)?;
+        send.write_all(&prfcom)?;
         send.flush()?;
 
         recv.read_exact(&mut coincom)?;
         recv.read_exact(&mut prfcom)?;
 
-        send.write(&coin_raw[RO_TAG_SIZE..])?;
-        send.write(&nonce_raw)?;
-        send.write(&proof)?;
+        send.write_all(&coin_raw[RO_TAG_SIZE..])?;
+        send.write_all(&nonce_raw)?;
+        send.write_all(&proof)?;
         send.flush()?;
 
         recv.read_exact(&mut coin_raw[RO_TAG_SIZE..])?;
@@ -1783,116 +3549,323 @@ impl ThresholdSigner {
         let mut coincomcomp = [0u8; HASH_SIZE];
         hash(&mut coincomcomp, &coin_raw);
         if coincom != coincomcomp {
-            return Err(MPECDSAError::Proof(ProofError::new(
+            return Err(MPECDSAError::Blame(BlameError::new(
+                counterparty,
+                ProtocolPhase::Refresh,
                 "Counterparty decommitted incorrectly in proactive refresh",
             )));
         }
 
         recv.read_exact(&mut nonce_raw)?;
-        let cp_nonce = Secp::from_bytes(&nonce_raw);
-        let proofresult = verify_dl_fs_with_com(
+        let cp_nonce =
+            C::Point::from_wire_bytes_finite(&nonce_raw, counterparty, ProtocolPhase::Refresh)?;
+        let proofresult = verify_dl_fs_with_com::<C, _>(
             &cp_nonce,
             &prfcom,
             &ModelessDyadicROTagger::new(&self.ro.get_dyadic_tagger(prunedcpindex).unwrap(), false),
             recv,
+            ProtocolPhase::Refresh,
         )?;
 
         if !proofresult {
-            return Err(MPECDSAError::Proof(ProofError::new(
+            return Err(MPECDSAError::Blame(BlameError::new(
+                counterparty,
+                ProtocolPhase::Refresh,
                 "Counterparty failed to prove discrete log in proactive refresh",
             )));
         }
 
-        let schnorr_nonce = Secp::op(&my_nonce, &cp_nonce).affine();
-        let coin = my_coin.add(&SecpOrd::from_bytes(&coin_raw[RO_TAG_SIZE..]));
-
-        let mut schnorr_e_in = vec![0u8; 2 * Secp::NBYTES + SecpOrd::NBYTES + tag.len()];
-        R.to_bytes(&mut schnorr_e_in[0..Secp::NBYTES]);
-        schnorr_nonce.to_bytes(&mut schnorr_e_in[Secp::NBYTES..2 * Secp::NBYTES]);
-        coin.to_bytes(&mut schnorr_e_in[2 * Secp::NBYTES..2 * Secp::NBYTES + SecpOrd::NBYTES]);
-
-        let mut schnorr_e = [0u8; HASH_SIZE];
-        hash(&mut schnorr_e, &schnorr_e_in);
-        let schnorr_e = SecpOrd::from_bytes(&schnorr_e);
+        let schnorr_nonce = C::Point::op(&my_nonce, &cp_nonce).affine();
+        let coin = my_coin.add(&C::Scalar::from_bytes(&coin_raw[RO_TAG_SIZE..]));
+        let schnorr_e = refresh_challenge::<C>(R, &schnorr_nonce, &coin, self.epoch, tag);
 
         // calculate lagrange coefficient
-        let mut coef = SecpOrd::from_native((counterparty + 1) as u64);
+        let mut coef = C::Scalar::from_native((counterparty + 1) as u64);
         coef = coef.mul(
-            &(SecpOrd::from_native((counterparty + 1) as u64)
-                .sub(&SecpOrd::from_native((self.playerindex + 1) as u64)))
+            &(C::Scalar::from_native((counterparty + 1) as u64)
+                .sub(&C::Scalar::from_native((self.playerindex + 1) as u64)))
             .inv(),
         );
         let my_sk = coef.mul(&self.poly_point);
         let schnorr_z = my_sk.mul(&schnorr_e).add(&my_nonce_dl);
-        let mut schnorr_z_raw = [0u8; SecpOrd::NBYTES];
+        let mut schnorr_z_raw = [0u8; SCALAR_SIZE];
         schnorr_z.to_bytes(&mut schnorr_z_raw);
 
-        send.write(&schnorr_z_raw)?;
+        send.write_all(&schnorr_z_raw)?;
         send.flush()?;
         recv.read_exact(&mut schnorr_z_raw)?;
-        let cp_schnorr_z = SecpOrd::from_bytes(&schnorr_z_raw);
+        let cp_schnorr_z = C::Scalar::from_bytes(&schnorr_z_raw);
 
-        let cp_pk_e = Secp::op(
+        let cp_pk_e = C::Point::op(
             &self.pk,
-            &Secp::scalar_table_multi(&precomp::P256_TABLE[..], &my_sk).neg(),
+            &C::Point::scalar_table_multi_ct(C::gen_table(), &my_sk).neg(),
         )
         .scalar_table(&schnorr_e);
 
-        if Secp::scalar_table_multi(&precomp::P256_TABLE[..], &cp_schnorr_z).affine()
-            != Secp::op(&cp_pk_e, &cp_nonce).affine()
-        {
-            return Err(MPECDSAError::Proof(ProofError::new(
-                "Counterparty refresh signature failed to verify",
-            )));
+        if C::Point::scalar_table_multi(C::gen_table(), &cp_schnorr_z).affine()
+            != C::Point::op(&cp_pk_e, &cp_nonce).affine()
+        {
+            return Err(MPECDSAError::Blame(BlameError::new(
+                counterparty,
+                ProtocolPhase::Refresh,
+                "Counterparty refresh signature failed to verify",
+            )));
+        }
+
+        Ok((
+            *R,
+            tag.to_vec(),
+            self.epoch,
+            coin,
+            schnorr_nonce,
+            schnorr_z.add(&cp_schnorr_z),
+        ))
+    }
+
+    fn gen_refresh_threshold<TR: Read, TW: Write>(
+        &self,
+        r: &C::Point,
+        tag: &[u8],
+        counterparties: &[usize],
+        rng: &mut dyn Rng,
+        recv: &mut [&mut Option<TR>],
+        send: &mut [&mut Option<TW>],
+    ) -> Result<ProactiveRefreshPackage<C>, MPECDSAError> {
+        let prunedplayerindex = counterparties
+            .iter()
+            .position(|&x| x == self.playerindex)
+            .unwrap();
+
+        // commit to a coin and to a schnorr nonce (with PoK), as in the two-party case
+        let my_coin = C::Scalar::rand(rng);
+        let (my_nonce_dl, my_nonce) = C::Point::rand(rng);
+        let mut coin_raw = [0u8; SCALAR_SIZE + RO_TAG_SIZE];
+        let mut nonce_raw = [0u8; POINT_SIZE];
+        let mut pubshare_raw = [0u8; POINT_SIZE];
+        let mut coincoms = vec![[0u8; HASH_SIZE]; self.threshold];
+        let mut prfcoms = vec![[0u8; HASH_SIZE]; self.threshold];
+        my_coin.to_bytes(&mut coin_raw[RO_TAG_SIZE..]);
+        my_nonce.to_wire_bytes(&mut nonce_raw);
+        coin_raw[0..RO_TAG_SIZE].copy_from_slice(&self.ro.next_broadcast_tag()[..]);
+        hash(&mut coincoms[prunedplayerindex], &coin_raw);
+        let (prfcom, proof) = prove_dl_fs_to_com::<C>(
+            &my_nonce_dl,
+            &my_nonce,
+            &ModelessGroupROTagger::new(&self.ro, false),
+            rng,
+        )?;
+        prfcoms[prunedplayerindex] = prfcom;
+
+        for ii in 0..self.threshold {
+            if ii != prunedplayerindex {
+                send[ii]
+                    .as_mut()
+                    .unwrap()
+                    .write_all(&coincoms[prunedplayerindex])?;
+                send[ii].as_mut().unwrap().write_all(&prfcom)?;
+                send[ii].as_mut().unwrap().flush()?;
+            }
+        }
+
+        for ii in 0..self.threshold {
+            if ii != prunedplayerindex {
+                recv[ii].as_mut().unwrap().read_exact(&mut coincoms[ii])?;
+                recv[ii].as_mut().unwrap().read_exact(&mut prfcoms[ii])?;
+            }
+        }
+
+        // when all commitments are in, release coins, nonces, and proofs, along with our
+        // public share Y = p(i)*G, against which the others will check our partial response
+        let my_pubshare =
+            C::Point::scalar_table_multi_ct(C::gen_table(), &self.poly_point).affine();
+        my_pubshare.to_wire_bytes(&mut pubshare_raw);
+        for ii in 0..self.threshold {
+            if ii != prunedplayerindex {
+                send[ii]
+                    .as_mut()
+                    .unwrap()
+                    .write_all(&coin_raw[RO_TAG_SIZE..])?;
+                send[ii].as_mut().unwrap().write_all(&nonce_raw)?;
+                send[ii].as_mut().unwrap().write_all(&proof)?;
+                send[ii].as_mut().unwrap().write_all(&pubshare_raw)?;
+                send[ii].as_mut().unwrap().flush()?;
+            }
+        }
+
+        let mut coin = my_coin;
+        let mut schnorr_nonce = my_nonce;
+        let mut nonces = vec![my_nonce; self.threshold];
+        let mut pubshares = vec![my_pubshare; self.threshold];
+        for ii in 0..self.threshold {
+            if ii != prunedplayerindex {
+                recv[ii]
+                    .as_mut()
+                    .unwrap()
+                    .read_exact(&mut coin_raw[RO_TAG_SIZE..])?;
+                coin_raw[0..RO_TAG_SIZE]
+                    .copy_from_slice(&self.ro.next_counterparty_broadcast_tag(ii)?[..]);
+                let mut coincomcomp = [0u8; HASH_SIZE];
+                hash(&mut coincomcomp, &coin_raw);
+                if coincoms[ii] != coincomcomp {
+                    return Err(MPECDSAError::Blame(BlameError::new(
+                        counterparties[ii],
+                        ProtocolPhase::Refresh,
+                        "decommitted incorrectly in proactive refresh",
+                    )));
+                }
+                coin = coin.add(&C::Scalar::from_bytes(&coin_raw[RO_TAG_SIZE..]));
+
+                recv[ii].as_mut().unwrap().read_exact(&mut nonce_raw)?;
+                let cp_nonce = C::Point::from_wire_bytes_finite(
+                    &nonce_raw,
+                    counterparties[ii],
+                    ProtocolPhase::Refresh,
+                )?;
+                if !verify_dl_fs_with_com::<C, _>(
+                    &cp_nonce,
+                    &prfcoms[ii],
+                    &ModelessDyadicROTagger::new(&self.ro.get_dyadic_tagger(ii).unwrap(), false),
+                    &mut recv[ii].as_mut().unwrap(),
+                    ProtocolPhase::Refresh,
+                )? {
+                    return Err(MPECDSAError::Blame(BlameError::new(
+                        counterparties[ii],
+                        ProtocolPhase::Refresh,
+                        "failed to prove discrete log in proactive refresh",
+                    )));
+                }
+                schnorr_nonce = C::Point::op(&schnorr_nonce, &cp_nonce);
+                nonces[ii] = cp_nonce;
+
+                recv[ii].as_mut().unwrap().read_exact(&mut pubshare_raw)?;
+                pubshares[ii] = C::Point::from_wire_bytes(
+                    &pubshare_raw,
+                    counterparties[ii],
+                    ProtocolPhase::Refresh,
+                )?;
+            }
+        }
+        let schnorr_nonce = schnorr_nonce.affine();
+
+        // the public shares must interpolate to the group's key
+        let coefs: Vec<C::Scalar> = (0..self.threshold)
+            .map(|ii| lagrange_coefficient::<C>(counterparties, ii))
+            .collect();
+        let mut pkcheck = C::Point::INF;
+        for ii in 0..self.threshold {
+            pkcheck = C::Point::op(&pkcheck, &pubshares[ii].scalar(&coefs[ii]));
+        }
+        if pkcheck.affine() != self.pk {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Public key shares do not match the group's public key",
+            )));
+        }
+        let schnorr_e = refresh_challenge::<C>(r, &schnorr_nonce, &coin, self.epoch, tag);
+
+        let my_sk = self.poly_point.mul(&coefs[prunedplayerindex]);
+        let mut schnorr_z = my_sk.mul(&schnorr_e).add(&my_nonce_dl);
+        let mut schnorr_z_raw = [0u8; SCALAR_SIZE];
+        schnorr_z.to_bytes(&mut schnorr_z_raw);
+
+        for ii in 0..self.threshold {
+            if ii != prunedplayerindex {
+                send[ii].as_mut().unwrap().write_all(&schnorr_z_raw)?;
+                send[ii].as_mut().unwrap().flush()?;
+            }
+        }
+
+        // verify each partial response against its party's nonce and public share, so that
+        // the joint signature verifies whenever they all do
+        for ii in 0..self.threshold {
+            if ii != prunedplayerindex {
+                recv[ii].as_mut().unwrap().read_exact(&mut schnorr_z_raw)?;
+                let cp_z = C::Scalar::from_bytes(&schnorr_z_raw);
+                let expected = C::Point::op(
+                    &pubshares[ii].scalar(&coefs[ii].mul(&schnorr_e)),
+                    &nonces[ii],
+                );
+                if C::Point::scalar_table_multi(C::gen_table(), &cp_z).affine() != expected.affine()
+                {
+                    return Err(MPECDSAError::Blame(BlameError::new(
+                        counterparties[ii],
+                        ProtocolPhase::Refresh,
+                        "partial refresh signature is invalid",
+                    )));
+                }
+                schnorr_z = schnorr_z.add(&cp_z);
+            }
         }
 
-        Ok((
-            *R,
-            tag.to_vec(),
-            coin,
-            schnorr_nonce,
-            schnorr_z.add(&cp_schnorr_z),
-        ))
+        Ok((*r, tag.to_vec(), self.epoch, coin, schnorr_nonce, schnorr_z))
     }
 
-    fn apply_refresh_2t(
+    pub fn apply_refresh(
         &mut self,
-        refreshpackage: &ProactiveRefreshPackage,
+        refreshpackage: &ProactiveRefreshPackage<C>,
     ) -> Result<(), MPECDSAError> {
-        let (R, tag, coin, schnorr_nonce, schnorr_z) = refreshpackage;
-        self.ro.remove_subgroup_mask();
+        let (r, tag, epoch, coin, schnorr_nonce, schnorr_z) = refreshpackage;
 
-        let mut schnorr_e_in = vec![0u8; 2 * Secp::NBYTES + SecpOrd::NBYTES + tag.len()];
-        R.to_bytes(&mut schnorr_e_in[0..Secp::NBYTES]);
-        schnorr_nonce.to_bytes(&mut schnorr_e_in[Secp::NBYTES..2 * Secp::NBYTES]);
-        coin.to_bytes(&mut schnorr_e_in[2 * Secp::NBYTES..2 * Secp::NBYTES + SecpOrd::NBYTES]);
+        // packages made in the same epoch can be applied in any order, but each only once,
+        // and none after a package from a later epoch
+        if *epoch > self.epoch {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Refresh package was made after refreshes that have not been applied",
+            )));
+        }
+        if *epoch < self.refresh_epoch
+            || (*epoch == self.refresh_epoch && self.refreshed.contains(schnorr_nonce))
+        {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Refresh package is stale or has already been applied",
+            )));
+        }
+        if *epoch == self.refresh_epoch && self.refreshed.len() >= MAX_REFRESHED {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Too many refresh packages have been applied from this epoch",
+            )));
+        }
 
-        let mut schnorr_e = [0u8; HASH_SIZE];
-        hash(&mut schnorr_e, &schnorr_e_in);
-        let schnorr_e = SecpOrd::from_bytes(&schnorr_e);
+        let schnorr_e = refresh_challenge::<C>(r, schnorr_nonce, coin, *epoch, tag);
 
-        if Secp::scalar_table_multi(&precomp::P256_TABLE[..], &schnorr_z).affine()
-            != Secp::op(&self.pk.scalar_table(&schnorr_e), &schnorr_nonce).affine()
+        if C::Point::scalar_table_multi(C::gen_table(), &schnorr_z).affine()
+            != C::Point::op(&self.pk.scalar_table(&schnorr_e), &schnorr_nonce).affine()
         {
             Err(MPECDSAError::Proof(ProofError::new(
                 "Refresh Package failed to verify",
             )))
         } else {
-            self.poly_point = self
-                .poly_point
-                .add(&coin.mul(&SecpOrd::from_native((self.playerindex + 1) as u64)));
+            // a rejected package leaves the signer as it was, subgroup mask included
+            self.ro.remove_subgroup_mask();
+
+            // add a random polynomial of degree threshold-1 with a zero constant term to
+            // the shared polynomial. its first coefficient is the coin itself; the others
+            // are derived from the coin, so that every party derives the same polynomial
+            let mut coin_raw = [0u8; SCALAR_SIZE + 8];
+            coin.to_bytes(&mut coin_raw[0..SCALAR_SIZE]);
+            let x = C::Scalar::from_native((self.playerindex + 1) as u64);
+            let mut xpow = x;
+            let mut offset = coin.mul(&x);
+            for jj in 2..self.threshold {
+                let mut coef_raw = [0u8; HASH_SIZE];
+                LittleEndian::write_u64(&mut coin_raw[SCALAR_SIZE..], jj as u64);
+                hash(&mut coef_raw, &coin_raw);
+                xpow = xpow.mul(&x);
+                offset = offset.add(&C::Scalar::from_bytes(&coef_raw).mul(&xpow));
+            }
+            self.poly_point = self.poly_point.add(&offset);
+
             for (ii, mulinstance) in self.multiplier.iter_mut().enumerate() {
                 match mulinstance {
                     MulPlayer::Sender(m) => {
                         m.apply_refresh(
-                            &schnorr_e_in[2 * Secp::NBYTES..2 * Secp::NBYTES + SecpOrd::NBYTES],
+                            &coin_raw[0..SCALAR_SIZE],
                             &self.ro.get_dyadic_tagger(ii).unwrap(),
                         )
                         .unwrap();
                     }
                     MulPlayer::Recver(m) => {
                         m.apply_refresh(
-                            &schnorr_e_in[2 * Secp::NBYTES..2 * Secp::NBYTES + SecpOrd::NBYTES],
+                            &coin_raw[0..SCALAR_SIZE],
                             &self.ro.get_dyadic_tagger(ii).unwrap(),
                         )
                         .unwrap();
@@ -1900,20 +3873,612 @@ impl ThresholdSigner {
                     MulPlayer::Null => {}
                 };
             }
+
+            if *epoch > self.refresh_epoch {
+                self.refresh_epoch = *epoch;
+                self.refreshed.clear();
+            }
+            self.refreshed.push(*schnorr_nonce);
+            self.epoch += 1;
             Ok(())
         }
-    }
-}
+    }
+}
+
+// persistence, BIP-32 derivation, and BIP-340 signatures are defined only over secp256k1
+impl GenericThresholdSigner<Secp256k1> {
+    // Serializes all of the state required to resume signing after a restart.
+    // The resulting bytes include the RO counters, so they must be rewritten after every
+    // signing or refresh operation, and an old copy must never be restored.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut body = Vec::new();
+        // we perform only local IO, so there should never be an error
+        persist::write_usize(self.playerindex, &mut body).unwrap();
+        persist::write_usize(self.threshold, &mut body).unwrap();
+        persist::write_scalar(&self.poly_point, &mut body).unwrap();
+        persist::write_point(&self.pk, &mut body).unwrap();
+        body.write_all(&self.chaincode).unwrap();
+        persist::write_usize(self.epoch, &mut body).unwrap();
+        persist::write_usize(self.refresh_epoch, &mut body).unwrap();
+        persist::write_usize(self.refreshed.len(), &mut body).unwrap();
+        for schnorr_nonce in self.refreshed.iter() {
+            persist::write_point(schnorr_nonce, &mut body).unwrap();
+        }
+        self.ro.write_to(&mut body).unwrap();
+        for mulinstance in self.multiplier.iter() {
+            mulinstance.write_to(&mut body).unwrap();
+        }
+        let sealed = persist::seal(persist::PersistKind::ThresholdSigner, &body);
+        wipe_bytes(&mut body);
+        sealed
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Result<ThresholdSigner, MPECDSAError> {
+        let mut body = Cursor::new(persist::unseal(
+            persist::PersistKind::ThresholdSigner,
+            data,
+        )?);
+        let playerindex = persist::read_usize(&mut body)?;
+        let threshold = persist::read_usize(&mut body)?;
+        let poly_point = persist::read_scalar(&mut body)?;
+        let pk = persist::read_point(&mut body)?;
+        let mut chaincode = [0u8; CHAINCODE_SIZE];
+        body.read_exact(&mut chaincode)?;
+        let epoch = persist::read_usize(&mut body)?;
+        let refresh_epoch = persist::read_usize(&mut body)?;
+        let refreshedcount = persist::read_usize(&mut body)?;
+        let remaining = body.get_ref().len() - body.position() as usize;
+        if refresh_epoch > epoch
+            || refreshedcount > epoch - refresh_epoch
+            || refreshedcount > MAX_REFRESHED
+            || refreshedcount > remaining / Secp::NBYTES
+        {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Invalid refresh epoch in serialized signer",
+            )));
+        }
+        let mut refreshed = Vec::new();
+        for _ in 0..refreshedcount {
+            refreshed.push(persist::read_point(&mut body)?);
+        }
+        let ro = GroupROTagger::read_from(&mut body)?;
+        let playercount = ro.get_supergroup_party_count();
+        if threshold < 2 || threshold > playercount || playerindex != ro.get_playerindex() {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Invalid threshold or player index in serialized signer",
+            )));
+        }
+
+        // the player with the higher index is always Bob
+        let mut multiplier = Vec::with_capacity(playercount);
+        for ii in 0..playercount {
+            let mulinstance = MulPlayer::read_from(&mut body)?;
+            match (&mulinstance, ii.cmp(&playerindex)) {
+                (MulPlayer::Sender(_), std::cmp::Ordering::Greater)
+                | (MulPlayer::Recver(_), std::cmp::Ordering::Less)
+                | (MulPlayer::Null, std::cmp::Ordering::Equal) => multiplier.push(mulinstance),
+                // parties that have been removed from the group have no multiplier
+                (MulPlayer::Null, _) => multiplier.push(mulinstance),
+                _ => {
+                    return Err(MPECDSAError::General(GeneralError::new(
+                        "Multiplier roles in serialized signer do not match player index",
+                    )))
+                }
+            }
+        }
+        persist::finish_body(&body)?;
+
+        let pktable = Secp::precomp_table(&pk);
+        Ok(GenericThresholdSigner {
+            ro,
+            playerindex,
+            threshold,
+            multiplier,
+            poly_point,
+            pk,
+            pktable,
+            chaincode,
+            epoch,
+            refresh_epoch,
+            refreshed,
+        })
+    }
+
+    // encrypts the output of to_bytes under a key derived from the passphrase, for storage at rest
+    pub fn to_encrypted_bytes(
+        &self,
+        passphrase: &[u8],
+        rng: &mut dyn Rng,
+    ) -> Result<Vec<u8>, MPECDSAError> {
+        let mut sealed = self.to_bytes();
+        let encrypted = persist::encrypt(&sealed, passphrase, rng);
+        wipe_bytes(&mut sealed);
+        encrypted
+    }
+
+    pub fn from_encrypted_bytes(
+        data: &[u8],
+        passphrase: &[u8],
+    ) -> Result<ThresholdSigner, MPECDSAError> {
+        let mut sealed = persist::decrypt(persist::PersistKind::ThresholdSigner, data, passphrase)?;
+        let res = GenericThresholdSigner::from_bytes(&sealed);
+        wipe_bytes(&mut sealed);
+        res
+    }
+
+    // the extended public key for the group's key, from which child keys can be derived
+    pub fn xpub(&self) -> ExtendedPublicKey {
+        ExtendedPublicKey::new(&self.pk, &self.chaincode)
+    }
+
+    // Signs a digest under a key derived from xpub(). All of the counterparties must sign with the same key.
+    pub fn sign_derived<TR: Read + Send, TW: Write + Send>(
+        &mut self,
+        counterparties: &[usize],
+        key: &ExtendedPublicKey,
+        digest: &[u8; HASH_SIZE],
+        rng: &mut dyn Rng,
+        recv: &mut [Option<TR>],
+        send: &mut [Option<TW>],
+    ) -> Result<Option<Signature>, MPECDSAError> {
+        let key = check_derived_key(&self.pk, key)?;
+        let req = SignRequest {
+            digest,
+            key: Some(&key),
+            tag: None,
+            returnsig: false,
+        };
+        self.sign_with_key(counterparties, &req, rng, recv, send)
+    }
+
+    // the BIP-340 x-only encoding of the group's key, under which sign_schnorr signatures verify
+    pub fn schnorr_pk(&self) -> [u8; 32] {
+        schnorr::xonly_bytes(&self.pk)
+    }
+
+    // Produces a BIP-340 schnorr signature over the same shares used for ecdsa, via a protocol in
+    // the style of FROST. Every party in the quorum receives the signature. FROST's two rounds assume
+    // a broadcast channel; over pairwise channels a party could send different nonce commitments to
+    // different parties, so the commitments are echoed (as in resharing) before any share is sent.
+    pub fn sign_schnorr<TR: Read + Send, TW: Write + Send>(
+        &mut self,
+        counterparties: &[usize],
+        msg: &[u8],
+        rng: &mut dyn Rng,
+        recv: &mut [Option<TR>],
+        send: &mut [Option<TW>],
+    ) -> Result<[u8; schnorr::SCHNORR_SIG_SIZE], MPECDSAError> {
+        self.sign_schnorr_with_tweak(counterparties, None, msg, rng, recv, send)
+    }
+
+    // the BIP-341 output key that commits to the group's key and to an optional script tree
+    pub fn taproot_output_key(
+        &self,
+        merkle_root: Option<&[u8; 32]>,
+    ) -> Result<[u8; 32], MPECDSAError> {
+        let (q, _): (Secp, SecpOrd) =
+            schnorr::taproot_tweak_pubkey(&self.schnorr_pk(), merkle_root)
+                .map_err(|e| MPECDSAError::General(GeneralError::new(e)))?;
+        Ok(schnorr::xonly_bytes(&q))
+    }
+
+    // Produces a schnorr signature for the key path of taproot_output_key(merkle_root)
+    pub fn sign_taproot<TR: Read + Send, TW: Write + Send>(
+        &mut self,
+        counterparties: &[usize],
+        merkle_root: Option<&[u8; 32]>,
+        msg: &[u8],
+        rng: &mut dyn Rng,
+        recv: &mut [Option<TR>],
+        send: &mut [Option<TW>],
+    ) -> Result<[u8; schnorr::SCHNORR_SIG_SIZE], MPECDSAError> {
+        let tweak: SecpOrd = schnorr::taproot_tweak(&self.schnorr_pk(), merkle_root)
+            .map_err(|e| MPECDSAError::General(GeneralError::new(e)))?;
+        self.sign_schnorr_with_tweak(counterparties, Some(&tweak), msg, rng, recv, send)
+    }
+
+    fn sign_schnorr_with_tweak<TR: Read + Send, TW: Write + Send>(
+        &mut self,
+        counterparties: &[usize],
+        tweak: Option<&SecpOrd>,
+        msg: &[u8],
+        rng: &mut dyn Rng,
+        recv: &mut [Option<TR>],
+        send: &mut [Option<TW>],
+    ) -> Result<[u8; schnorr::SCHNORR_SIG_SIZE], MPECDSAError> {
+        let parties = self.quorum(counterparties)?;
+        self.ro.apply_subgroup_list(&parties)?;
+        let prunedplayerindex = parties.iter().position(|&x| x == self.playerindex).unwrap();
+        let mut prunedrecv = prune(&parties, recv);
+        let mut prunedsend = prune(&parties, send);
+
+        // nonce commitments D = d*G and E = e*G, along with our public share Y = p(i)*G. the
+        // broadcast counter rides along, in place of a separate sync_ro flight
+        let di = SecpOrd::rand(rng);
+        let ei = SecpOrd::rand(rng);
+        let mut round1_raw = vec![[0u8; 8 + 3 * POINT_SIZE]; self.threshold];
+        LittleEndian::write_u64(
+            &mut round1_raw[prunedplayerindex][0..8],
+            self.ro.current_broadcast_counter(),
+        );
+        Secp::scalar_table_multi_ct(Secp256k1::gen_table(), &di)
+            .affine()
+            .to_wire_bytes(&mut round1_raw[prunedplayerindex][8..(8 + POINT_SIZE)]);
+        Secp::scalar_table_multi_ct(Secp256k1::gen_table(), &ei)
+            .affine()
+            .to_wire_bytes(
+                &mut round1_raw[prunedplayerindex][(8 + POINT_SIZE)..(8 + 2 * POINT_SIZE)],
+            );
+        Secp::scalar_table_multi_ct(Secp256k1::gen_table(), &self.poly_point)
+            .affine()
+            .to_wire_bytes(
+                &mut round1_raw[prunedplayerindex][(8 + 2 * POINT_SIZE)..(8 + 3 * POINT_SIZE)],
+            );
+
+        // send commitments
+        for ii in 0..self.threshold {
+            if ii != prunedplayerindex {
+                prunedsend[ii]
+                    .as_mut()
+                    .unwrap()
+                    .write_all(&round1_raw[prunedplayerindex])?;
+                prunedsend[ii].as_mut().unwrap().flush()?;
+            }
+        }
+        let mut round1 = Vec::with_capacity(self.threshold);
+        for ii in 0..self.threshold {
+            if ii != prunedplayerindex {
+                prunedrecv[ii]
+                    .as_mut()
+                    .unwrap()
+                    .read_exact(&mut round1_raw[ii])?;
+                self.ro.advance_counterparty_broadcast_counter(
+                    ii,
+                    LittleEndian::read_u64(&round1_raw[ii][0..8]),
+                )?;
+            }
+            // the nonce commitments D and E must not be the point at infinity
+            let points = vec![
+                Secp::from_wire_bytes_finite(
+                    &round1_raw[ii][8..(8 + POINT_SIZE)],
+                    parties[ii],
+                    ProtocolPhase::Sign,
+                )?,
+                Secp::from_wire_bytes_finite(
+                    &round1_raw[ii][(8 + POINT_SIZE)..(8 + 2 * POINT_SIZE)],
+                    parties[ii],
+                    ProtocolPhase::Sign,
+                )?,
+                Secp::from_wire_bytes(
+                    &round1_raw[ii][(8 + 2 * POINT_SIZE)..(8 + 3 * POINT_SIZE)],
+                    parties[ii],
+                    ProtocolPhase::Sign,
+                )?,
+            ];
+            round1.push(points);
+        }
+
+        // make sure that every party saw the same commitments from each party, before any share
+        // is checked against them
+        let coms_hashes = commitment_hashes::<Secp256k1>(&round1);
+        let coms_hashes_raw = coms_hashes.concat();
+        for ii in 0..self.threshold {
+            if ii != prunedplayerindex {
+                prunedsend[ii]
+                    .as_mut()
+                    .unwrap()
+                    .write_all(&coms_hashes_raw)?;
+                prunedsend[ii].as_mut().unwrap().flush()?;
+            }
+        }
+        for ii in 0..self.threshold {
+            if ii != prunedplayerindex {
+                let mut other_coms_hashes_raw = vec![0u8; self.threshold * HASH_SIZE];
+                prunedrecv[ii]
+                    .as_mut()
+                    .unwrap()
+                    .read_exact(&mut other_coms_hashes_raw)?;
+                check_commitment_echo(
+                    &coms_hashes,
+                    &other_coms_hashes_raw,
+                    &parties,
+                    self.playerindex,
+                    parties[ii],
+                    ProtocolPhase::Sign,
+                )?;
+            }
+        }
+
+        // the signing key is Q = P, or Q = lift_x(P) + t*G when tweaked. BIP-340 signs with the
+        // secret key of whichever of Q and -Q has an even y coordinate, so the sum of the
+        // shares is negated as needed, and the tweak is added to the final signature
+        let (q, keyneg, offset) = match tweak {
+            None => (self.pk, !schnorr::has_even_y(&self.pk), SecpOrd::ZERO),
+            Some(t) => {
+                let pneg = !schnorr::has_even_y(&self.pk);
+                let p = if pneg { self.pk.neg() } else { self.pk };
+                let q = Secp::op(&p, &Secp::scalar_table_multi(Secp256k1::gen_table(), t)).affine();
+                if q.is_infinity() {
+                    return Err(MPECDSAError::General(GeneralError::new(
+                        "Taproot output key is the point at infinity",
+                    )));
+                }
+                let qneg = !schnorr::has_even_y(&q);
+                (q, pneg != qneg, if qneg { t.neg() } else { *t })
+            }
+        };
+        let px = schnorr::xonly_bytes(&q);
+        let commitments: Vec<u8> = round1_raw
+            .iter()
+            .flat_map(|x| x[8..(8 + 2 * POINT_SIZE)].iter().cloned())
+            .collect();
+
+        // the public shares must interpolate to the group's key
+        let coefs: Vec<SecpOrd> = (0..self.threshold)
+            .map(|ii| lagrange_coefficient::<Secp256k1>(&parties, ii))
+            .collect();
+        let mut pkcheck = Secp::INF;
+        for ii in 0..self.threshold {
+            pkcheck = Secp::op(&pkcheck, &round1[ii][2].scalar(&coefs[ii]));
+        }
+        if pkcheck.affine() != self.pk {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Public key shares do not match the group's public key",
+            )));
+        }
+
+        // each party's binding factor commits its nonce to the message and to every other commitment
+        let mut r = Secp::INF;
+        let mut rjs = Vec::with_capacity(self.threshold);
+        let mut rhoi = SecpOrd::ZERO;
+        let mut binding_raw = vec![0u8; RO_TAG_SIZE];
+        binding_raw.extend_from_slice(&px);
+        binding_raw.extend_from_slice(&commitments);
+        binding_raw.extend_from_slice(msg);
+        let mut hashout = [0u8; HASH_SIZE];
+        for ii in 0..self.threshold {
+            let tag = if ii == prunedplayerindex {
+                self.ro.next_broadcast_tag()
+            } else {
+                self.ro.next_counterparty_broadcast_tag(ii)?
+            };
+            binding_raw[0..RO_TAG_SIZE].copy_from_slice(&tag);
+            hash(&mut hashout, &binding_raw);
+            let rho = SecpOrd::from_bytes(&hashout);
+            if ii == prunedplayerindex {
+                rhoi = rho;
+            }
+            let rj = Secp::op(&round1[ii][0], &round1[ii][1].scalar(&rho));
+            r = Secp::op(&r, &rj);
+            rjs.push(rj);
+        }
+        let r = r.affine();
+        if r.is_infinity() {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Schnorr instance point is the point at infinity",
+            )));
+        }
+
+        // BIP-340 requires an even y coordinate for R; where it is odd, the nonces are negated
+        let rx = schnorr::xonly_bytes(&r);
+        let c: SecpOrd = schnorr::schnorr_challenge(&rx, &px, msg);
+        let ki = di.add(&ei.mul(&rhoi));
+        let ki = if schnorr::has_even_y(&r) {
+            ki
+        } else {
+            ki.neg()
+        };
+        let cpk = if keyneg { c.neg() } else { c };
+
+        let zi = ki.add(&cpk.mul(&coefs[prunedplayerindex].mul(&self.poly_point)));
+        let mut zi_raw = [0u8; SecpOrd::NBYTES];
+        zi.to_bytes(&mut zi_raw);
+        for ii in 0..self.threshold {
+            if ii != prunedplayerindex {
+                prunedsend[ii].as_mut().unwrap().write_all(&zi_raw)?;
+                prunedsend[ii].as_mut().unwrap().flush()?;
+            }
+        }
+
+        // verify each share against its party's nonce commitment and public share
+        let mut z = zi;
+        for ii in 0..self.threshold {
+            if ii != prunedplayerindex {
+                prunedrecv[ii].as_mut().unwrap().read_exact(&mut zi_raw)?;
+                let zj = SecpOrd::from_bytes(&zi_raw);
+                let rj = if schnorr::has_even_y(&r) {
+                    rjs[ii]
+                } else {
+                    rjs[ii].neg()
+                };
+                let expected = Secp::op(&rj, &round1[ii][2].scalar(&cpk.mul(&coefs[ii]))).affine();
+                if Secp::scalar_table_multi(Secp256k1::gen_table(), &zj).affine() != expected {
+                    return Err(MPECDSAError::Blame(BlameError::new(
+                        parties[ii],
+                        ProtocolPhase::Sign,
+                        "Schnorr signature share is invalid",
+                    )));
+                }
+                z = z.add(&zj);
+            }
+        }
+
+        // the tweak is public, so it is applied once, after the shares are combined
+        let z = z.add(&c.mul(&offset));
+        let mut sig = [0u8; schnorr::SCHNORR_SIG_SIZE];
+        sig[0..32].copy_from_slice(&rx);
+        z.to_bytes(&mut sig[32..64]);
+        if !schnorr::schnorr_verify::<_, _, Secp>(msg, &sig, &px) {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Schnorr signature failed to verify",
+            )));
+        }
+        Ok(sig)
+    }
+}
+
+// evaluates a polynomial in the exponent, given commitments to its coefficients, at the given player's point
+fn feldman_eval<C: Curve>(coefficients_com: &[C::Point], playerindex: usize) -> C::Point {
+    let x = C::Scalar::from_native((playerindex + 1) as u64);
+    let mut result = C::Point::INF;
+    for coefficient_com in coefficients_com.iter().rev() {
+        result = C::Point::op(&result.scalar_table(&x), coefficient_com);
+    }
+    result.affine()
+}
+
+// hashes each dealer's commitments to the coefficients of its polynomial
+fn commitment_hashes<C: Curve>(coefficients_com: &[Vec<C::Point>]) -> Vec<[u8; HASH_SIZE]> {
+    coefficients_com
+        .iter()
+        .map(|dealer_com| {
+            let mut coms_raw = vec![0u8; dealer_com.len() * POINT_SIZE];
+            for (jj, coefficient_com) in dealer_com.iter().enumerate() {
+                coefficient_com
+                    .to_wire_bytes(&mut coms_raw[(jj * POINT_SIZE)..((jj + 1) * POINT_SIZE)]);
+            }
+            let mut coms_hash = [0u8; HASH_SIZE];
+            hash(&mut coms_hash, &coms_raw);
+            coms_hash
+        })
+        .collect()
+}
+
+// compares the commitment hashes echoed by another party with our own, one per dealer. a dealer
+// whose hashes differ sent different commitments to different parties, unless the dealer is us,
+// in which case the echo itself is false
+fn check_commitment_echo(
+    coms_hashes: &[[u8; HASH_SIZE]],
+    echoed_raw: &[u8],
+    dealers: &[usize],
+    playerindex: usize,
+    echoer: usize,
+    phase: ProtocolPhase,
+) -> Result<(), MPECDSAError> {
+    for (dd, &dealer) in dealers.iter().enumerate() {
+        if echoed_raw[(dd * HASH_SIZE)..((dd + 1) * HASH_SIZE)] != coms_hashes[dd] {
+            return Err(MPECDSAError::Blame(BlameError::new(
+                if dealer == playerindex {
+                    echoer
+                } else {
+                    dealer
+                },
+                phase,
+                "sent inconsistent commitments to different parties",
+            )));
+        }
+    }
+    Ok(())
+}
+
+// selects the streams (or other per-party resources) of the given parties, so that they can be
+// addressed by position within the quorum
+fn prune<'a, T>(parties: &[usize], items: &'a mut [T]) -> Vec<&'a mut T> {
+    items
+        .iter_mut()
+        .enumerate()
+        .filter_map(|(index, val)| {
+            if parties.contains(&index) {
+                Some(val)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+// the lagrange coefficient that weights the share of parties[index] when interpolating the
+// shared polynomial at zero from the shares of all the given parties
+fn lagrange_coefficient<C: Curve>(parties: &[usize], index: usize) -> C::Scalar {
+    let mut coefnum = C::Scalar::ONE;
+    let mut coefdenom = C::Scalar::ONE;
+    for (kk, &party) in parties.iter().enumerate() {
+        if kk != index {
+            coefnum = coefnum.mul(&C::Scalar::from_native((party + 1) as u64));
+            coefdenom = coefdenom.mul(
+                &C::Scalar::from_native((party + 1) as u64)
+                    .sub(&C::Scalar::from_native((parties[index] + 1) as u64)),
+            );
+        }
+    }
+    coefnum.mul(&coefdenom.inv())
+}
+
+// the schnorr challenge that binds a refresh package to its signing session, epoch, and tag
+fn refresh_challenge<C: Curve>(
+    r: &C::Point,
+    schnorr_nonce: &C::Point,
+    coin: &C::Scalar,
+    epoch: usize,
+    tag: &[u8],
+) -> C::Scalar {
+    let mut schnorr_e_in = vec![0u8; 2 * POINT_SIZE + SCALAR_SIZE + 8 + tag.len()];
+    r.to_wire_bytes(&mut schnorr_e_in[0..POINT_SIZE]);
+    schnorr_nonce.to_wire_bytes(&mut schnorr_e_in[POINT_SIZE..2 * POINT_SIZE]);
+    coin.to_bytes(&mut schnorr_e_in[2 * POINT_SIZE..2 * POINT_SIZE + SCALAR_SIZE]);
+    LittleEndian::write_u64(
+        &mut schnorr_e_in[2 * POINT_SIZE + SCALAR_SIZE..2 * POINT_SIZE + SCALAR_SIZE + 8],
+        epoch as u64,
+    );
+    schnorr_e_in[2 * POINT_SIZE + SCALAR_SIZE + 8..].copy_from_slice(tag);
+
+    let mut schnorr_e = [0u8; HASH_SIZE];
+    hash(&mut schnorr_e, &schnorr_e_in);
+    C::Scalar::from_bytes(&schnorr_e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::channelstream::*;
+    use super::*;
+    use std::thread;
+    use test::Bencher;
+
+    #[test]
+    fn test_mpecdsa_2psign() {
+        let msg = "The Quick Brown Fox Jumped Over The Lazy Dog".as_bytes();
+        let mut rng = rand::thread_rng();
+        let ska = SecpOrd::rand(&mut rng);
+        let skb = SecpOrd::rand(&mut rng);
+
+        let (mut writ_a, mut read_b) = channelstream::new_channelstream();
+        let (mut writ_b, mut read_a) = channelstream::new_channelstream();
+
+        let thandle = thread::spawn(move || {
+            let mut rng = rand::thread_rng();
+            let bob = Bob2P::new(&skb, &mut rng, &mut read_b, &mut writ_b);
+            if bob.is_err() {
+                return Err(bob.err().unwrap());
+            }
+            let bob = bob.unwrap();
+
+            let mut results = Vec::with_capacity(10);
+            for _ in 0..10 {
+                results.push(bob.sign(&msg, &mut rng, &mut read_b, &mut writ_b));
+            }
+
+            Ok(results)
+        });
+
+        let alice = Alice2P::new(&ska, &mut rng, &mut read_a, &mut writ_a);
+        assert!(alice.is_ok());
+        let alice = alice.unwrap();
+        let mut aliceresults = Vec::with_capacity(10);
+        for _ in 0..10 {
+            aliceresults.push(alice.sign(&msg, &mut rng, &mut read_a, &mut writ_a));
+        }
 
-#[cfg(test)]
-mod tests {
-    use super::channelstream::*;
-    use super::*;
-    use std::thread;
-    use test::Bencher;
+        let bobresults = thandle.join().unwrap();
+        assert!(bobresults.is_ok());
+        let bobresults = bobresults.unwrap();
+        for ii in 0..10 {
+            assert!(aliceresults[ii].is_ok());
+            assert!(bobresults[ii].is_ok());
+        }
+    }
 
     #[test]
-    fn test_mpecdsa_2psign() {
+    fn test_mpecdsa_2psign_return() {
         let msg = "The Quick Brown Fox Jumped Over The Lazy Dog".as_bytes();
         let mut rng = rand::thread_rng();
         let ska = SecpOrd::rand(&mut rng);
@@ -1932,7 +4497,7 @@ mod tests {
 
             let mut results = Vec::with_capacity(10);
             for _ in 0..10 {
-                results.push(bob.sign(&msg, &mut rng, &mut read_b, &mut writ_b));
+                results.push(bob.sign_with_return(&msg, &mut rng, &mut read_b, &mut writ_b));
             }
 
             Ok(results)
@@ -1943,7 +4508,7 @@ mod tests {
         let alice = alice.unwrap();
         let mut aliceresults = Vec::with_capacity(10);
         for _ in 0..10 {
-            aliceresults.push(alice.sign(&msg, &mut rng, &mut read_a, &mut writ_a));
+            aliceresults.push(alice.sign_with_return(&msg, &mut rng, &mut read_a, &mut writ_a));
         }
 
         let bobresults = thandle.join().unwrap();
@@ -1952,6 +4517,207 @@ mod tests {
         for ii in 0..10 {
             assert!(aliceresults[ii].is_ok());
             assert!(bobresults[ii].is_ok());
+            assert_eq!(
+                aliceresults[ii].as_ref().unwrap(),
+                bobresults[ii].as_ref().unwrap()
+            );
+            // alice normalizes independently, so this also checks her recovery id
+            let sig = aliceresults[ii].as_ref().unwrap();
+            assert!(sig.is_low_s());
+            assert_eq!(ecdsa::recover_public_key(&msg, sig), Some(alice.pk));
+        }
+    }
+
+    #[test]
+    fn test_mpecdsa_2psign_prehashed() {
+        // an ethereum-style keccak-256 digest, which sign would otherwise hash again with sha-256
+        let mut digest = [0u8; HASH_SIZE];
+        let mut hasher = crypto::sha3::Sha3::keccak256();
+        hasher.input("The Quick Brown Fox Jumped Over The Lazy Dog".as_bytes());
+        hasher.result(&mut digest);
+        let mut rng = rand::thread_rng();
+        let ska = SecpOrd::rand(&mut rng);
+        let skb = SecpOrd::rand(&mut rng);
+
+        let (mut writ_a, mut read_b) = channelstream::new_channelstream();
+        let (mut writ_b, mut read_a) = channelstream::new_channelstream();
+
+        let thandle = thread::spawn(move || {
+            let mut rng = rand::thread_rng();
+            let bob = Bob2P::new(&skb, &mut rng, &mut read_b, &mut writ_b).unwrap();
+            bob.sign_prehashed(&digest, &mut rng, &mut read_b, &mut writ_b)
+                .unwrap()
+        });
+
+        let alice = Alice2P::new(&ska, &mut rng, &mut read_a, &mut writ_a).unwrap();
+        assert!(alice
+            .sign_prehashed(&digest, &mut rng, &mut read_a, &mut writ_a)
+            .is_ok());
+        let sig = thandle.join().unwrap();
+        assert!(ecdsa::ecdsa_verify_prehashed(
+            &digest,
+            (&sig.r, &sig.s),
+            &alice.pk
+        ));
+        assert_eq!(
+            ecdsa::recover_public_key_prehashed(&digest, &sig),
+            Some(alice.pk)
+        );
+    }
+
+    #[test]
+    fn test_mpecdsa_2psign_derived() {
+        let mut digest = [0u8; HASH_SIZE];
+        ecdsa_hash(
+            &mut digest,
+            "The Quick Brown Fox Jumped Over The Lazy Dog".as_bytes(),
+        );
+        let mut rng = rand::thread_rng();
+        let ska = SecpOrd::rand(&mut rng);
+        let skb = SecpOrd::rand(&mut rng);
+
+        let (mut writ_a, mut read_b) = channelstream::new_channelstream();
+        let (mut writ_b, mut read_a) = channelstream::new_channelstream();
+
+        let thandle = thread::spawn(move || {
+            let mut rng = rand::thread_rng();
+            let bob = Bob2P::new(&skb, &mut rng, &mut read_b, &mut writ_b).unwrap();
+            let key = bob.xpub().derive_path(&[0, 5]).unwrap();
+            let sig = bob
+                .sign_derived(&key, &digest, &mut rng, &mut read_b, &mut writ_b)
+                .unwrap();
+            (bob.xpub(), sig)
+        });
+
+        let alice = Alice2P::new(&ska, &mut rng, &mut read_a, &mut writ_a).unwrap();
+        let key = alice.xpub().derive_path(&[0, 5]).unwrap();
+        assert!(alice
+            .sign_derived(&key, &digest, &mut rng, &mut read_a, &mut writ_a)
+            .is_ok());
+        let (xpub, sig) = thandle.join().unwrap();
+
+        // both parties agree on the chain code, and sign under the child key
+        assert_eq!(xpub, alice.xpub());
+        assert!(key.pk() != alice.pk);
+        assert!(ecdsa::ecdsa_verify_prehashed(
+            &digest,
+            (&sig.r, &sig.s),
+            &key.pk()
+        ));
+        assert_eq!(
+            ecdsa::recover_public_key_prehashed(&digest, &sig),
+            Some(key.pk())
+        );
+
+        // keys that do not descend from the joint key are rejected
+        let foreign = ExtendedPublicKey::new(&Secp::gen(), alice.xpub().chaincode());
+        assert!(alice
+            .sign_derived(&foreign, &digest, &mut rng, &mut read_a, &mut writ_a)
+            .is_err());
+    }
+
+    #[test]
+    fn test_mpecdsa_2ppresign() {
+        let msg1 = "The Quick Brown Fox Jumped Over The Lazy Dog".as_bytes();
+        let msg2 = "etaoin shrdlu".as_bytes();
+        let mut rng = rand::thread_rng();
+        let ska = SecpOrd::rand(&mut rng);
+        let skb = SecpOrd::rand(&mut rng);
+
+        let (mut writ_a, mut read_b) = channelstream::new_channelstream();
+        let (mut writ_b, mut read_a) = channelstream::new_channelstream();
+
+        let thandle = thread::spawn(move || {
+            let mut rng = rand::thread_rng();
+            let mut bob = Bob2P::new(&skb, &mut rng, &mut read_b, &mut writ_b).unwrap();
+            let presig0 = bob.presign(&mut rng, &mut read_b, &mut writ_b).unwrap();
+            let presig1 = bob.presign(&mut rng, &mut read_b, &mut writ_b).unwrap();
+
+            // presignatures may be used out of order, and interleaved with ordinary signing
+            let sig1 = bob.sign_presigned(presig1, &msg1, &mut read_b).unwrap();
+            let sig = bob.sign(&msg2, &mut rng, &mut read_b, &mut writ_b).unwrap();
+            let sig0 = bob
+                .sign_presigned_with_return(presig0, &msg2, &mut read_b, &mut writ_b)
+                .unwrap();
+            (bob.pk, sig1, sig, sig0)
+        });
+
+        let mut alice = Alice2P::new(&ska, &mut rng, &mut read_a, &mut writ_a).unwrap();
+        let presig0 = alice.presign(&mut rng, &mut read_a, &mut writ_a).unwrap();
+        let presig1 = alice.presign(&mut rng, &mut read_a, &mut writ_a).unwrap();
+        assert!(alice.sign_presigned(presig1, &msg1, &mut writ_a).is_ok());
+        assert!(alice
+            .sign(&msg2, &mut rng, &mut read_a, &mut writ_a)
+            .is_ok());
+        let alicesig0 = alice
+            .sign_presigned_with_return(presig0, &msg2, &mut read_a, &mut writ_a)
+            .unwrap();
+
+        let (pk, sig1, sig, sig0) = thandle.join().unwrap();
+        assert_eq!(alicesig0, sig0);
+        assert_eq!(pk, alice.pk);
+        assert!(ecdsa::ecdsa_verify(&msg1, (&sig1.r, &sig1.s), &pk));
+        assert!(ecdsa::ecdsa_verify(&msg2, (&sig.r, &sig.s), &pk));
+        assert!(ecdsa::ecdsa_verify(&msg2, (&sig0.r, &sig0.s), &pk));
+        assert_eq!(ecdsa::recover_public_key(&msg1, &sig1), Some(pk));
+        assert_eq!(ecdsa::recover_public_key(&msg2, &sig), Some(pk));
+        assert_eq!(ecdsa::recover_public_key(&msg2, &sig0), Some(pk));
+        assert!(sig1.is_low_s() && sig.is_low_s() && sig0.is_low_s());
+        assert!(alice.presigs.is_empty());
+    }
+
+    #[test]
+    fn test_mpecdsa_2ppersist() {
+        let msg = "The Quick Brown Fox Jumped Over The Lazy Dog".as_bytes();
+        let mut rng = rand::thread_rng();
+        let ska = SecpOrd::rand(&mut rng);
+        let skb = SecpOrd::rand(&mut rng);
+
+        let (mut writ_a, mut read_b) = channelstream::new_channelstream();
+        let (mut writ_b, mut read_a) = channelstream::new_channelstream();
+
+        let thandle = thread::spawn(move || {
+            let mut rng = rand::thread_rng();
+            let bob = Bob2P::new(&skb, &mut rng, &mut read_b, &mut writ_b).unwrap();
+            bob.sign(&msg, &mut rng, &mut read_b, &mut writ_b).unwrap();
+            let serialized = bob.to_bytes();
+            drop(bob);
+
+            assert!(Alice2P::from_bytes(&serialized).is_err());
+            let encrypted = Bob2P::from_bytes(&serialized)
+                .unwrap()
+                .to_encrypted_bytes(b"bob", &mut rng)
+                .unwrap();
+            assert!(Bob2P::from_encrypted_bytes(&encrypted, b"alice").is_err());
+            assert!(Alice2P::from_encrypted_bytes(&encrypted, b"bob").is_err());
+            // a header demanding too much memory is refused before the kdf runs
+            let mut expensive = encrypted.clone();
+            expensive[9] = 20;
+            assert!(Bob2P::from_encrypted_bytes(&expensive, b"bob").is_err());
+            let bob = Bob2P::from_encrypted_bytes(&encrypted, b"bob").unwrap();
+            assert!(bob.to_bytes() == serialized);
+            let mut results = Vec::with_capacity(3);
+            for _ in 0..3 {
+                results.push(bob.sign(&msg, &mut rng, &mut read_b, &mut writ_b));
+            }
+            results
+        });
+
+        let alice = Alice2P::new(&ska, &mut rng, &mut read_a, &mut writ_a).unwrap();
+        alice
+            .sign(&msg, &mut rng, &mut read_a, &mut writ_a)
+            .unwrap();
+        let serialized = alice.to_bytes();
+        drop(alice);
+
+        assert!(Bob2P::from_bytes(&serialized).is_err());
+        let alice = Alice2P::from_bytes(&serialized).unwrap();
+        for _ in 0..3 {
+            assert!(alice.sign(&msg, &mut rng, &mut read_a, &mut writ_a).is_ok());
+        }
+
+        for result in thandle.join().unwrap() {
+            assert!(result.is_ok());
         }
     }
 
@@ -2039,39 +4805,368 @@ mod tests {
             (result1, result2)
         });
 
-        let mut s2 = sendvec.remove(0);
-        let mut r2 = recvvec.remove(0);
+        let mut s2 = sendvec.remove(0);
+        let mut r2 = recvvec.remove(0);
+
+        let thandlec = thread::spawn(move || {
+            let mut rng = rand::thread_rng();
+            let mut charlie =
+                ThresholdSigner::new(2, 2, &mut rng, &mut r2[..], &mut s2[..]).unwrap();
+            let result1 = charlie.sign(
+                &[0],
+                &"etaoin shrdlu".as_bytes(),
+                &mut rng,
+                &mut r2[..],
+                &mut s2[..],
+            );
+            let result2 = charlie.sign(
+                &[1],
+                &"Lorem ipsum dolor sit amet".as_bytes(),
+                &mut rng,
+                &mut r2[..],
+                &mut s2[..],
+            );
+            (result1, result2)
+        });
+
+        let alice = thandlea.join().unwrap();
+        assert!(alice.0.is_ok());
+        assert!(alice.1.is_ok());
+        let bob = thandleb.join().unwrap();
+        assert!(bob.0.is_ok());
+        assert!(bob.1.is_ok());
+        let charlie = thandlec.join().unwrap();
+        assert!(charlie.0.is_ok());
+        assert!(charlie.1.is_ok());
+    }
+
+    #[test]
+    fn test_mpecdsa_3p2tsign_return() {
+        let (mut sendvec, mut recvvec) = spawn_n2_channelstreams(3);
+
+        let mut s0 = sendvec.remove(0);
+        let mut r0 = recvvec.remove(0);
+
+        let thandlea = thread::spawn(move || {
+            let mut rng = rand::thread_rng();
+            let mut alice = ThresholdSigner::new(0, 2, &mut rng, &mut r0[..], &mut s0[..]).unwrap();
+            let result1 = alice.sign_with_return(
+                &[1],
+                &"The Quick Brown Fox Jumped Over The Lazy Dog".as_bytes(),
+                &mut rng,
+                &mut r0[..],
+                &mut s0[..],
+            );
+            let result2 = alice.sign_with_return(
+                &[2],
+                &"etaoin shrdlu".as_bytes(),
+                &mut rng,
+                &mut r0[..],
+                &mut s0[..],
+            );
+            (result1, result2)
+        });
+
+        let mut s1 = sendvec.remove(0);
+        let mut r1 = recvvec.remove(0);
+
+        let thandleb = thread::spawn(move || {
+            let mut rng = rand::thread_rng();
+            let mut bob = ThresholdSigner::new(1, 2, &mut rng, &mut r1[..], &mut s1[..]).unwrap();
+            let result1 = bob.sign_with_return(
+                &[0],
+                &"The Quick Brown Fox Jumped Over The Lazy Dog".as_bytes(),
+                &mut rng,
+                &mut r1[..],
+                &mut s1[..],
+            );
+            let result2 = bob.sign_with_return(
+                &[2],
+                &"Lorem ipsum dolor sit amet".as_bytes(),
+                &mut rng,
+                &mut r1[..],
+                &mut s1[..],
+            );
+            (result1, result2)
+        });
+
+        let mut s2 = sendvec.remove(0);
+        let mut r2 = recvvec.remove(0);
+
+        let thandlec = thread::spawn(move || {
+            let mut rng = rand::thread_rng();
+            let mut charlie =
+                ThresholdSigner::new(2, 2, &mut rng, &mut r2[..], &mut s2[..]).unwrap();
+            let result1 = charlie.sign_with_return(
+                &[0],
+                &"etaoin shrdlu".as_bytes(),
+                &mut rng,
+                &mut r2[..],
+                &mut s2[..],
+            );
+            let result2 = charlie.sign_with_return(
+                &[1],
+                &"Lorem ipsum dolor sit amet".as_bytes(),
+                &mut rng,
+                &mut r2[..],
+                &mut s2[..],
+            );
+            (result1, result2)
+        });
+
+        let alice = thandlea.join().unwrap();
+        assert!(alice.0.is_ok());
+        assert!(alice.1.is_ok());
+        let bob = thandleb.join().unwrap();
+        assert!(bob.0.is_ok());
+        assert!(bob.1.is_ok());
+        let charlie = thandlec.join().unwrap();
+        assert!(charlie.0.is_ok());
+        assert!(charlie.1.is_ok());
+
+        // the lower-indexed party receives the signature too
+        assert_eq!(alice.0.unwrap(), bob.0.unwrap());
+        assert_eq!(alice.1.unwrap(), charlie.0.unwrap());
+        assert_eq!(bob.1.unwrap(), charlie.1.unwrap());
+    }
+
+    #[test]
+    fn test_mpecdsa_3p2tsign_derived() {
+        let mut digest = [0u8; HASH_SIZE];
+        ecdsa_hash(
+            &mut digest,
+            "The Quick Brown Fox Jumped Over The Lazy Dog".as_bytes(),
+        );
+        let (sendvec, recvvec) = spawn_n2_channelstreams(3);
+
+        let mut thandles = sendvec
+            .into_iter()
+            .zip(recvvec.into_iter())
+            .enumerate()
+            .map(|(ii, (mut si, mut ri))| {
+                thread::spawn(move || {
+                    let mut rng = rand::thread_rng();
+                    let mut signer =
+                        ThresholdSigner::new(ii, 2, &mut rng, &mut ri[..], &mut si[..]).unwrap();
+                    let key = signer.xpub().derive_child(3).unwrap();
+                    let result = if ii < 2 {
+                        signer
+                            .sign_derived(
+                                &[1 - ii],
+                                &key,
+                                &digest,
+                                &mut rng,
+                                &mut ri[..],
+                                &mut si[..],
+                            )
+                            .unwrap()
+                    } else {
+                        None
+                    };
+                    (signer.xpub(), key, result)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let (xpub0, key, sig0) = thandles.remove(0).join().unwrap();
+        let (xpub1, _, sig1) = thandles.remove(0).join().unwrap();
+        let (xpub2, _, _) = thandles.remove(0).join().unwrap();
+
+        // every party holds the same extended public key
+        assert_eq!(xpub0, xpub1);
+        assert_eq!(xpub0, xpub2);
+        // without the return flight, only the higher-indexed party learns the signature
+        assert!(sig0.is_none());
+        let sig = sig1.unwrap();
+        assert!(ecdsa::ecdsa_verify_prehashed(
+            &digest,
+            (&sig.r, &sig.s),
+            &key.pk()
+        ));
+    }
+
+    #[test]
+    fn test_mpecdsa_5p3tsign_schnorr() {
+        let msg = "The Quick Brown Fox Jumped Over The Lazy Dog".as_bytes();
+        let (sendvec, recvvec) = spawn_n2_channelstreams(5);
+
+        let mut thandles = sendvec
+            .into_iter()
+            .zip(recvvec.into_iter())
+            .enumerate()
+            .map(|(ii, (mut si, mut ri))| {
+                thread::spawn(move || {
+                    let mut rng = rand::thread_rng();
+                    let mut signer =
+                        ThresholdSigner::new(ii, 3, &mut rng, &mut ri[..], &mut si[..]).unwrap();
+                    let quorum = [0, 2, 4];
+                    let result = if quorum.contains(&ii) {
+                        let counterparties: Vec<usize> =
+                            quorum.iter().cloned().filter(|&x| x != ii).collect();
+                        let sig = signer
+                            .sign_schnorr(&counterparties, msg, &mut rng, &mut ri[..], &mut si[..])
+                            .unwrap();
+                        // ecdsa signing proceeds as usual afterward
+                        signer
+                            .sign(&counterparties, msg, &mut rng, &mut ri[..], &mut si[..])
+                            .unwrap();
+                        Some(sig)
+                    } else {
+                        None
+                    };
+                    (signer.schnorr_pk(), result)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let results: Vec<_> = thandles.drain(..).map(|h| h.join().unwrap()).collect();
+        let pk = results[0].0;
+        let sig = results[0].1.unwrap();
+        for (ii, &(pki, sigi)) in results.iter().enumerate() {
+            assert_eq!(pki, pk);
+            if ii % 2 == 0 {
+                assert_eq!(sigi.unwrap()[..], sig[..]);
+            }
+        }
+        assert!(schnorr::schnorr_verify::<_, _, Secp>(msg, &sig, &pk));
+        assert!(!schnorr::schnorr_verify::<_, _, Secp>(
+            "etaoin shrdlu".as_bytes(),
+            &sig,
+            &pk
+        ));
+    }
+
+    #[test]
+    fn test_mpecdsa_3p2tsign_taproot() {
+        let msg = "The Quick Brown Fox Jumped Over The Lazy Dog".as_bytes();
+        let root = [0x5au8; 32];
+        let (sendvec, recvvec) = spawn_n2_channelstreams(3);
+
+        let mut thandles = sendvec
+            .into_iter()
+            .zip(recvvec.into_iter())
+            .enumerate()
+            .map(|(ii, (mut si, mut ri))| {
+                thread::spawn(move || {
+                    let mut rng = rand::thread_rng();
+                    let mut signer =
+                        ThresholdSigner::new(ii, 2, &mut rng, &mut ri[..], &mut si[..]).unwrap();
+                    let result = if ii > 0 {
+                        let counterparties = [3 - ii];
+                        let sig0 = signer
+                            .sign_taproot(
+                                &counterparties,
+                                None,
+                                msg,
+                                &mut rng,
+                                &mut ri[..],
+                                &mut si[..],
+                            )
+                            .unwrap();
+                        let sig1 = signer
+                            .sign_taproot(
+                                &counterparties,
+                                Some(&root),
+                                msg,
+                                &mut rng,
+                                &mut ri[..],
+                                &mut si[..],
+                            )
+                            .unwrap();
+                        Some((sig0, sig1))
+                    } else {
+                        None
+                    };
+                    (
+                        signer.schnorr_pk(),
+                        signer.taproot_output_key(None).unwrap(),
+                        signer.taproot_output_key(Some(&root)).unwrap(),
+                        result,
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let (pk, q0, q1, _) = thandles.remove(0).join().unwrap();
+        let (_, _, _, sigs1) = thandles.remove(0).join().unwrap();
+        let (_, _, _, sigs2) = thandles.remove(0).join().unwrap();
+        let (sig0, sig1) = sigs1.unwrap();
+        assert_eq!(sigs2.unwrap().0[..], sig0[..]);
+
+        // each signature verifies only under the output key it was made for
+        assert!(schnorr::schnorr_verify::<_, _, Secp>(msg, &sig0, &q0));
+        assert!(schnorr::schnorr_verify::<_, _, Secp>(msg, &sig1, &q1));
+        assert!(!schnorr::schnorr_verify::<_, _, Secp>(msg, &sig0, &pk));
+        assert!(!schnorr::schnorr_verify::<_, _, Secp>(msg, &sig1, &q0));
+    }
 
-        let thandlec = thread::spawn(move || {
-            let mut rng = rand::thread_rng();
-            let mut charlie =
-                ThresholdSigner::new(2, 2, &mut rng, &mut r2[..], &mut s2[..]).unwrap();
-            let result1 = charlie.sign(
-                &[0],
-                &"etaoin shrdlu".as_bytes(),
-                &mut rng,
-                &mut r2[..],
-                &mut s2[..],
-            );
-            let result2 = charlie.sign(
-                &[1],
-                &"Lorem ipsum dolor sit amet".as_bytes(),
-                &mut rng,
-                &mut r2[..],
-                &mut s2[..],
-            );
-            (result1, result2)
-        });
+    #[test]
+    fn test_mpecdsa_3p2tpersist() {
+        let (sendvec, recvvec) = spawn_n2_channelstreams(3);
 
-        let alice = thandlea.join().unwrap();
-        assert!(alice.0.is_ok());
-        assert!(alice.1.is_ok());
-        let bob = thandleb.join().unwrap();
-        assert!(bob.0.is_ok());
-        assert!(bob.1.is_ok());
-        let charlie = thandlec.join().unwrap();
-        assert!(charlie.0.is_ok());
-        assert!(charlie.1.is_ok());
+        let thandles = sendvec
+            .into_iter()
+            .zip(recvvec.into_iter())
+            .enumerate()
+            .map(|(ii, (si, ri))| {
+                thread::spawn(move || {
+                    let mut rng = rand::thread_rng();
+                    let mut sin = si;
+                    let mut rin = ri;
+                    let signer =
+                        ThresholdSigner::new(ii, 2, &mut rng, &mut rin[..], &mut sin[..]).unwrap();
+                    let serialized = signer.to_bytes();
+                    drop(signer);
+
+                    // corrupted or truncated state must be rejected
+                    let mut corrupted = serialized.clone();
+                    corrupted[serialized.len() / 2] ^= 1;
+                    assert!(ThresholdSigner::from_bytes(&corrupted).is_err());
+                    assert!(ThresholdSigner::from_bytes(&serialized[1..]).is_err());
+
+                    let encrypted = ThresholdSigner::from_bytes(&serialized)
+                        .unwrap()
+                        .to_encrypted_bytes(b"passphrase", &mut rng)
+                        .unwrap();
+                    let mut corrupted = encrypted.clone();
+                    corrupted[encrypted.len() / 2] ^= 1;
+                    assert!(
+                        ThresholdSigner::from_encrypted_bytes(&corrupted, b"passphrase").is_err()
+                    );
+                    assert!(
+                        ThresholdSigner::from_encrypted_bytes(&encrypted, b"passphrasf").is_err()
+                    );
+
+                    let mut signer =
+                        ThresholdSigner::from_encrypted_bytes(&encrypted, b"passphrase").unwrap();
+                    assert!(signer.to_bytes() == serialized);
+                    let pk = signer.pk;
+                    let result = if ii < 2 {
+                        signer.sign(
+                            &[1 - ii],
+                            &"etaoin shrdlu".as_bytes(),
+                            &mut rng,
+                            &mut rin[..],
+                            &mut sin[..],
+                        )
+                    } else {
+                        Ok(None)
+                    };
+                    (pk, result)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut firstpk = Secp::INF;
+        for handle in thandles {
+            let (pk, result) = handle.join().unwrap();
+            assert!(result.is_ok());
+            if firstpk == Secp::INF {
+                firstpk = pk;
+            } else {
+                assert_eq!(pk, firstpk);
+            }
+        }
     }
 
     #[test]
@@ -2261,11 +5356,17 @@ mod tests {
             (Ok((_, cr0)), Ok((_, cr1)), mut charlie),
         ) = (aliceout, bobout, charlieout)
         {
-            for refpack in [ar0, br0, cr0, ar1, br1, cr1].iter() {
+            // both parties to a session output the same package, which may be applied only once
+            for refpack in [ar0, ar1, br1].iter() {
                 assert!(alice.apply_refresh(&refpack).is_ok());
                 assert!(bob.apply_refresh(&refpack).is_ok());
                 assert!(charlie.apply_refresh(&refpack).is_ok());
             }
+            for refpack in [br0, cr0, cr1].iter() {
+                assert!(alice.apply_refresh(&refpack).is_err());
+                assert!(bob.apply_refresh(&refpack).is_err());
+                assert!(charlie.apply_refresh(&refpack).is_err());
+            }
 
             let (mut sendvec, mut recvvec) = spawn_n2_channelstreams(3);
 
@@ -2317,46 +5418,393 @@ mod tests {
                 (result1, result2)
             });
 
-            let mut s2 = sendvec.remove(0);
-            let mut r2 = recvvec.remove(0);
+            let mut s2 = sendvec.remove(0);
+            let mut r2 = recvvec.remove(0);
+
+            let thandlec = thread::spawn(move || {
+                let mut rng = rand::thread_rng();
+                let result1 = charlie.sign_and_gen_refresh(
+                    &[0],
+                    &"etaoin shrdlu".as_bytes(),
+                    &"YTMP".as_bytes(),
+                    &mut rng,
+                    &mut r2[..],
+                    &mut s2[..],
+                );
+                let result2 = charlie.sign_and_gen_refresh(
+                    &[1],
+                    &"Lorem ipsum dolor sit amet".as_bytes(),
+                    &"YWQMD".as_bytes(),
+                    &mut rng,
+                    &mut r2[..],
+                    &mut s2[..],
+                );
+                (result1, result2)
+            });
+
+            let aliceout = thandlea.join().unwrap();
+            match aliceout.0 {
+                Ok(_) => println!("working"),
+                Err(e) => println!("{:?}", e),
+            }
+            //assert!(aliceout.0.is_ok());
+            assert!(aliceout.1.is_ok());
+            let bobout = thandleb.join().unwrap();
+            assert!(bobout.0.is_ok());
+            assert!(bobout.1.is_ok());
+            let charlieout = thandlec.join().unwrap();
+            assert!(charlieout.0.is_ok());
+            assert!(charlieout.1.is_ok());
+        } else {
+            assert!(false);
+        }
+    }
+
+    #[test]
+    fn test_mpecdsa_3p2trefresh_standalone() {
+        let (sendvec, recvvec) = spawn_n2_channelstreams(3);
+
+        let thandles = sendvec
+            .into_iter()
+            .zip(recvvec.into_iter())
+            .enumerate()
+            .map(|(ii, (si, ri))| {
+                thread::spawn(move || {
+                    let mut rng = rand::thread_rng();
+                    let mut sin = si;
+                    let mut rin = ri;
+                    let mut signer =
+                        ThresholdSigner::new(ii, 2, &mut rng, &mut rin[..], &mut sin[..]).unwrap();
+                    let refpack = if ii < 2 {
+                        Some(
+                            signer
+                                .refresh(
+                                    &[1 - ii],
+                                    &"nightly".as_bytes(),
+                                    &mut rng,
+                                    &mut rin[..],
+                                    &mut sin[..],
+                                )
+                                .unwrap(),
+                        )
+                    } else {
+                        None
+                    };
+                    (signer, refpack, sin, rin)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut outs: Vec<_> = thandles.into_iter().map(|h| h.join().unwrap()).collect();
+        let refpack = outs[0].1.take().unwrap();
+        assert_eq!(refpack.0, Secp::INF);
+        for (signer, _, _, _) in outs.iter_mut() {
+            assert!(signer.apply_refresh(&refpack).is_ok());
+        }
+
+        // the party that was offline during the refresh can sign with either of the others
+        let thandles = outs
+            .into_iter()
+            .enumerate()
+            .map(|(ii, (mut signer, _, mut sin, mut rin))| {
+                thread::spawn(move || {
+                    let mut rng = rand::thread_rng();
+                    let counterparty = if ii == 2 { 0 } else { 2 };
+                    if ii == 1 {
+                        return Ok(None);
+                    }
+                    signer.sign(
+                        &[counterparty],
+                        &"etaoin shrdlu".as_bytes(),
+                        &mut rng,
+                        &mut rin[..],
+                        &mut sin[..],
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for handle in thandles {
+            assert!(handle.join().unwrap().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_mpecdsa_5p3trefresh_gen_apply() {
+        let (sendvec, recvvec) = spawn_n2_channelstreams(5);
+
+        let thandles = sendvec
+            .into_iter()
+            .zip(recvvec.into_iter())
+            .enumerate()
+            .map(|(ii, (si, ri))| {
+                thread::spawn(move || {
+                    let mut rng = rand::thread_rng();
+                    let mut sin = si;
+                    let mut rin = ri;
+                    let mut signer =
+                        ThresholdSigner::new(ii, 3, &mut rng, &mut rin[..], &mut sin[..]).unwrap();
+                    let result = if ii < 3 {
+                        let counterparties: Vec<usize> = (0..3).filter(|&x| x != ii).collect();
+                        Some(signer.sign_and_gen_refresh(
+                            &counterparties,
+                            &"etaoin shrdlu".as_bytes(),
+                            &"YW".as_bytes(),
+                            &mut rng,
+                            &mut rin[..],
+                            &mut sin[..],
+                        ))
+                    } else {
+                        None
+                    };
+                    (signer, result, sin, rin)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut outs: Vec<_> = thandles.into_iter().map(|h| h.join().unwrap()).collect();
+        let mut refpacks = Vec::new();
+        for out in outs.iter_mut() {
+            if let Some(result) = out.1.take() {
+                let (sig, refpack) = result.unwrap();
+                assert!(sig.is_some());
+                refpacks.push(refpack);
+            }
+        }
+
+        for (signer, _, _, _) in outs.iter_mut() {
+            let old_poly_point = signer.poly_point;
+            assert!(signer.apply_refresh(&refpacks[0]).is_ok());
+            assert!(signer.poly_point != old_poly_point);
+        }
+
+        // a tampered or replayed package must be rejected
+        let mut badpack = refpacks[1].clone();
+        badpack.1 = "YX".as_bytes().to_vec();
+        assert!(outs[0].0.apply_refresh(&badpack).is_err());
+        let old_poly_point = outs[0].0.poly_point;
+        assert!(outs[0].0.apply_refresh(&refpacks[1]).is_err());
+        assert!(outs[0].0.poly_point == old_poly_point);
+
+        // a different quorum, including both parties that did not participate, can still sign
+        let thandles = outs
+            .into_iter()
+            .enumerate()
+            .map(|(ii, (mut signer, _, mut sin, mut rin))| {
+                thread::spawn(move || {
+                    let mut rng = rand::thread_rng();
+                    let pk = signer.pk;
+                    let result = if ii >= 2 {
+                        let counterparties: Vec<usize> = (2..5).filter(|&x| x != ii).collect();
+                        signer.sign(
+                            &counterparties,
+                            &"Lorem ipsum dolor sit amet".as_bytes(),
+                            &mut rng,
+                            &mut rin[..],
+                            &mut sin[..],
+                        )
+                    } else {
+                        Ok(None)
+                    };
+                    (pk, result)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut firstpk = Secp::INF;
+        for handle in thandles {
+            let (pk, result) = handle.join().unwrap();
+            assert!(result.is_ok());
+            if firstpk == Secp::INF {
+                firstpk = pk;
+            } else {
+                assert_eq!(pk, firstpk);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mpecdsa_3p2treshare_4p3t() {
+        let (sendvec, recvvec) = spawn_n2_channelstreams(3);
+        let thandles = sendvec
+            .into_iter()
+            .zip(recvvec.into_iter())
+            .enumerate()
+            .map(|(ii, (si, ri))| {
+                thread::spawn(move || {
+                    let mut rng = rand::thread_rng();
+                    let mut sin = si;
+                    let mut rin = ri;
+                    ThresholdSigner::new(ii, 2, &mut rng, &mut rin[..], &mut sin[..]).unwrap()
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut oldsigners: Vec<Option<ThresholdSigner>> = thandles
+            .into_iter()
+            .map(|h| Some(h.join().unwrap()))
+            .collect();
+        let oldpk = oldsigners[0].as_ref().unwrap().pk;
+
+        // old party 1 leaves, old parties 0 and 2 become new parties 0 and 1, and two new parties join
+        let mut newold = vec![oldsigners[0].take(), oldsigners[2].take(), None, None];
+        let (sendvec, recvvec) = spawn_n2_channelstreams(4);
+        let thandles = sendvec
+            .into_iter()
+            .zip(recvvec.into_iter())
+            .enumerate()
+            .map(|(ii, (si, ri))| {
+                let mut old = newold[ii].take();
+                thread::spawn(move || {
+                    let mut rng = rand::thread_rng();
+                    let mut sin = si;
+                    let mut rin = ri;
+                    let mut signer = ThresholdSigner::reshare(
+                        old.as_mut(),
+                        &[0, 1],
+                        ii,
+                        3,
+                        &mut rng,
+                        &mut rin[..],
+                        &mut sin[..],
+                    )
+                    .unwrap();
+                    // the old share is wiped only once the new one exists
+                    if let Some(old) = old {
+                        assert_eq!(old.poly_point, SecpOrd::ZERO);
+                    }
+                    let pk = signer.pk;
+                    let result = if ii > 0 {
+                        let counterparties: Vec<usize> = (1..4).filter(|&x| x != ii).collect();
+                        signer.sign(
+                            &counterparties,
+                            &"etaoin shrdlu".as_bytes(),
+                            &mut rng,
+                            &mut rin[..],
+                            &mut sin[..],
+                        )
+                    } else {
+                        Ok(None)
+                    };
+                    (pk, result)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for handle in thandles {
+            let (pk, result) = handle.join().unwrap();
+            assert!(result.is_ok());
+            assert_eq!(pk, oldpk);
+        }
+    }
+
+    #[test]
+    fn test_mpecdsa_3p2tadd_remove() {
+        let msg = "etaoin shrdlu".as_bytes();
+        let (sendvec, recvvec) = spawn_n2_channelstreams(4);
 
-            let thandlec = thread::spawn(move || {
-                let mut rng = rand::thread_rng();
-                let result1 = charlie.sign_and_gen_refresh(
-                    &[0],
-                    &"etaoin shrdlu".as_bytes(),
-                    &"YTMP".as_bytes(),
-                    &mut rng,
-                    &mut r2[..],
-                    &mut s2[..],
-                );
-                let result2 = charlie.sign_and_gen_refresh(
-                    &[1],
-                    &"Lorem ipsum dolor sit amet".as_bytes(),
-                    &"YWQMD".as_bytes(),
-                    &mut rng,
-                    &mut r2[..],
-                    &mut s2[..],
-                );
-                (result1, result2)
-            });
+        let thandles = sendvec
+            .into_iter()
+            .zip(recvvec.into_iter())
+            .enumerate()
+            .map(|(ii, (si, ri))| {
+                thread::spawn(move || {
+                    let mut rng = rand::thread_rng();
+                    let mut sin = si;
+                    let mut rin = ri;
+                    let mut signer = if ii < 3 {
+                        let mut signer =
+                            ThresholdSigner::new(ii, 2, &mut rng, &mut rin[0..3], &mut sin[0..3])
+                                .unwrap();
+                        signer
+                            .add_party(&[0, 2], &mut rng, &mut rin[..], &mut sin[..])
+                            .unwrap();
+                        signer
+                    } else {
+                        ThresholdSigner::join(3, &[0, 2], &mut rng, &mut rin[..], &mut sin[..])
+                            .unwrap()
+                    };
+                    let old_poly_point = signer.poly_point;
+                    let join_epoch = signer.epoch;
 
-            let aliceout = thandlea.join().unwrap();
-            match aliceout.0 {
-                Ok(_) => println!("working"),
-                Err(e) => println!("{:?}", e),
-            }
-            //assert!(aliceout.0.is_ok());
-            assert!(aliceout.1.is_ok());
-            let bobout = thandleb.join().unwrap();
-            assert!(bobout.0.is_ok());
-            assert!(bobout.1.is_ok());
-            let charlieout = thandlec.join().unwrap();
-            assert!(charlieout.0.is_ok());
-            assert!(charlieout.1.is_ok());
-        } else {
-            assert!(false);
+                    // the new party signs with a party that was not in the quorum, then party 1 is removed
+                    match ii {
+                        1 => {
+                            signer
+                                .sign(&[3], &msg, &mut rng, &mut rin[..], &mut sin[..])
+                                .unwrap();
+                        }
+                        3 => {
+                            signer
+                                .sign(&[1], &msg, &mut rng, &mut rin[..], &mut sin[..])
+                                .unwrap();
+                            signer
+                                .remove_party(1, &mut rng, &mut rin[..], &mut sin[..])
+                                .unwrap();
+                            signer
+                                .sign(&[2], &msg, &mut rng, &mut rin[..], &mut sin[..])
+                                .unwrap();
+                        }
+                        _ => {
+                            assert!(signer
+                                .remove_party(ii, &mut rng, &mut rin[..], &mut sin[..])
+                                .is_err());
+                            signer
+                                .remove_party(1, &mut rng, &mut rin[..], &mut sin[..])
+                                .unwrap();
+                            assert!(signer
+                                .remove_party(1, &mut rng, &mut rin[..], &mut sin[..])
+                                .is_err());
+                            if ii == 0 {
+                                assert!(signer
+                                    .sign(&[1], &msg, &mut rng, &mut rin[..], &mut sin[..])
+                                    .is_err());
+                            } else {
+                                signer
+                                    .sign(&[3], &msg, &mut rng, &mut rin[..], &mut sin[..])
+                                    .unwrap();
+                            }
+                        }
+                    }
+                    (signer.pk, old_poly_point, signer.poly_point, join_epoch)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let outs: Vec<_> = thandles.into_iter().map(|h| h.join().unwrap()).collect();
+        for out in outs.iter() {
+            assert_eq!(out.0, outs[0].0);
+            // adding a party begins a new epoch, for the new party as for the rest
+            assert_eq!(out.3, 1);
         }
+
+        // the removed party's share no longer interpolates with the refreshed shares
+        let x0 = SecpOrd::from_native(1);
+        let x1 = SecpOrd::from_native(2);
+        let coef0 = x1.mul(&x1.sub(&x0).inv());
+        let coef1 = x0.mul(&x0.sub(&x1).inv());
+        let stale = Secp::scalar_table_multi(
+            Secp256k1::gen_table(),
+            &outs[0].2.mul(&coef0).add(&outs[1].1.mul(&coef1)),
+        )
+        .affine();
+        assert!(stale != outs[0].0);
+        let old = Secp::scalar_table_multi(
+            Secp256k1::gen_table(),
+            &outs[0].1.mul(&coef0).add(&outs[1].1.mul(&coef1)),
+        )
+        .affine();
+        assert_eq!(old, outs[0].0);
+
+        // while the remaining parties' new shares do
+        let x2 = SecpOrd::from_native(3);
+        let coef0 = x2.mul(&x2.sub(&x0).inv());
+        let coef2 = x0.mul(&x0.sub(&x2).inv());
+        let fresh = Secp::scalar_table_multi(
+            Secp256k1::gen_table(),
+            &outs[0].2.mul(&coef0).add(&outs[2].2.mul(&coef2)),
+        )
+        .affine();
+        assert_eq!(fresh, outs[0].0);
     }
 
     #[test]
@@ -2420,6 +5868,359 @@ mod tests {
         assert!(charlie.is_ok());
     }
 
+    #[test]
+    fn test_mpecdsa_5p3tpresign() {
+        let (sendvec, recvvec) = spawn_n2_channelstreams(5);
+        let quorum = [0usize, 2, 4];
+
+        let thandles = sendvec
+            .into_iter()
+            .zip(recvvec.into_iter())
+            .enumerate()
+            .map(|(ii, (si, ri))| {
+                thread::spawn(move || {
+                    let mut rng = rand::thread_rng();
+                    let mut sin = si;
+                    let mut rin = ri;
+                    let mut signer =
+                        ThresholdSigner::new(ii, 3, &mut rng, &mut rin[..], &mut sin[..]).unwrap();
+                    if !quorum.contains(&ii) {
+                        return None;
+                    }
+                    let counterparties: Vec<usize> =
+                        quorum.iter().cloned().filter(|&x| x != ii).collect();
+
+                    let presig1 = signer
+                        .presign(&counterparties, &mut rng, &mut rin[..], &mut sin[..])
+                        .unwrap();
+                    let presig2 = signer
+                        .presign(&counterparties, &mut rng, &mut rin[..], &mut sin[..])
+                        .unwrap();
+                    assert_eq!(presig1.parties(), &quorum[..]);
+
+                    // presignatures can be finished out of order, with other signing in between
+                    let sig0 = signer
+                        .sign(
+                            &counterparties,
+                            &"etaoin shrdlu".as_bytes(),
+                            &mut rng,
+                            &mut rin[..],
+                            &mut sin[..],
+                        )
+                        .unwrap()
+                        .unwrap();
+                    let sig2 = signer
+                        .finish_sign(
+                            presig2,
+                            &"cmfwyp vbgkqj".as_bytes(),
+                            &mut rng,
+                            &mut rin[..],
+                            &mut sin[..],
+                        )
+                        .unwrap();
+                    let sig1 = signer
+                        .finish_sign(
+                            presig1,
+                            &"etaoin shrdlu".as_bytes(),
+                            &mut rng,
+                            &mut rin[..],
+                            &mut sin[..],
+                        )
+                        .unwrap();
+
+                    // a presignature made before a refresh cannot be finished after it
+                    let presig3 = signer
+                        .presign(&counterparties, &mut rng, &mut rin[..], &mut sin[..])
+                        .unwrap();
+                    let refpack = signer
+                        .refresh(
+                            &counterparties,
+                            &"nightly".as_bytes(),
+                            &mut rng,
+                            &mut rin[..],
+                            &mut sin[..],
+                        )
+                        .unwrap();
+                    signer.apply_refresh(&refpack).unwrap();
+                    assert!(signer
+                        .finish_sign(
+                            presig3,
+                            &"etaoin shrdlu".as_bytes(),
+                            &mut rng,
+                            &mut rin[..],
+                            &mut sin[..],
+                        )
+                        .is_err());
+                    Some((signer.pk, sig0, sig1, sig2))
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let results: Vec<_> = thandles
+            .into_iter()
+            .filter_map(|h| h.join().unwrap())
+            .collect();
+        assert_eq!(results.len(), quorum.len());
+        for (pk, sig0, sig1, sig2) in results.iter() {
+            assert_eq!(
+                (pk, sig1, sig2),
+                (&results[0].0, &results[0].2, &results[0].3)
+            );
+            assert!(ecdsa::ecdsa_verify(
+                &"etaoin shrdlu".as_bytes(),
+                (&sig1.r, &sig1.s),
+                pk
+            ));
+            assert!(ecdsa::ecdsa_verify(
+                &"cmfwyp vbgkqj".as_bytes(),
+                (&sig2.r, &sig2.s),
+                pk
+            ));
+            // each presignature carries a fresh instance key
+            assert!(sig0.r != sig1.r && sig1.r != sig2.r);
+            assert_eq!(
+                ecdsa::recover_public_key(&"etaoin shrdlu".as_bytes(), sig1),
+                Some(*pk)
+            );
+            assert!(ecdsa::ecdsa_verify_strict(
+                &"cmfwyp vbgkqj".as_bytes(),
+                (&sig2.r, &sig2.s),
+                pk
+            ));
+        }
+    }
+
+    #[test]
+    fn test_mpecdsa_7p2tsetup() {
+        let threshold = 2;
+        let parties = 7;
+
+        let (sendvec, recvvec) = spawn_n2_channelstreams(parties);
+
+        let thandles = sendvec
+            .into_iter()
+            .zip(recvvec.into_iter())
+            .enumerate()
+            .map(|(ii, (si, ri))| {
+                thread::spawn(move || {
+                    let mut rng = rand::thread_rng();
+                    let mut sin = si;
+                    let mut rin = ri;
+                    ThresholdSigner::new(ii, threshold, &mut rng, &mut rin, &mut sin)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut firstpk = Secp::INF;
+        for handle in thandles {
+            let signer = handle.join().unwrap();
+            assert!(signer.is_ok());
+            if firstpk == Secp::INF {
+                firstpk = signer.unwrap().pk;
+            } else {
+                assert_eq!(signer.unwrap().pk, firstpk);
+            }
+        }
+    }
+
+    // flips a bit at a fixed offset in the stream, to simulate a cheating party
+    struct CorruptingWriter<T: Write> {
+        inner: T,
+        offset: usize,
+        written: usize,
+    }
+
+    impl<T: Write> Write for CorruptingWriter<T> {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            let mut buf = buf.to_vec();
+            if self.offset >= self.written && self.offset < self.written + buf.len() {
+                buf[self.offset - self.written] ^= 1;
+            }
+            self.written += buf.len();
+            self.inner.write(&buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            self.inner.flush()
+        }
+    }
+
+    #[test]
+    fn test_mpecdsa_7p2tsetup_cheating_dealer() {
+        let threshold = 2;
+        let parties = 7;
+
+        let (sendvec, recvvec) = spawn_n2_channelstreams(parties);
+
+        let thandles = sendvec
+            .into_iter()
+            .zip(recvvec.into_iter())
+            .enumerate()
+            .map(|(ii, (si, ri))| {
+                thread::spawn(move || {
+                    let mut rng = rand::thread_rng();
+                    // player 0 corrupts the fragment it deals to player 1, which follows the RO seed and
+                    // the polynomial commitments
+                    let mut sin: Vec<_> = si
+                        .into_iter()
+                        .enumerate()
+                        .map(|(jj, s)| {
+                            s.map(|s| CorruptingWriter {
+                                inner: s,
+                                offset: if ii == 0 && jj == 1 {
+                                    HASH_SIZE + threshold * POINT_SIZE
+                                } else {
+                                    usize::MAX
+                                },
+                                written: 0,
+                            })
+                        })
+                        .collect();
+                    let mut rin = ri;
+                    ThresholdSigner::new(ii, threshold, &mut rng, &mut rin, &mut sin)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // the other parties may abort when player 1 does, so only player 1's result is examined
+        let mut thandles = thandles.into_iter();
+        thandles.next();
+        match thandles.next().unwrap().join().unwrap() {
+            Err(MPECDSAError::Blame(e)) => {
+                assert_eq!(e.party(), 0);
+                assert_eq!(e.phase(), ProtocolPhase::Setup);
+            }
+            _ => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_threshold_from_bytes_refreshed_count() {
+        // a crafted file claiming an enormous number of refresh nonces is rejected, rather
+        // than allocated for
+        let mut body = Vec::new();
+        persist::write_usize(0, &mut body).unwrap();
+        persist::write_usize(2, &mut body).unwrap();
+        persist::write_scalar(&SecpOrd::ONE, &mut body).unwrap();
+        persist::write_point(&Secp::gen(), &mut body).unwrap();
+        body.extend_from_slice(&[0u8; CHAINCODE_SIZE]);
+        persist::write_usize(u64::MAX as usize, &mut body).unwrap();
+        persist::write_usize(0, &mut body).unwrap();
+        persist::write_usize(1 << 60, &mut body).unwrap();
+        let sealed = persist::seal(persist::PersistKind::ThresholdSigner, &body);
+        assert!(ThresholdSigner::from_bytes(&sealed).is_err());
+
+        // as is a count within the limit that the rest of the file cannot hold
+        let countpos = body.len() - 8;
+        LittleEndian::write_u64(&mut body[countpos..], 1000);
+        let sealed = persist::seal(persist::PersistKind::ThresholdSigner, &body);
+        assert!(ThresholdSigner::from_bytes(&sealed).is_err());
+    }
+
+    #[test]
+    fn test_wire_point_blame() {
+        let mut genraw = [0u8; POINT_SIZE];
+        Secp::gen().affine().to_wire_bytes(&mut genraw);
+        assert!(Secp::from_wire_bytes_finite(&genraw, 3, ProtocolPhase::Sign).is_ok());
+
+        // points off the curve, and the point at infinity where a key or nonce is expected,
+        // are blamed on the sender
+        for (raw, finite) in [([1u8; POINT_SIZE], false), ([0u8; POINT_SIZE], true)] {
+            let res = if finite {
+                Secp::from_wire_bytes_finite(&raw, 3, ProtocolPhase::Sign)
+            } else {
+                Secp::from_wire_bytes(&raw, 3, ProtocolPhase::Sign)
+            };
+            match res {
+                Err(MPECDSAError::Blame(e)) => {
+                    assert_eq!(e.party(), 3);
+                    assert_eq!(e.phase(), ProtocolPhase::Sign);
+                }
+                _ => assert!(false),
+            }
+        }
+    }
+
+    #[test]
+    fn test_recv_signature_recid() {
+        let mut rng = rand::thread_rng();
+        let mut digest = [0u8; HASH_SIZE];
+        ecdsa_hash(&mut digest, "etaoin shrdlu".as_bytes());
+        let sk = SecpOrd::rand(&mut rng);
+        let pk = Secp::scalar_gen(&sk).affine();
+
+        // sign as bob does, with the recovery id taken from R, and without normalizing s
+        let k = SecpOrd::rand(&mut rng);
+        let rpt = Secp::scalar_gen(&k).affine();
+        let mut rxb = [0u8; SCALAR_SIZE];
+        rpt.x().to_bytes(&mut rxb);
+        let r = SecpOrd::from_bytes(&rxb);
+        let z = SecpOrd::from_bytes(&digest);
+        let s = z.add(&r.mul(&sk)).mul(&k.inv());
+        let recid = ecdsa::recovery_id(&rpt);
+
+        // whichever of s and n-s is low, the returned signature must recover the key
+        let sigraw = ecdsa::Signature { r, s, recid: 0 }.to_compact();
+        let sig =
+            recv_signature::<Secp256k1, _>(&digest, 1, &pk, None, recid, &mut &sigraw[..]).unwrap();
+        assert!(sig.is_low_s());
+        assert_eq!(ecdsa::recover_public_key_prehashed(&digest, &sig), Some(pk));
+
+        // bob must not negate s himself, or swap r and s
+        for (r, s) in [(r, SecpOrd::ZERO.sub(&s)), (s, r)] {
+            let sigraw = ecdsa::Signature { r, s, recid: 0 }.to_compact();
+            match recv_signature::<Secp256k1, _>(&digest, 1, &pk, None, recid, &mut &sigraw[..]) {
+                Err(MPECDSAError::Blame(e)) => assert_eq!(e.party(), 1),
+                _ => assert!(false),
+            }
+        }
+    }
+
+    #[test]
+    fn test_commitment_echo_blame() {
+        let coms_hashes = vec![[1u8; HASH_SIZE], [2u8; HASH_SIZE], [3u8; HASH_SIZE]];
+        let dealers = [0, 2, 5];
+        let mut echoed_raw = coms_hashes.concat();
+        assert!(check_commitment_echo(
+            &coms_hashes,
+            &echoed_raw,
+            &dealers,
+            2,
+            0,
+            ProtocolPhase::Setup
+        )
+        .is_ok());
+
+        // a dealer that equivocated is blamed, rather than the party that noticed
+        echoed_raw[2 * HASH_SIZE] ^= 1;
+        match check_commitment_echo(
+            &coms_hashes,
+            &echoed_raw,
+            &dealers,
+            2,
+            0,
+            ProtocolPhase::Setup,
+        ) {
+            Err(MPECDSAError::Blame(e)) => assert_eq!(e.party(), 5),
+            _ => assert!(false),
+        }
+
+        // but we know what we dealt, so a false echo of our own commitments blames the echoer
+        echoed_raw[2 * HASH_SIZE] ^= 1;
+        echoed_raw[HASH_SIZE] ^= 1;
+        match check_commitment_echo(
+            &coms_hashes,
+            &echoed_raw,
+            &dealers,
+            2,
+            0,
+            ProtocolPhase::Setup,
+        ) {
+            Err(MPECDSAError::Blame(e)) => assert_eq!(e.party(), 0),
+            _ => assert!(false),
+        }
+    }
+
     #[test]
     fn test_mpecdsa_7p4tsetup() {
         let threshold = 4;
@@ -2535,6 +6336,80 @@ mod tests {
         assert_eq!(somecount, threshold);
     }
 
+    #[test]
+    fn test_mpecdsa_2psign_p256() {
+        let msg = "The Quick Brown Fox Jumped Over The Lazy Dog".as_bytes();
+        let mut rng = rand::thread_rng();
+        let ska = curves::SecpR1Ord::rand(&mut rng);
+        let skb = curves::SecpR1Ord::rand(&mut rng);
+
+        let (mut writ_a, mut read_b) = channelstream::new_channelstream();
+        let (mut writ_b, mut read_a) = channelstream::new_channelstream();
+
+        let thandle = thread::spawn(move || {
+            let mut rng = rand::thread_rng();
+            let bob =
+                GenericBob2P::<Secp256r1>::new(&skb, &mut rng, &mut read_b, &mut writ_b).unwrap();
+            bob.sign_with_return(&msg, &mut rng, &mut read_b, &mut writ_b)
+        });
+
+        let alice =
+            GenericAlice2P::<Secp256r1>::new(&ska, &mut rng, &mut read_a, &mut writ_a).unwrap();
+        let aliceresult = alice.sign_with_return(&msg, &mut rng, &mut read_a, &mut writ_a);
+        let bobresult = thandle.join().unwrap();
+        assert!(aliceresult.is_ok());
+        assert!(bobresult.is_ok());
+        let sig = aliceresult.unwrap();
+        assert_eq!(sig, bobresult.unwrap());
+
+        // the joint key is ska*skb*G, and the signature verifies under it with the ordinary algorithm
+        assert!(alice.pk == SecpR1::scalar_gen(&ska.mul(&skb)));
+        assert!(sig.is_low_s());
+        assert!(ecdsa::ecdsa_verify(&msg, (&sig.r, &sig.s), &alice.pk));
+        assert_eq!(ecdsa::recover_public_key(&msg, &sig), Some(alice.pk));
+    }
+
+    #[test]
+    fn test_mpecdsa_3p3tsign_p256() {
+        let msg = "etaoin shrdlu".as_bytes();
+        let (sendvec, recvvec) = spawn_n2_channelstreams(3);
+
+        let thandles = sendvec
+            .into_iter()
+            .zip(recvvec.into_iter())
+            .enumerate()
+            .map(|(ii, (mut si, mut ri))| {
+                thread::spawn(move || {
+                    let mut rng = rand::thread_rng();
+                    let mut signer = GenericThresholdSigner::<Secp256r1>::new(
+                        ii,
+                        3,
+                        &mut rng,
+                        &mut ri[..],
+                        &mut si[..],
+                    )
+                    .unwrap();
+                    let counterparties: Vec<usize> = (0..3).filter(|&jj| jj != ii).collect();
+                    let sig =
+                        signer.sign(&counterparties, &msg, &mut rng, &mut ri[..], &mut si[..]);
+                    (signer.pk, sig)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let outs: Vec<_> = thandles.into_iter().map(|h| h.join().unwrap()).collect();
+        let pk = outs[0].0;
+        let mut somecount = 0;
+        for (pki, sig) in outs.into_iter() {
+            assert!(pki == pk);
+            if let Some(sig) = sig.unwrap() {
+                assert!(ecdsa::ecdsa_verify(&msg, (&sig.r, &sig.s), &pk));
+                somecount += 1;
+            }
+        }
+        assert!(somecount > 0);
+    }
+
     #[bench]
     fn bench_ecdsa_2psign(b: &mut Bencher) -> () {
         let msg = "The Quick Brown Fox Jumped Over The Lazy Dog".as_bytes();
@@ -13,6 +13,27 @@ pub struct GeneralError {
     descstring: String,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProtocolPhase {
+    Setup,
+    Sign,
+    Refresh,
+    Reshare,
+    Join,
+    Remove,
+    RandomOT,
+    OTExtension,
+    RandomOracle,
+}
+
+// identifies a misbehaving party by its supergroup index, so that callers can exclude it and retry
+#[derive(Debug)]
+pub struct BlameError {
+    party: usize,
+    phase: ProtocolPhase,
+    check: String,
+}
+
 impl GeneralError {
     pub fn new(descstring: &str) -> GeneralError {
         GeneralError {
@@ -29,6 +50,28 @@ impl ProofError {
     }
 }
 
+impl BlameError {
+    pub fn new(party: usize, phase: ProtocolPhase, check: &str) -> BlameError {
+        BlameError {
+            party,
+            phase,
+            check: String::from(check),
+        }
+    }
+
+    pub fn party(&self) -> usize {
+        self.party
+    }
+
+    pub fn phase(&self) -> ProtocolPhase {
+        self.phase
+    }
+
+    pub fn check(&self) -> &str {
+        self.check.as_str()
+    }
+}
+
 impl error::Error for ProofError {
     fn description(&self) -> &str {
         "Proof Error"
@@ -41,6 +84,28 @@ impl error::Error for GeneralError {
     }
 }
 
+impl error::Error for BlameError {
+    fn description(&self) -> &str {
+        "Blame Error"
+    }
+}
+
+impl fmt::Display for ProtocolPhase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl fmt::Display for BlameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Player {} misbehaved during {}: {}",
+            self.party, self.phase, self.check
+        )
+    }
+}
+
 impl fmt::Display for ProofError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str(self.descstring.as_str())
@@ -57,14 +122,26 @@ impl fmt::Display for GeneralError {
 pub enum MPECDSAError {
     General(GeneralError),
     Proof(ProofError),
+    Blame(BlameError),
     Io(io::Error),
 }
 
+impl MPECDSAError {
+    // the supergroup index of the party responsible for the failure, if one could be identified
+    pub fn culprit(&self) -> Option<usize> {
+        match *self {
+            MPECDSAError::Blame(ref err) => Some(err.party()),
+            _ => None,
+        }
+    }
+}
+
 impl fmt::Display for MPECDSAError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             MPECDSAError::Io(ref err) => write!(f, "IO Error: {}", err),
             MPECDSAError::Proof(ref err) => write!(f, "Proof Error: {}", err),
+            MPECDSAError::Blame(ref err) => write!(f, "Blame Error: {}", err),
             MPECDSAError::General(ref err) => write!(f, "General Error: {}", err),
         }
     }
@@ -75,6 +152,7 @@ impl error::Error for MPECDSAError {
         match *self {
             MPECDSAError::Io(ref err) => err.description(),
             MPECDSAError::Proof(ref err) => err.description(),
+            MPECDSAError::Blame(ref err) => err.description(),
             MPECDSAError::General(ref err) => err.description(),
         }
     }*/
@@ -83,6 +161,7 @@ impl error::Error for MPECDSAError {
         match *self {
             MPECDSAError::Io(ref err) => Some(err),
             MPECDSAError::Proof(ref err) => Some(err),
+            MPECDSAError::Blame(ref err) => Some(err),
             MPECDSAError::General(ref err) => Some(err),
         }
     }
@@ -100,6 +179,12 @@ impl From<ProofError> for MPECDSAError {
     }
 }
 
+impl From<BlameError> for MPECDSAError {
+    fn from(err: BlameError) -> MPECDSAError {
+        MPECDSAError::Blame(err)
+    }
+}
+
 impl From<GeneralError> for MPECDSAError {
     fn from(err: GeneralError) -> MPECDSAError {
         MPECDSAError::General(err)
@@ -12,7 +12,7 @@ use std::result::Result;
 
 use rand::Rng;
 
-use curves::{Ford, SecpOrd};
+use curves::Ford;
 
 use rayon::prelude::*;
 use rayon::ThreadPool;
@@ -24,14 +24,18 @@ use super::*;
 
 extern crate test;
 
-pub fn mpmul_round<TR: Read + std::marker::Send, TW: Write + std::marker::Send>(
+pub fn mpmul_round<
+    S: Ford + Send + Sync,
+    TR: Read + std::marker::Send,
+    TW: Write + std::marker::Send,
+>(
     round: usize,
-    inputs: &[SecpOrd],
+    inputs: &[S],
     playerindex: usize,
-    shares: &[&[(SecpOrd, SecpOrd)]],
+    shares: &[&[(S, S)]],
     recv: &mut [&mut Option<TR>],
     send: &mut [&mut Option<TW>],
-) -> Result<Vec<SecpOrd>, MPECDSAError> {
+) -> Result<Vec<S>, MPECDSAError> {
     if recv.len() != send.len() {
         return Err(MPECDSAError::General(GeneralError::new(
             "Number of Send streams does not match number of Recv streams",
@@ -58,13 +62,13 @@ pub fn mpmul_round<TR: Read + std::marker::Send, TW: Write + std::marker::Send>(
             let counterparty = thislevelbase + jj;
 
             if counterparty != playerindex {
-                let mut deltasbuf = vec![0u8; SecpOrd::NBYTES * inputs.len()];
+                let mut deltasbuf = vec![0u8; S::NBYTES * inputs.len()];
                 for kk in 0..inputs.len() {
-                    betas[kk].sub(&shares[kk].0).to_bytes(
-                        &mut deltasbuf[(kk * SecpOrd::NBYTES)..((kk + 1) * SecpOrd::NBYTES)],
-                    );
+                    betas[kk]
+                        .sub(&shares[kk].0)
+                        .to_bytes(&mut deltasbuf[(kk * S::NBYTES)..((kk + 1) * S::NBYTES)]);
                 }
-                sendi.as_mut().unwrap().write(&deltasbuf)?;
+                sendi.as_mut().unwrap().write_all(&deltasbuf)?;
                 sendi.as_mut().unwrap().flush()?;
             }
 
@@ -79,34 +83,33 @@ pub fn mpmul_round<TR: Read + std::marker::Send, TW: Write + std::marker::Send>(
         .map(|(jj, (recvi, shares))| {
             let counterparty = thislevelbase + jj;
 
-            let mut roundoutputaccumulator = vec![SecpOrd::ZERO; inputs.len()];
+            let mut roundoutputaccumulator = vec![S::ZERO; inputs.len()];
 
             if counterparty != playerindex {
-                let mut deltasbuf = vec![0u8; SecpOrd::NBYTES * inputs.len()];
+                let mut deltasbuf = vec![0u8; S::NBYTES * inputs.len()];
                 recvi.as_mut().unwrap().read_exact(&mut deltasbuf)?;
 
                 for kk in 0..inputs.len() {
                     roundoutputaccumulator[kk] = shares[kk].1.add(
-                        &SecpOrd::from_bytes(
-                            &deltasbuf[(kk * SecpOrd::NBYTES)..((kk + 1) * SecpOrd::NBYTES)],
-                        )
-                        .mul(if counterparty < discriminator {
-                            // I am Bob
-                            &betas[kk]
-                        } else {
-                            // I am Alice
-                            &shares[kk].0
-                        }),
+                        &S::from_bytes(&deltasbuf[(kk * S::NBYTES)..((kk + 1) * S::NBYTES)]).mul(
+                            if counterparty < discriminator {
+                                // I am Bob
+                                &betas[kk]
+                            } else {
+                                // I am Alice
+                                &shares[kk].0
+                            },
+                        ),
                     );
                 }
             }
 
             Ok(roundoutputaccumulator)
         })
-        .collect::<Result<Vec<Vec<SecpOrd>>, MPECDSAError>>()?;
+        .collect::<Result<Vec<Vec<S>>, MPECDSAError>>()?;
 
     if thislevelparties > 0 {
-        betas = vec![SecpOrd::ZERO; inputs.len()];
+        betas = vec![S::ZERO; inputs.len()];
         for thisresult in resultvec {
             for kk in 0..inputs.len() {
                 betas[kk] = betas[kk].add(&thisresult[kk]);
@@ -117,10 +120,10 @@ pub fn mpmul_round<TR: Read + std::marker::Send, TW: Write + std::marker::Send>(
     return Ok(betas);
 }
 
-pub fn mpmul_first<TW: Write + std::marker::Send>(
-    inputs: &[SecpOrd],
+pub fn mpmul_first<S: Ford + Send + Sync, TW: Write + std::marker::Send>(
+    inputs: &[S],
     playerindex: usize,
-    shares: &[&[SecpOrd]],
+    shares: &[&[S]],
     send: &mut [&mut Option<TW>],
 ) -> Result<(), MPECDSAError> {
     let playercount = send.len();
@@ -142,13 +145,13 @@ pub fn mpmul_first<TW: Write + std::marker::Send>(
             let counterparty = thislevelbase + jj;
 
             if counterparty != playerindex {
-                let mut deltasbuf = vec![0u8; SecpOrd::NBYTES * inputs.len()];
+                let mut deltasbuf = vec![0u8; S::NBYTES * inputs.len()];
                 for kk in 0..inputs.len() {
-                    inputs[kk].sub(&shares[kk]).to_bytes(
-                        &mut deltasbuf[(kk * SecpOrd::NBYTES)..((kk + 1) * SecpOrd::NBYTES)],
-                    );
+                    inputs[kk]
+                        .sub(&shares[kk])
+                        .to_bytes(&mut deltasbuf[(kk * S::NBYTES)..((kk + 1) * S::NBYTES)]);
                 }
-                sendi.as_mut().unwrap().write(&deltasbuf)?;
+                sendi.as_mut().unwrap().write_all(&deltasbuf)?;
                 sendi.as_mut().unwrap().flush()?;
             }
 
@@ -159,13 +162,17 @@ pub fn mpmul_first<TW: Write + std::marker::Send>(
     Ok(())
 }
 
-pub fn mpmul_rest<TR: Read + std::marker::Send, TW: Write + std::marker::Send>(
-    inputs: &[SecpOrd],
+pub fn mpmul_rest<
+    S: Ford + Send + Sync,
+    TR: Read + std::marker::Send,
+    TW: Write + std::marker::Send,
+>(
+    inputs: &[S],
     playerindex: usize,
-    shares: &[&[(SecpOrd, SecpOrd)]],
+    shares: &[&[(S, S)]],
     recv: &mut [&mut Option<TR>],
     send: &mut [&mut Option<TW>],
-) -> Result<Vec<SecpOrd>, MPECDSAError> {
+) -> Result<Vec<S>, MPECDSAError> {
     if recv.len() != send.len() {
         return Err(MPECDSAError::General(GeneralError::new(
             "Number of Send streams does not match number of Recv streams",
@@ -198,34 +205,33 @@ pub fn mpmul_rest<TR: Read + std::marker::Send, TW: Write + std::marker::Send>(
         .map(|(jj, (recvi, shares))| {
             let counterparty = thislevelbase + jj;
 
-            let mut roundoutputaccumulator = vec![SecpOrd::ZERO; inputs.len()];
+            let mut roundoutputaccumulator = vec![S::ZERO; inputs.len()];
 
             if counterparty != playerindex {
-                let mut deltasbuf = vec![0u8; SecpOrd::NBYTES * inputs.len()];
+                let mut deltasbuf = vec![0u8; S::NBYTES * inputs.len()];
                 recvi.as_mut().unwrap().read_exact(&mut deltasbuf)?;
 
                 for kk in 0..inputs.len() {
                     roundoutputaccumulator[kk] = shares[kk].1.add(
-                        &SecpOrd::from_bytes(
-                            &deltasbuf[(kk * SecpOrd::NBYTES)..((kk + 1) * SecpOrd::NBYTES)],
-                        )
-                        .mul(if counterparty < discriminator {
-                            // I am Bob
-                            &betas[kk]
-                        } else {
-                            // I am Alice
-                            &shares[kk].0
-                        }),
+                        &S::from_bytes(&deltasbuf[(kk * S::NBYTES)..((kk + 1) * S::NBYTES)]).mul(
+                            if counterparty < discriminator {
+                                // I am Bob
+                                &betas[kk]
+                            } else {
+                                // I am Alice
+                                &shares[kk].0
+                            },
+                        ),
                     );
                 }
             }
 
             Ok(roundoutputaccumulator)
         })
-        .collect::<Result<Vec<Vec<SecpOrd>>, MPECDSAError>>()?;
+        .collect::<Result<Vec<Vec<S>>, MPECDSAError>>()?;
 
     if thislevelparties > 0 {
-        betas = vec![SecpOrd::ZERO; inputs.len()];
+        betas = vec![S::ZERO; inputs.len()];
         for thisresult in resultvec {
             for kk in 0..inputs.len() {
                 betas[kk] = betas[kk].add(&thisresult[kk]);
@@ -240,13 +246,13 @@ pub fn mpmul_rest<TR: Read + std::marker::Send, TW: Write + std::marker::Send>(
     return Ok(betas);
 }
 
-pub fn mpmul<TR: Read + std::marker::Send, TW: Write + std::marker::Send>(
-    inputs: &[SecpOrd],
+pub fn mpmul<S: Ford + Send + Sync, TR: Read + std::marker::Send, TW: Write + std::marker::Send>(
+    inputs: &[S],
     playerindex: usize,
-    shares: &[&[(SecpOrd, SecpOrd)]],
+    shares: &[&[(S, S)]],
     recv: &mut [&mut Option<TR>],
     send: &mut [&mut Option<TW>],
-) -> Result<Vec<SecpOrd>, MPECDSAError> {
+) -> Result<Vec<S>, MPECDSAError> {
     let playercount = recv.len();
     let levels = (size_of_val(&playercount) * 8) - (playercount.leading_zeros() as usize) - 1;
     let levels = if playercount > (1 << levels) {
@@ -264,10 +270,10 @@ pub fn mpmul<TR: Read + std::marker::Send, TW: Write + std::marker::Send>(
     Ok(betas)
 }
 
-pub fn mpswapmul_send<TW: Write + std::marker::Send>(
-    inputs: &[(SecpOrd, SecpOrd)],
+pub fn mpswapmul_send<S: Ford + Send + Sync, TW: Write + std::marker::Send>(
+    inputs: &[(S, S)],
     playerindex: usize,
-    shares: &[&[(SecpOrd, SecpOrd)]],
+    shares: &[&[(S, S)]],
     send: &mut [&mut Option<TW>],
 ) -> Result<(), MPECDSAError> {
     send.iter_mut() //par_iter_mut
@@ -277,31 +283,27 @@ pub fn mpswapmul_send<TW: Write + std::marker::Send>(
             let counterparty = jj;
 
             if counterparty != playerindex {
-                let mut deltasbuf = vec![0u8; SecpOrd::NBYTES * 2 * inputs.len()];
+                let mut deltasbuf = vec![0u8; S::NBYTES * 2 * inputs.len()];
                 for kk in 0..inputs.len() {
                     if counterparty < playerindex {
                         // I am Bob
                         inputs[kk].0.sub(&shares[kk * 2].0).to_bytes(
-                            &mut deltasbuf
-                                [(2 * kk * SecpOrd::NBYTES)..((2 * kk + 1) * SecpOrd::NBYTES)],
+                            &mut deltasbuf[(2 * kk * S::NBYTES)..((2 * kk + 1) * S::NBYTES)],
                         );
                         inputs[kk].1.sub(&shares[kk * 2 + 1].0).to_bytes(
-                            &mut deltasbuf[((2 * kk + 1) * SecpOrd::NBYTES)
-                                ..((2 * kk + 2) * SecpOrd::NBYTES)],
+                            &mut deltasbuf[((2 * kk + 1) * S::NBYTES)..((2 * kk + 2) * S::NBYTES)],
                         );
                     } else if counterparty > playerindex {
                         // I am Alice
                         inputs[kk].1.sub(&shares[kk * 2].0).to_bytes(
-                            &mut deltasbuf
-                                [(2 * kk * SecpOrd::NBYTES)..((2 * kk + 1) * SecpOrd::NBYTES)],
+                            &mut deltasbuf[(2 * kk * S::NBYTES)..((2 * kk + 1) * S::NBYTES)],
                         );
                         inputs[kk].0.sub(&shares[kk * 2 + 1].0).to_bytes(
-                            &mut deltasbuf[((2 * kk + 1) * SecpOrd::NBYTES)
-                                ..((2 * kk + 2) * SecpOrd::NBYTES)],
+                            &mut deltasbuf[((2 * kk + 1) * S::NBYTES)..((2 * kk + 2) * S::NBYTES)],
                         );
                     }
                 }
-                sendi.as_mut().unwrap().write(&deltasbuf)?;
+                sendi.as_mut().unwrap().write_all(&deltasbuf)?;
             }
 
             Ok(())
@@ -311,12 +313,12 @@ pub fn mpswapmul_send<TW: Write + std::marker::Send>(
     Ok(())
 }
 
-pub fn mpswapmul_recv<TR: Read + std::marker::Send>(
-    inputs: &[(SecpOrd, SecpOrd)],
+pub fn mpswapmul_recv<S: Ford + Send + Sync, TR: Read + std::marker::Send>(
+    inputs: &[(S, S)],
     playerindex: usize,
-    shares: &[&[(SecpOrd, SecpOrd)]],
+    shares: &[&[(S, S)]],
     recv: &mut [&mut Option<TR>],
-) -> Result<Vec<SecpOrd>, MPECDSAError> {
+) -> Result<Vec<S>, MPECDSAError> {
     let resultvec = recv
         .iter_mut() //part_iter_mut
         .zip(shares.iter()) //par_iter
@@ -324,19 +326,18 @@ pub fn mpswapmul_recv<TR: Read + std::marker::Send>(
         .map(|(jj, (recvi, shares))| {
             let counterparty = jj;
 
-            let mut roundoutputaccumulator = vec![SecpOrd::ZERO; inputs.len()];
+            let mut roundoutputaccumulator = vec![S::ZERO; inputs.len()];
 
             if counterparty != playerindex {
-                let mut deltasbuf = vec![0u8; SecpOrd::NBYTES * 2 * inputs.len()];
+                let mut deltasbuf = vec![0u8; S::NBYTES * 2 * inputs.len()];
                 recvi.as_mut().unwrap().read_exact(&mut deltasbuf)?;
 
                 for kk in 0..inputs.len() {
                     roundoutputaccumulator[kk] = shares[kk * 2]
                         .1
                         .add(
-                            &SecpOrd::from_bytes(
-                                &deltasbuf
-                                    [(2 * kk * SecpOrd::NBYTES)..((2 * kk + 1) * SecpOrd::NBYTES)],
+                            &S::from_bytes(
+                                &deltasbuf[(2 * kk * S::NBYTES)..((2 * kk + 1) * S::NBYTES)],
                             )
                             .mul(if counterparty < playerindex {
                                 // I am Bob
@@ -348,9 +349,9 @@ pub fn mpswapmul_recv<TR: Read + std::marker::Send>(
                         )
                         .add(
                             &shares[kk * 2 + 1].1.add(
-                                &SecpOrd::from_bytes(
-                                    &deltasbuf[((2 * kk + 1) * SecpOrd::NBYTES)
-                                        ..((2 * kk + 2) * SecpOrd::NBYTES)],
+                                &S::from_bytes(
+                                    &deltasbuf
+                                        [((2 * kk + 1) * S::NBYTES)..((2 * kk + 2) * S::NBYTES)],
                                 )
                                 .mul(
                                     if counterparty < playerindex {
@@ -372,9 +373,9 @@ pub fn mpswapmul_recv<TR: Read + std::marker::Send>(
 
             Ok(roundoutputaccumulator)
         })
-        .collect::<Result<Vec<Vec<SecpOrd>>, MPECDSAError>>()?; //});
+        .collect::<Result<Vec<Vec<S>>, MPECDSAError>>()?; //});
 
-    let mut sums = vec![SecpOrd::ZERO; inputs.len()];
+    let mut sums = vec![S::ZERO; inputs.len()];
     for result in resultvec {
         for kk in 0..inputs.len() {
             sums[kk] = sums[kk].add(&result[kk]);
@@ -384,32 +385,38 @@ pub fn mpswapmul_recv<TR: Read + std::marker::Send>(
     Ok(sums)
 }
 
-pub fn mpswapmul<TR: Read + std::marker::Send, TW: Write + std::marker::Send>(
-    inputs: &[(SecpOrd, SecpOrd)],
+pub fn mpswapmul<
+    S: Ford + Send + Sync,
+    TR: Read + std::marker::Send,
+    TW: Write + std::marker::Send,
+>(
+    inputs: &[(S, S)],
     playerindex: usize,
-    shares: &[&[(SecpOrd, SecpOrd)]],
+    shares: &[&[(S, S)]],
     recv: &mut [&mut Option<TR>],
     send: &mut [&mut Option<TW>],
-) -> Result<Vec<SecpOrd>, MPECDSAError> {
+) -> Result<Vec<S>, MPECDSAError> {
     mpswapmul_send(inputs, playerindex, shares, send)?;
     mpswapmul_recv(inputs, playerindex, shares, recv)
 }
 
-pub enum RmulData {
-    Sender(RmulSenderData),
-    Recver(RmulRecverData),
+pub enum RmulData<S> {
+    Sender(RmulSenderData<S>),
+    Recver(RmulRecverData<S>),
 }
+pub type RmulRoundOneData<S> = Vec<(Vec<S>, Option<RmulData<S>>)>;
+pub type RmulShares<S> = Vec<Vec<(S, S)>>;
 
-pub fn mprmul_round_one<TR: Read + std::marker::Send, TW: Write + std::marker::Send>(
+pub fn mprmul_round_one<C: Curve, TR: Read + std::marker::Send, TW: Write + std::marker::Send>(
     mulcount: usize,
     playerindex: usize,
-    multiplier: &mut [&mul::MulPlayer],
+    multiplier: &mut [&mul::MulPlayer<C>],
     ro: &GroupROTagger,
     rng: &mut dyn Rng,
     recv: &mut [&mut Option<TR>],
     send: &mut [&mut Option<TW>],
     rayonpool: &ThreadPool,
-) -> Result<Vec<(Vec<SecpOrd>, Option<RmulData>)>, MPECDSAError> {
+) -> Result<RmulRoundOneData<C::Scalar>, MPECDSAError> {
     if recv.len() != send.len() {
         return Err(MPECDSAError::General(GeneralError::new(
             "Number of Send streams does not match number of Recv streams",
@@ -475,21 +482,21 @@ pub fn mprmul_round_one<TR: Read + std::marker::Send, TW: Write + std::marker::S
                     Ok((Vec::with_capacity(0), None))
                 }
             })
-            .collect::<Result<Vec<(Vec<SecpOrd>, Option<RmulData>)>, MPECDSAError>>()
+            .collect::<Result<RmulRoundOneData<C::Scalar>, MPECDSAError>>()
     })
 }
 
-pub fn mprmul_round_two<TR: Read + std::marker::Send, TW: Write + std::marker::Send>(
+pub fn mprmul_round_two<C: Curve, TR: Read + std::marker::Send, TW: Write + std::marker::Send>(
     playerindex: usize,
-    round_one_data: &Vec<(Vec<SecpOrd>, Option<RmulData>)>,
-    multiplier: &mut [&mul::MulPlayer],
+    round_one_data: &RmulRoundOneData<C::Scalar>,
+    multiplier: &mut [&mul::MulPlayer<C>],
     ro: &GroupROTagger,
     rng: &mut dyn Rng,
     recv: &mut [&mut Option<TR>],
     send: &mut [&mut Option<TW>],
     rayonpool: &ThreadPool,
     auxsend: Option<Vec<Vec<u8>>>,
-) -> Result<Vec<Vec<SecpOrd>>, MPECDSAError> {
+) -> Result<Vec<Vec<C::Scalar>>, MPECDSAError> {
     if recv.len() != send.len() {
         return Err(MPECDSAError::General(GeneralError::new(
             "Number of Send streams does not match number of Recv streams",
@@ -548,7 +555,7 @@ pub fn mprmul_round_two<TR: Read + std::marker::Send, TW: Write + std::marker::S
                             }
                         };
 
-                        sendi.as_mut().unwrap().write(&auxsend_datum)?;
+                        sendi.as_mut().unwrap().write_all(&auxsend_datum)?;
                         sendi.as_mut().unwrap().flush()?;
                         let output = thismultiplier.rmul_transfer(
                             &extensions.0.iter().collect::<Vec<_>>(),
@@ -589,7 +596,7 @@ pub fn mprmul_round_two<TR: Read + std::marker::Send, TW: Write + std::marker::S
                             rngi,
                             sendi.as_mut().unwrap(),
                         )?;
-                        sendi.as_mut().unwrap().write(&auxsend_datum)?;
+                        sendi.as_mut().unwrap().write_all(&auxsend_datum)?;
                         sendi.as_mut().unwrap().flush()?;
                         Ok(output)
                     } else {
@@ -598,20 +605,20 @@ pub fn mprmul_round_two<TR: Read + std::marker::Send, TW: Write + std::marker::S
                     }
                 },
             )
-            .collect::<Result<Vec<Vec<SecpOrd>>, MPECDSAError>>()
+            .collect::<Result<Vec<Vec<C::Scalar>>, MPECDSAError>>()
     })
 }
 
-pub fn mprmul<TR: Read + std::marker::Send, TW: Write + std::marker::Send>(
+pub fn mprmul<C: Curve, TR: Read + std::marker::Send, TW: Write + std::marker::Send>(
     mulcount: usize,
     playerindex: usize,
-    multiplier: &mut [&mul::MulPlayer],
+    multiplier: &mut [&mul::MulPlayer<C>],
     ro: &GroupROTagger,
     rng: &mut dyn Rng,
     recv: &mut [&mut Option<TR>],
     send: &mut [&mut Option<TW>],
     rayonpool: &ThreadPool,
-) -> Result<Vec<Vec<(SecpOrd, SecpOrd)>>, MPECDSAError> {
+) -> Result<RmulShares<C::Scalar>, MPECDSAError> {
     let r1d = mprmul_round_one(
         mulcount,
         playerindex,
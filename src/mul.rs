@@ -13,7 +13,7 @@ use super::mpecdsa_error::*;
 use super::ote::*;
 use super::ro::*;
 use super::*;
-use curves::{precomp, Ford, SecpOrd};
+use curves::Ford;
 use rand::Rng;
 use std::cmp;
 use std::result::Result;
@@ -21,75 +21,161 @@ use std::result::Result;
 extern crate test;
 
 //#[derive(Clone)]
-pub struct MulSender {
-    publicrandomvec: Vec<SecpOrd>,
+pub struct GenericMulSender<C: Curve> {
+    publicrandomvec: Vec<C::Scalar>,
     ote: OTESender,
 }
 
 //#[derive(Clone)]
-pub struct MulRecver {
-    publicrandomvec: Vec<SecpOrd>,
+pub struct GenericMulRecver<C: Curve> {
+    publicrandomvec: Vec<C::Scalar>,
     ote: OTERecver,
 }
 
+pub type MulSender = GenericMulSender<Secp256k1>;
+pub type MulRecver = GenericMulRecver<Secp256k1>;
+
 //#[derive(Clone)]
-pub enum MulPlayer {
-    Sender(MulSender),
-    Recver(MulRecver),
+pub enum MulPlayer<C: Curve = Secp256k1> {
+    Sender(GenericMulSender<C>),
+    Recver(GenericMulRecver<C>),
     Null,
 }
 
 pub type MulSenderData = (
-    Vec<[u8; HASH_SIZE * (SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS)]>,
+    Vec<[u8; HASH_SIZE * (SCALAR_BITS + ENCODING_PER_ELEMENT_BITS)]>,
     [u8; ENCODING_EXTRA_BITS * HASH_SIZE],
 );
-pub type RmulSenderData = (
+pub type RmulSenderData<S> = (
     Vec<[u8; HASH_SIZE * RAND_ENCODING_PER_ELEMENT_BITS]>,
     [u8; RAND_ENCODING_EXTRA_BITS * HASH_SIZE],
-    Vec<SecpOrd>,
+    Vec<S>,
 );
 pub type MulRecverData = (
-    Vec<[bool; SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS]>,
+    Vec<[bool; SCALAR_BITS + ENCODING_PER_ELEMENT_BITS]>,
     [bool; ENCODING_EXTRA_BITS],
-    Vec<[u8; (SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE]>,
+    Vec<[u8; (SCALAR_BITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE]>,
     [u8; ENCODING_EXTRA_BITS * HASH_SIZE],
 );
-pub type RmulRecverData = (
+pub type RmulRecverData<S> = (
     Vec<[bool; RAND_ENCODING_PER_ELEMENT_BITS]>,
     [bool; RAND_ENCODING_EXTRA_BITS],
     Vec<[u8; RAND_ENCODING_PER_ELEMENT_BITS * HASH_SIZE]>,
     [u8; RAND_ENCODING_EXTRA_BITS * HASH_SIZE],
-    Vec<SecpOrd>,
+    Vec<S>,
 );
 
-impl MulSender {
+// the public random vector always has the same length; see MulSender::new
+fn publicrandomvec_len() -> usize {
+    cmp::max(
+        SCALAR_BITS + ENCODING_PER_ELEMENT_BITS + ENCODING_EXTRA_BITS,
+        RAND_ENCODING_PER_ELEMENT_BITS + RAND_ENCODING_EXTRA_BITS,
+    )
+}
+
+fn write_publicrandomvec<S: Ford, T: Write>(
+    publicrandomvec: &[S],
+    send: &mut T,
+) -> Result<(), MPECDSAError> {
+    let mut buf = [0u8; SCALAR_SIZE];
+    for el in publicrandomvec {
+        el.to_bytes(&mut buf);
+        send.write_all(&buf)?;
+    }
+    Ok(())
+}
+
+fn read_publicrandomvec<S: Ford, T: Read>(recv: &mut T) -> Result<Vec<S>, MPECDSAError> {
+    let mut publicrandomvec = vec![S::ZERO; publicrandomvec_len()];
+    let mut buf = [0u8; SCALAR_SIZE];
+    for ii in 0..publicrandomvec.len() {
+        recv.read_exact(&mut buf)?;
+        publicrandomvec[ii] = S::from_bytes(&buf);
+    }
+    Ok(publicrandomvec)
+}
+
+impl<C: Curve> MulPlayer<C> {
+    // one byte identifies the role, followed by the state of the multiplier itself (if any)
+    pub fn write_to<T: Write>(&self, send: &mut T) -> Result<(), MPECDSAError> {
+        match self {
+            MulPlayer::Null => {
+                send.write_all(&[0u8])?;
+                Ok(())
+            }
+            MulPlayer::Sender(m) => {
+                send.write_all(&[1u8])?;
+                m.write_to(send)
+            }
+            MulPlayer::Recver(m) => {
+                send.write_all(&[2u8])?;
+                m.write_to(send)
+            }
+        }
+    }
+
+    pub fn read_from<T: Read>(recv: &mut T) -> Result<MulPlayer<C>, MPECDSAError> {
+        let mut role = [0u8; 1];
+        recv.read_exact(&mut role)?;
+        match role[0] {
+            0 => Ok(MulPlayer::Null),
+            1 => Ok(MulPlayer::Sender(GenericMulSender::read_from(recv)?)),
+            2 => Ok(MulPlayer::Recver(GenericMulRecver::read_from(recv)?)),
+            _ => Err(MPECDSAError::General(GeneralError::new(
+                "Invalid multiplier role in serialized state",
+            ))),
+        }
+    }
+
+    // destroys the secret state of the multiplier, for when the share it belongs to is retired
+    pub fn wipe(&mut self) {
+        match self {
+            MulPlayer::Null => {}
+            MulPlayer::Sender(m) => m.ote.wipe(),
+            MulPlayer::Recver(m) => m.ote.wipe(),
+        }
+    }
+}
+
+impl<C: Curve> GenericMulSender<C> {
     pub fn new<T1: Read, T2: Write>(
         ro: &DyadicROTagger,
         rng: &mut dyn Rng,
         recv: &mut T1,
         send: &mut T2,
-    ) -> Result<MulSender, MPECDSAError> {
-        let total_bits = cmp::max(
-            SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS + ENCODING_EXTRA_BITS,
-            RAND_ENCODING_PER_ELEMENT_BITS + RAND_ENCODING_EXTRA_BITS,
-        );
-        let mut publicrandomvec = vec![SecpOrd::ZERO; total_bits];
-        let mut raw_nonce = [0u8; SecpOrd::NBYTES];
+    ) -> Result<GenericMulSender<C>, MPECDSAError> {
+        let total_bits = publicrandomvec_len();
+        let mut publicrandomvec = vec![C::Scalar::ZERO; total_bits];
+        let mut raw_nonce = [0u8; SCALAR_SIZE];
         recv.read_exact(&mut raw_nonce)?;
-        let mut nonce = SecpOrd::from_bytes(&raw_nonce);
-        let mut prv_element = [0u8; SecpOrd::NBYTES];
+        let mut nonce = C::Scalar::from_bytes(&raw_nonce);
+        let mut prv_element = [0u8; SCALAR_SIZE];
         for ii in 0..total_bits {
-            nonce = nonce.add(&SecpOrd::ONE);
+            nonce = nonce.add(&C::Scalar::ONE);
             nonce.to_bytes(&mut raw_nonce);
             hash(&mut prv_element, &raw_nonce);
-            publicrandomvec[ii] = SecpOrd::from_bytes(&prv_element);
+            publicrandomvec[ii] = scalar_from_hash::<C::Scalar>(&prv_element);
         }
 
         let ote = OTESender::new(ro, rng, recv, send)?;
 
-        Ok(MulSender {
-            publicrandomvec: publicrandomvec,
-            ote: ote,
+        Ok(GenericMulSender {
+            publicrandomvec,
+            ote,
+        })
+    }
+
+    pub fn write_to<T: Write>(&self, send: &mut T) -> Result<(), MPECDSAError> {
+        write_publicrandomvec(&self.publicrandomvec, send)?;
+        self.ote.write_to(send)
+    }
+
+    pub fn read_from<T: Read>(recv: &mut T) -> Result<GenericMulSender<C>, MPECDSAError> {
+        let publicrandomvec = read_publicrandomvec(recv)?;
+        let ote = OTESender::read_from(recv)?;
+        Ok(GenericMulSender {
+            publicrandomvec,
+            ote,
         })
     }
 
@@ -104,29 +190,27 @@ impl MulSender {
         recv: &mut T,
     ) -> Result<MulSenderData, MPECDSAError> {
         let transposed_seed = self.ote.extend(
-            input_count * (SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS) + ENCODING_EXTRA_BITS,
+            input_count * (SCALAR_BITS + ENCODING_PER_ELEMENT_BITS) + ENCODING_EXTRA_BITS,
             ro,
             recv,
         )?;
 
         //finally, collate the output
         let mut transposed_seed_fragments: Vec<
-            [u8; HASH_SIZE * (SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS)],
+            [u8; HASH_SIZE * (SCALAR_BITS + ENCODING_PER_ELEMENT_BITS)],
         > = Vec::with_capacity(input_count);
         for ii in 0..input_count {
-            let mut fragment = [0u8; (SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE];
+            let mut fragment = [0u8; (SCALAR_BITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE];
             fragment.copy_from_slice(
-                &transposed_seed[(ii * (SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE)
-                    ..((ii + 1) * (SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE)],
+                &transposed_seed[(ii * (SCALAR_BITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE)
+                    ..((ii + 1) * (SCALAR_BITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE)],
             );
             transposed_seed_fragments.push(fragment);
         }
         let mut transposed_seed_encoding_fragment = [0u8; ENCODING_EXTRA_BITS * HASH_SIZE];
         transposed_seed_encoding_fragment.copy_from_slice(
-            &transposed_seed[(input_count
-                * (SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS)
-                * HASH_SIZE)
-                ..(input_count * (SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE
+            &transposed_seed[(input_count * (SCALAR_BITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE)
+                ..(input_count * (SCALAR_BITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE
                     + ENCODING_EXTRA_BITS * HASH_SIZE)],
         );
 
@@ -135,23 +219,14 @@ impl MulSender {
 
     pub fn mul_transfer<T: Write>(
         &self,
-        inputs_alpha: &[&SecpOrd],
-        transposed_seed_fragment: &[&[u8; (SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS)
-               * HASH_SIZE]],
+        inputs_alpha: &[&C::Scalar],
+        transposed_seed_fragment: &[&[u8; (SCALAR_BITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE]],
         transposed_seed_encoding_fragment: &[u8; ENCODING_EXTRA_BITS * HASH_SIZE],
         ro: &DyadicROTagger,
         rng: &mut dyn Rng,
         send: &mut T,
-    ) -> Result<Vec<SecpOrd>, MPECDSAError> {
-        let gadget_table = match SecpOrd::NBITS {
-            256 => &precomp::GADGET_TABLE_256,
-            _ => {
-                return Err(MPECDSAError::General(GeneralError::new(&format!(
-                    "No gadget table defined for {} bit numbers",
-                    SecpOrd::NBITS
-                ))));
-            }
-        };
+    ) -> Result<Vec<C::Scalar>, MPECDSAError> {
+        let gadget_table = C::gadget_table();
 
         let mut results = Vec::with_capacity(transposed_seed_fragment.len());
         let mut transposed_seeds = Vec::with_capacity(transposed_seed_fragment.len());
@@ -159,16 +234,15 @@ impl MulSender {
 
         for kk in 0..transposed_seed_fragment.len() {
             let mut transposed_seed =
-                [0u8; (SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS + ENCODING_EXTRA_BITS)
-                    * HASH_SIZE];
-            transposed_seed[0..((SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE)]
+                [0u8; (SCALAR_BITS + ENCODING_PER_ELEMENT_BITS + ENCODING_EXTRA_BITS) * HASH_SIZE];
+            transposed_seed[0..((SCALAR_BITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE)]
                 .copy_from_slice(transposed_seed_fragment[kk]);
-            transposed_seed[((SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE)..]
+            transposed_seed[((SCALAR_BITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE)..]
                 .copy_from_slice(transposed_seed_encoding_fragment);
 
             transposed_seeds.push(transposed_seed);
 
-            input_lengths.push(SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS + ENCODING_EXTRA_BITS);
+            input_lengths.push(SCALAR_BITS + ENCODING_PER_ELEMENT_BITS + ENCODING_EXTRA_BITS);
         }
 
         let vals0 = self.ote.transfer(
@@ -184,14 +258,14 @@ impl MulSender {
         )?;
 
         for kk in 0..transposed_seed_fragment.len() {
-            let mut result = SecpOrd::ZERO;
+            let mut result = C::Scalar::ZERO;
 
-            for ii in 0..((SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS) + ENCODING_EXTRA_BITS) {
+            for ii in 0..((SCALAR_BITS + ENCODING_PER_ELEMENT_BITS) + ENCODING_EXTRA_BITS) {
                 // primary value
-                let offset = if ii < SecpOrd::NBITS {
-                    &gadget_table[SecpOrd::NBITS - (ii / 8) * 8 - 8 + (ii % 8)]
+                let offset = if ii < SCALAR_BITS {
+                    &gadget_table[SCALAR_BITS - (ii / 8) * 8 - 8 + (ii % 8)]
                 } else {
-                    &self.publicrandomvec[(ii / 8) * 8 - SecpOrd::NBITS + ii % 8]
+                    &self.publicrandomvec[(ii / 8) * 8 - SCALAR_BITS + ii % 8]
                 };
                 result = result.add(&vals0[kk][ii].mul(offset));
             }
@@ -207,7 +281,7 @@ impl MulSender {
         ro: &DyadicROTagger,
         rng: &mut dyn Rng,
         recv: &mut T,
-    ) -> Result<RmulSenderData, MPECDSAError> {
+    ) -> Result<RmulSenderData<C::Scalar>, MPECDSAError> {
         let transposed_seed = self.ote.extend(
             input_count * RAND_ENCODING_PER_ELEMENT_BITS + RAND_ENCODING_EXTRA_BITS,
             ro,
@@ -234,7 +308,7 @@ impl MulSender {
 
         let mut inputs_alpha = Vec::with_capacity(transposed_seed_fragments.len());
         for _ in 0..transposed_seed_fragments.len() {
-            inputs_alpha.push(SecpOrd::rand(rng));
+            inputs_alpha.push(C::Scalar::rand(rng));
         }
 
         Ok((
@@ -246,13 +320,13 @@ impl MulSender {
 
     pub fn rmul_transfer<T: Write>(
         &self,
-        inputs_alpha: &[&SecpOrd],
+        inputs_alpha: &[&C::Scalar],
         transposed_seed_fragment: &[&[u8; RAND_ENCODING_PER_ELEMENT_BITS * HASH_SIZE]],
         transposed_seed_encoding_fragment: &[u8; RAND_ENCODING_EXTRA_BITS * HASH_SIZE],
         ro: &DyadicROTagger,
         rng: &mut dyn Rng,
         send: &mut T,
-    ) -> Result<Vec<SecpOrd>, MPECDSAError> {
+    ) -> Result<Vec<C::Scalar>, MPECDSAError> {
         let mut results = Vec::with_capacity(transposed_seed_fragment.len());
         let mut transposed_seeds = Vec::with_capacity(transposed_seed_fragment.len());
         let mut input_lengths = Vec::with_capacity(transposed_seed_fragment.len());
@@ -283,7 +357,7 @@ impl MulSender {
         )?;
 
         for kk in 0..transposed_seed_fragment.len() {
-            let mut result = SecpOrd::ZERO;
+            let mut result = C::Scalar::ZERO;
 
             for ii in 0..(RAND_ENCODING_PER_ELEMENT_BITS + RAND_ENCODING_EXTRA_BITS) {
                 // primary value
@@ -298,37 +372,48 @@ impl MulSender {
     }
 }
 
-impl MulRecver {
+impl<C: Curve> GenericMulRecver<C> {
     pub fn new<T1: Read, T2: Write>(
         ro: &DyadicROTagger,
         rng: &mut dyn Rng,
         recv: &mut T1,
         send: &mut T2,
-    ) -> Result<MulRecver, MPECDSAError> {
+    ) -> Result<GenericMulRecver<C>, MPECDSAError> {
         //ROT sender goes first, so we let the OTExt recver choose the public random vector to reduce rounds.
-        let total_bits = cmp::max(
-            SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS + ENCODING_EXTRA_BITS,
-            RAND_ENCODING_PER_ELEMENT_BITS + RAND_ENCODING_EXTRA_BITS,
-        );
-        let mut publicrandomvec = vec![SecpOrd::ZERO; total_bits];
-        let mut raw_nonce = [0u8; SecpOrd::NBYTES];
-        let mut prv_element = [0u8; SecpOrd::NBYTES];
-        let mut nonce = SecpOrd::rand(rng);
+        let total_bits = publicrandomvec_len();
+        let mut publicrandomvec = vec![C::Scalar::ZERO; total_bits];
+        let mut raw_nonce = [0u8; SCALAR_SIZE];
+        let mut prv_element = [0u8; SCALAR_SIZE];
+        let mut nonce = C::Scalar::rand(rng);
         nonce.to_bytes(&mut raw_nonce);
-        send.write(&raw_nonce)?;
+        send.write_all(&raw_nonce)?;
         send.flush()?;
         for ii in 0..total_bits {
-            nonce = nonce.add(&SecpOrd::ONE);
+            nonce = nonce.add(&C::Scalar::ONE);
             nonce.to_bytes(&mut raw_nonce);
             hash(&mut prv_element, &raw_nonce);
-            publicrandomvec[ii] = SecpOrd::from_bytes(&prv_element);
+            publicrandomvec[ii] = scalar_from_hash::<C::Scalar>(&prv_element);
         }
 
         let ote = OTERecver::new(ro, rng, recv, send)?;
 
-        Ok(MulRecver {
-            publicrandomvec: publicrandomvec,
-            ote: ote,
+        Ok(GenericMulRecver {
+            publicrandomvec,
+            ote,
+        })
+    }
+
+    pub fn write_to<T: Write>(&self, send: &mut T) -> Result<(), MPECDSAError> {
+        write_publicrandomvec(&self.publicrandomvec, send)?;
+        self.ote.write_to(send)
+    }
+
+    pub fn read_from<T: Read>(recv: &mut T) -> Result<GenericMulRecver<C>, MPECDSAError> {
+        let publicrandomvec = read_publicrandomvec(recv)?;
+        let ote = OTERecver::read_from(recv)?;
+        Ok(GenericMulRecver {
+            publicrandomvec,
+            ote,
         })
     }
 
@@ -338,14 +423,14 @@ impl MulRecver {
 
     pub fn mul_encode_and_extend<T: Write>(
         &self,
-        inputs_beta: &[SecpOrd],
+        inputs_beta: &[C::Scalar],
         ro: &DyadicROTagger,
         rng: &mut dyn Rng,
         send: &mut T,
     ) -> Result<MulRecverData, MPECDSAError> {
         // Encode phase
         let mut encoding_private_bits = [false; ENCODING_EXTRA_BITS];
-        let mut encoding_private_offset = SecpOrd::ZERO;
+        let mut encoding_private_offset = C::Scalar::ZERO;
         for ii in 0..ENCODING_EXTRA_BITS {
             encoding_private_bits[ii] = (rng.next_u32() % 2) > 0;
             let potential_offset =
@@ -357,7 +442,7 @@ impl MulRecver {
 
         let mut encoding_private_element_bits =
             vec![[false; ENCODING_PER_ELEMENT_BITS]; inputs_beta.len()];
-        let mut encoding_private_element_offsets = vec![SecpOrd::ZERO; inputs_beta.len()];
+        let mut encoding_private_element_offsets = vec![C::Scalar::ZERO; inputs_beta.len()];
         for jj in 0..inputs_beta.len() {
             for ii in 0..ENCODING_PER_ELEMENT_BITS {
                 encoding_private_element_bits[jj][ii] = (rng.next_u32() % 2) > 0;
@@ -369,30 +454,29 @@ impl MulRecver {
             }
         }
 
-        let mut inputs_encoded: Vec<[bool; SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS]> =
+        let mut inputs_encoded: Vec<[bool; SCALAR_BITS + ENCODING_PER_ELEMENT_BITS]> =
             Vec::with_capacity(inputs_beta.len());
         let mut choice_bits: Vec<bool> = Vec::with_capacity(
-            inputs_beta.len() * (SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS)
+            inputs_beta.len() * (SCALAR_BITS + ENCODING_PER_ELEMENT_BITS)
                 + ENCODING_EXTRA_BITS
                 + OT_SEC_PARAM,
         );
         for ii in 0..inputs_beta.len() {
-            inputs_encoded.push([false; SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS]);
+            inputs_encoded.push([false; SCALAR_BITS + ENCODING_PER_ELEMENT_BITS]);
             let beta_aug = inputs_beta[ii]
                 .sub(&encoding_private_offset)
                 .sub(&encoding_private_element_offsets[ii]);
-            for jj in 0..SecpOrd::NBYTES {
-                inputs_encoded[ii][jj * 8 + 0] = beta_aug.bit(SecpOrd::NBITS - ((jj + 1) * 8) + 0);
-                inputs_encoded[ii][jj * 8 + 1] = beta_aug.bit(SecpOrd::NBITS - ((jj + 1) * 8) + 1);
-                inputs_encoded[ii][jj * 8 + 2] = beta_aug.bit(SecpOrd::NBITS - ((jj + 1) * 8) + 2);
-                inputs_encoded[ii][jj * 8 + 3] = beta_aug.bit(SecpOrd::NBITS - ((jj + 1) * 8) + 3);
-                inputs_encoded[ii][jj * 8 + 4] = beta_aug.bit(SecpOrd::NBITS - ((jj + 1) * 8) + 4);
-                inputs_encoded[ii][jj * 8 + 5] = beta_aug.bit(SecpOrd::NBITS - ((jj + 1) * 8) + 5);
-                inputs_encoded[ii][jj * 8 + 6] = beta_aug.bit(SecpOrd::NBITS - ((jj + 1) * 8) + 6);
-                inputs_encoded[ii][jj * 8 + 7] = beta_aug.bit(SecpOrd::NBITS - ((jj + 1) * 8) + 7);
+            for jj in 0..SCALAR_SIZE {
+                inputs_encoded[ii][jj * 8 + 0] = beta_aug.bit(SCALAR_BITS - ((jj + 1) * 8) + 0);
+                inputs_encoded[ii][jj * 8 + 1] = beta_aug.bit(SCALAR_BITS - ((jj + 1) * 8) + 1);
+                inputs_encoded[ii][jj * 8 + 2] = beta_aug.bit(SCALAR_BITS - ((jj + 1) * 8) + 2);
+                inputs_encoded[ii][jj * 8 + 3] = beta_aug.bit(SCALAR_BITS - ((jj + 1) * 8) + 3);
+                inputs_encoded[ii][jj * 8 + 4] = beta_aug.bit(SCALAR_BITS - ((jj + 1) * 8) + 4);
+                inputs_encoded[ii][jj * 8 + 5] = beta_aug.bit(SCALAR_BITS - ((jj + 1) * 8) + 5);
+                inputs_encoded[ii][jj * 8 + 6] = beta_aug.bit(SCALAR_BITS - ((jj + 1) * 8) + 6);
+                inputs_encoded[ii][jj * 8 + 7] = beta_aug.bit(SCALAR_BITS - ((jj + 1) * 8) + 7);
             }
-            inputs_encoded[ii][SecpOrd::NBITS..]
-                .copy_from_slice(&encoding_private_element_bits[ii]);
+            inputs_encoded[ii][SCALAR_BITS..].copy_from_slice(&encoding_private_element_bits[ii]);
             choice_bits.extend_from_slice(&inputs_encoded[ii]);
         }
         choice_bits.extend_from_slice(&encoding_private_bits);
@@ -401,22 +485,22 @@ impl MulRecver {
 
         //finally, collate the output
         let mut transposed_seed_fragments: Vec<
-            [u8; (SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE],
+            [u8; (SCALAR_BITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE],
         > = Vec::with_capacity(inputs_beta.len());
         for ii in 0..inputs_beta.len() {
-            let mut fragment = [0u8; (SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE];
+            let mut fragment = [0u8; (SCALAR_BITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE];
             fragment.copy_from_slice(
-                &transposed_seed0[(ii * (SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE)
-                    ..((ii + 1) * (SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE)],
+                &transposed_seed0[(ii * (SCALAR_BITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE)
+                    ..((ii + 1) * (SCALAR_BITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE)],
             );
             transposed_seed_fragments.push(fragment);
         }
         let mut transposed_seed_encoding_fragment = [0u8; ENCODING_EXTRA_BITS * HASH_SIZE];
         transposed_seed_encoding_fragment.copy_from_slice(
             &transposed_seed0[(inputs_beta.len()
-                * (SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS)
+                * (SCALAR_BITS + ENCODING_PER_ELEMENT_BITS)
                 * HASH_SIZE)
-                ..(inputs_beta.len() * (SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE
+                ..(inputs_beta.len() * (SCALAR_BITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE
                     + ENCODING_EXTRA_BITS * HASH_SIZE)],
         );
 
@@ -430,23 +514,14 @@ impl MulRecver {
 
     pub fn mul_transfer<T: Read>(
         &self,
-        inputs_beta_encoded: &[&[bool; SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS]],
+        inputs_beta_encoded: &[&[bool; SCALAR_BITS + ENCODING_PER_ELEMENT_BITS]],
         encoding_private_bits: &[bool; ENCODING_EXTRA_BITS],
-        transposed_seed_fragment: &[&[u8; (SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS)
-               * HASH_SIZE]],
+        transposed_seed_fragment: &[&[u8; (SCALAR_BITS + ENCODING_PER_ELEMENT_BITS) * HASH_SIZE]],
         transposed_seed_encoding_fragment: &[u8; ENCODING_EXTRA_BITS * HASH_SIZE],
         ro: &DyadicROTagger,
         recv: &mut T,
-    ) -> Result<Vec<SecpOrd>, MPECDSAError> {
-        let gadget_table = match SecpOrd::NBITS {
-            256 => &precomp::GADGET_TABLE_256,
-            _ => {
-                return Err(MPECDSAError::General(GeneralError::new(&format!(
-                    "No gadget table defined for {} bit numbers",
-                    SecpOrd::NBITS
-                ))));
-            }
-        };
+    ) -> Result<Vec<C::Scalar>, MPECDSAError> {
+        let gadget_table = C::gadget_table();
 
         let mut results = Vec::with_capacity(inputs_beta_encoded.len());
         let mut transposed_seeds = Vec::with_capacity(inputs_beta_encoded.len());
@@ -454,26 +529,25 @@ impl MulRecver {
 
         for kk in 0..inputs_beta_encoded.len() {
             let mut transposed_seed =
-                [0u8; (SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS + ENCODING_EXTRA_BITS)
-                    * HASH_SIZE];
-            transposed_seed[0..(HASH_SIZE * (SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS))]
+                [0u8; (SCALAR_BITS + ENCODING_PER_ELEMENT_BITS + ENCODING_EXTRA_BITS) * HASH_SIZE];
+            transposed_seed[0..(HASH_SIZE * (SCALAR_BITS + ENCODING_PER_ELEMENT_BITS))]
                 .copy_from_slice(transposed_seed_fragment[kk]);
-            transposed_seed[(HASH_SIZE * (SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS))
-                ..(SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS + ENCODING_EXTRA_BITS) * HASH_SIZE]
+            transposed_seed[(HASH_SIZE * (SCALAR_BITS + ENCODING_PER_ELEMENT_BITS))
+                ..(SCALAR_BITS + ENCODING_PER_ELEMENT_BITS + ENCODING_EXTRA_BITS) * HASH_SIZE]
                 .copy_from_slice(transposed_seed_encoding_fragment);
             let mut choice_bits =
-                [false; SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS + ENCODING_EXTRA_BITS];
-            choice_bits[0..(SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS)]
+                [false; SCALAR_BITS + ENCODING_PER_ELEMENT_BITS + ENCODING_EXTRA_BITS];
+            choice_bits[0..(SCALAR_BITS + ENCODING_PER_ELEMENT_BITS)]
                 .copy_from_slice(inputs_beta_encoded[kk]);
-            choice_bits[(SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS)
-                ..(SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS) + ENCODING_EXTRA_BITS]
+            choice_bits[(SCALAR_BITS + ENCODING_PER_ELEMENT_BITS)
+                ..(SCALAR_BITS + ENCODING_PER_ELEMENT_BITS) + ENCODING_EXTRA_BITS]
                 .copy_from_slice(encoding_private_bits);
 
             transposed_seeds.push(transposed_seed);
             choice_bitss.push(choice_bits);
         }
 
-        let vals = self.ote.transfer(
+        let vals = self.ote.transfer::<C::Scalar, _>(
             &choice_bitss
                 .iter()
                 .map(|x| x.as_slice())
@@ -487,12 +561,12 @@ impl MulRecver {
         )?;
 
         for kk in 0..inputs_beta_encoded.len() {
-            let mut result = SecpOrd::ZERO;
-            for ii in 0..(SecpOrd::NBITS + ENCODING_PER_ELEMENT_BITS + ENCODING_EXTRA_BITS) {
-                let offset = if ii < SecpOrd::NBITS {
-                    &gadget_table[SecpOrd::NBITS - (ii / 8) * 8 - 8 + (ii % 8)]
+            let mut result = C::Scalar::ZERO;
+            for ii in 0..(SCALAR_BITS + ENCODING_PER_ELEMENT_BITS + ENCODING_EXTRA_BITS) {
+                let offset = if ii < SCALAR_BITS {
+                    &gadget_table[SCALAR_BITS - (ii / 8) * 8 - 8 + (ii % 8)]
                 } else {
-                    &self.publicrandomvec[(ii / 8) * 8 - SecpOrd::NBITS + ii % 8]
+                    &self.publicrandomvec[(ii / 8) * 8 - SCALAR_BITS + ii % 8]
                 };
                 result = result.add(&vals[kk][ii].mul(offset));
             }
@@ -508,10 +582,10 @@ impl MulRecver {
         ro: &DyadicROTagger,
         rng: &mut dyn Rng,
         send: &mut T,
-    ) -> Result<RmulRecverData, MPECDSAError> {
+    ) -> Result<RmulRecverData<C::Scalar>, MPECDSAError> {
         // Encode phase
         let mut encoding_private_bits = [false; RAND_ENCODING_EXTRA_BITS];
-        let mut encoding_private_joint = SecpOrd::ZERO;
+        let mut encoding_private_joint = C::Scalar::ZERO;
         for ii in 0..RAND_ENCODING_EXTRA_BITS {
             encoding_private_bits[ii] = (rng.next_u32() % 2) > 0;
             let potential_offset = encoding_private_joint
@@ -523,7 +597,7 @@ impl MulRecver {
 
         let mut encoding_private_random_bits =
             vec![[false; RAND_ENCODING_PER_ELEMENT_BITS]; input_count];
-        let mut encoding_private_random = vec![SecpOrd::ZERO; input_count];
+        let mut encoding_private_random = vec![C::Scalar::ZERO; input_count];
         for jj in 0..input_count {
             for ii in 0..RAND_ENCODING_PER_ELEMENT_BITS {
                 encoding_private_random_bits[jj][ii] = (rng.next_u32() % 2) > 0;
@@ -534,7 +608,7 @@ impl MulRecver {
             }
         }
 
-        let mut offsets: Vec<SecpOrd> = Vec::with_capacity(input_count);
+        let mut offsets: Vec<C::Scalar> = Vec::with_capacity(input_count);
         let mut choice_bits: Vec<bool> = Vec::with_capacity(
             input_count * RAND_ENCODING_PER_ELEMENT_BITS + RAND_ENCODING_EXTRA_BITS + OT_SEC_PARAM,
         );
@@ -586,7 +660,7 @@ impl MulRecver {
         transposed_seed_encoding_fragment: &[u8; RAND_ENCODING_EXTRA_BITS * HASH_SIZE],
         ro: &DyadicROTagger,
         recv: &mut T,
-    ) -> Result<Vec<SecpOrd>, MPECDSAError> {
+    ) -> Result<Vec<C::Scalar>, MPECDSAError> {
         let mut results = Vec::with_capacity(inputs_beta_encoded.len());
         let mut transposed_seeds = Vec::with_capacity(inputs_beta_encoded.len());
         let mut choice_bitss = Vec::with_capacity(inputs_beta_encoded.len());
@@ -610,7 +684,7 @@ impl MulRecver {
             choice_bitss.push(choice_bits);
         }
 
-        let vals = self.ote.transfer(
+        let vals = self.ote.transfer::<C::Scalar, _>(
             &choice_bitss
                 .iter()
                 .map(|x| x.as_slice())
@@ -624,7 +698,7 @@ impl MulRecver {
         )?;
 
         for kk in 0..inputs_beta_encoded.len() {
-            let mut result = SecpOrd::ZERO;
+            let mut result = C::Scalar::ZERO;
             for ii in 0..(RAND_ENCODING_PER_ELEMENT_BITS + RAND_ENCODING_EXTRA_BITS) {
                 let offset = self.publicrandomvec[(ii / 8) * 8 + ii % 8];
                 result = result.add(&vals[kk][ii].mul(&offset));
@@ -12,7 +12,7 @@ use std::result::Result;
 
 use rand::Rng;
 
-use curves::{Ford, SecpOrd};
+use curves::Ford;
 
 use bit_reverse::ParallelReverse;
 
@@ -82,8 +82,8 @@ fn transpose(data: &Vec<u8>, majtilelen: usize) -> Vec<u8> {
 
 //#[derive(Clone)]
 pub struct OTESender {
-    correlation: [bool; SecpOrd::NBITS],
-    compressed_correlation: [u8; SecpOrd::NBYTES],
+    correlation: [bool; SCALAR_BITS],
+    compressed_correlation: [u8; SCALAR_SIZE],
     seeds: Vec<[u8; HASH_SIZE]>,
 }
 
@@ -106,13 +106,13 @@ impl OTESender {
         recv: &mut T1,
         send: &mut T2,
     ) -> Result<OTESender, MPECDSAError> {
-        let mut correlation = [false; SecpOrd::NBITS];
-        for ii in 0..SecpOrd::NBITS {
+        let mut correlation = [false; SCALAR_BITS];
+        for ii in 0..SCALAR_BITS {
             correlation[ii] = (rng.next_u32() % 2) > 0;
         }
 
-        let mut compressed_correlation = [0u8; SecpOrd::NBYTES];
-        for ii in 0..SecpOrd::NBYTES {
+        let mut compressed_correlation = [0u8; SCALAR_SIZE];
+        for ii in 0..SCALAR_SIZE {
             compressed_correlation[ii] = ((correlation[ii * 8 + 0] as u8) << 0)
                 | ((correlation[ii * 8 + 1] as u8) << 1)
                 | ((correlation[ii * 8 + 2] as u8) << 2)
@@ -126,19 +126,57 @@ impl OTESender {
         let seeds = rot_recv_batch(&correlation, &ro, rng, recv, send)?;
 
         Ok(OTESender {
-            correlation: correlation,
+            correlation,
             compressed_correlation,
-            seeds: seeds,
+            seeds,
         })
     }
 
+    // the correlation is stored in compressed form, followed by the base OT seeds
+    pub fn write_to<T: Write>(&self, send: &mut T) -> Result<(), MPECDSAError> {
+        send.write_all(&self.compressed_correlation)?;
+        for ii in 0..SCALAR_BITS {
+            send.write_all(&self.seeds[ii])?;
+        }
+        Ok(())
+    }
+
+    pub fn read_from<T: Read>(recv: &mut T) -> Result<OTESender, MPECDSAError> {
+        let mut compressed_correlation = [0u8; SCALAR_SIZE];
+        recv.read_exact(&mut compressed_correlation)?;
+        let mut correlation = [false; SCALAR_BITS];
+        for ii in 0..SCALAR_BITS {
+            correlation[ii] = ((compressed_correlation[ii / 8] >> (ii % 8)) & 1) > 0;
+        }
+
+        let mut seeds = vec![[0u8; HASH_SIZE]; SCALAR_BITS];
+        for ii in 0..SCALAR_BITS {
+            recv.read_exact(&mut seeds[ii])?;
+        }
+
+        Ok(OTESender {
+            correlation,
+            compressed_correlation,
+            seeds,
+        })
+    }
+
+    // destroys the correlation and seeds, for when the sender is retired
+    pub fn wipe(&mut self) {
+        wipe(&mut self.correlation, [false; SCALAR_BITS]);
+        wipe(&mut self.compressed_correlation, [0u8; SCALAR_SIZE]);
+        for seed in self.seeds.iter_mut() {
+            wipe(seed, [0u8; HASH_SIZE]);
+        }
+    }
+
     pub fn apply_refresh(&mut self, rand: &[u8], ro: &DyadicROTagger) -> Result<(), MPECDSAError> {
         if rand.len() < HASH_SIZE {
             return Err(MPECDSAError::General(GeneralError::new(
                 "Insufficiently many random bits for safe refresh",
             )));
         }
-        let mut expanded_rand = vec![0u8; 2 * HASH_SIZE * SecpOrd::NBITS + SecpOrd::NBITS / 8];
+        let mut expanded_rand = vec![0u8; 2 * HASH_SIZE * SCALAR_BITS + SCALAR_BITS / 8];
         let mut source_with_tag = vec![0u8; rand.len() + RO_TAG_SIZE];
         let mut hashout = [0u8; HASH_SIZE];
         source_with_tag[RO_TAG_SIZE..].copy_from_slice(&rand[..]);
@@ -150,18 +188,18 @@ impl OTESender {
             expanded_rand[offset..(offset + remain)].copy_from_slice(&hashout[0..remain]);
         }
 
-        for ii in 0..SecpOrd::NBITS {
+        for ii in 0..SCALAR_BITS {
             self.correlation[ii] ^=
-                ((expanded_rand[2 * HASH_SIZE * SecpOrd::NBITS + ii / 8] >> (ii % 8)) & 1) > 0;
+                ((expanded_rand[2 * HASH_SIZE * SCALAR_BITS + ii / 8] >> (ii % 8)) & 1) > 0;
             for jj in 0..HASH_SIZE {
                 self.seeds[ii][jj] ^=
-                    expanded_rand[((self.correlation[ii] as usize) * HASH_SIZE * SecpOrd::NBITS)
+                    expanded_rand[((self.correlation[ii] as usize) * HASH_SIZE * SCALAR_BITS)
                         + ii * HASH_SIZE
                         + jj];
             }
         }
-        for ii in 0..SecpOrd::NBYTES {
-            self.compressed_correlation[ii] ^= expanded_rand[2 * HASH_SIZE * SecpOrd::NBITS + ii];
+        for ii in 0..SCALAR_SIZE {
+            self.compressed_correlation[ii] ^= expanded_rand[2 * HASH_SIZE * SCALAR_BITS + ii];
         }
         return Ok(());
     }
@@ -173,17 +211,17 @@ impl OTESender {
         recv: &mut T,
     ) -> Result<Vec<u8>, MPECDSAError> {
         let prgoutputlen = input_len + OT_SEC_PARAM;
-        let mut expanded_seeds: Vec<u8> = Vec::with_capacity(SecpOrd::NBYTES * prgoutputlen);
+        let mut expanded_seeds: Vec<u8> = Vec::with_capacity(SCALAR_SIZE * prgoutputlen);
         let prgiterations = ((prgoutputlen / 8) + HASH_SIZE - 1) / HASH_SIZE;
 
-        debug_assert!((SecpOrd::NBYTES * prgoutputlen) % HASH_SIZE == 0);
+        debug_assert!((SCALAR_SIZE * prgoutputlen) % HASH_SIZE == 0);
 
         let mut tagrange =
-            ro.allocate_dyadic_range((SecpOrd::NBITS * prgiterations + prgoutputlen + 1) as u64);
+            ro.allocate_dyadic_range((SCALAR_BITS * prgiterations + prgoutputlen + 1) as u64);
 
-        let mut prgoutput = vec![0u8; HASH_SIZE * prgiterations * SecpOrd::NBITS];
-        let mut hasherinput = vec![0u8; HASH_BLOCK_SIZE * prgiterations * SecpOrd::NBITS];
-        for ii in 0..SecpOrd::NBITS {
+        let mut prgoutput = vec![0u8; HASH_SIZE * prgiterations * SCALAR_BITS];
+        let mut hasherinput = vec![0u8; HASH_BLOCK_SIZE * prgiterations * SCALAR_BITS];
+        for ii in 0..SCALAR_BITS {
             for jj in 0..prgiterations {
                 // Map for (ii,jj): [ 20B: RO index | 32B: seed[ii] ], total: 52B
                 hasherinput[((ii * prgiterations + jj) * HASH_BLOCK_SIZE)
@@ -195,26 +233,26 @@ impl OTESender {
             }
         }
 
-        hash_multi(&hasherinput, &mut prgoutput, SecpOrd::NBITS * prgiterations);
+        hash_multi(&hasherinput, &mut prgoutput, SCALAR_BITS * prgiterations);
 
-        for ii in 0..SecpOrd::NBITS {
+        for ii in 0..SCALAR_BITS {
             expanded_seeds.extend_from_slice(
                 &prgoutput[(ii * prgiterations * HASH_SIZE)
                     ..(ii * prgiterations * HASH_SIZE + prgoutputlen / 8)],
             );
         }
 
-        let mut seeds_combined = vec![0u8; SecpOrd::NBYTES * prgoutputlen + RO_TAG_SIZE];
-        let mut sampled_bits = [0u8; SecpOrd::NBYTES];
-        let mut sampled_seeds = [0u8; SecpOrd::NBYTES];
-        recv.read_exact(&mut seeds_combined[0..SecpOrd::NBYTES * prgoutputlen])?;
+        let mut seeds_combined = vec![0u8; SCALAR_SIZE * prgoutputlen + RO_TAG_SIZE];
+        let mut sampled_bits = [0u8; SCALAR_SIZE];
+        let mut sampled_seeds = [0u8; SCALAR_SIZE];
+        recv.read_exact(&mut seeds_combined[0..SCALAR_SIZE * prgoutputlen])?;
         recv.read_exact(&mut sampled_bits)?;
         recv.read_exact(&mut sampled_seeds)?;
 
         let mut random_samples = vec![0u8; HASH_SIZE * prgoutputlen];
         let mut seeds_shortened = [0u8; HASH_SIZE];
         let mut hash_input = vec![0u8; HASH_BLOCK_SIZE * prgoutputlen];
-        seeds_combined[(SecpOrd::NBYTES * prgoutputlen)..].copy_from_slice(&tagrange.next()?[..]);
+        seeds_combined[(SCALAR_SIZE * prgoutputlen)..].copy_from_slice(&tagrange.next()?[..]);
         hash(&mut seeds_shortened, &seeds_combined);
         for ii in 0..prgoutputlen {
             // Map for ii: [ 20B: RO tag | 32B: seeds_shortened ], total: 52B
@@ -226,8 +264,8 @@ impl OTESender {
         }
         hash_multi(&hash_input, &mut random_samples, prgoutputlen);
 
-        let mut check_vec: Vec<u8> = Vec::with_capacity(SecpOrd::NBITS * prgoutputlen / 8);
-        for ii in 0..SecpOrd::NBITS {
+        let mut check_vec: Vec<u8> = Vec::with_capacity(SCALAR_BITS * prgoutputlen / 8);
+        for ii in 0..SCALAR_BITS {
             for jj in 0..(prgoutputlen / 8) {
                 check_vec.push(
                     expanded_seeds[ii * (prgoutputlen / 8) + jj]
@@ -239,37 +277,39 @@ impl OTESender {
 
         let transposed_check_vec = transpose(&check_vec, prgoutputlen / 8);
 
-        let mut sampled_check = [0u8; SecpOrd::NBYTES];
+        let mut sampled_check = [0u8; SCALAR_SIZE];
         for ii in 0..prgoutputlen {
-            for jj in 0..SecpOrd::NBYTES {
-                sampled_check[jj] ^= transposed_check_vec[ii * SecpOrd::NBYTES + jj]
-                    & random_samples[ii * SecpOrd::NBYTES + jj];
+            for jj in 0..SCALAR_SIZE {
+                sampled_check[jj] ^= transposed_check_vec[ii * SCALAR_SIZE + jj]
+                    & random_samples[ii * SCALAR_SIZE + jj];
             }
         }
 
-        let mut rhs = [0u8; SecpOrd::NBYTES];
-        for ii in 0..SecpOrd::NBYTES {
+        let mut rhs = [0u8; SCALAR_SIZE];
+        for ii in 0..SCALAR_SIZE {
             rhs[ii] = sampled_seeds[ii] ^ (self.compressed_correlation[ii] & sampled_bits[ii]);
         }
 
         if vec_eq(&sampled_check, &rhs) {
             Ok(transposed_check_vec)
         } else {
-            Err(MPECDSAError::Proof(ProofError::new(
+            Err(MPECDSAError::Blame(BlameError::new(
+                ro.get_supercounterparty(),
+                ProtocolPhase::OTExtension,
                 "Verification Failed for OTE (receiver cheated)",
             )))
         }
     }
 
-    pub fn transfer<T: Write>(
+    pub fn transfer<S: Ford, T: Write>(
         &self,
         input_len: &[usize],
-        input_correlation: &[&SecpOrd],
+        input_correlation: &[&S],
         transposed_seed: &[&[u8]],
         ro: &DyadicROTagger,
         rng: &mut dyn Rng,
         send: &mut T,
-    ) -> Result<Vec<Vec<SecpOrd>>, MPECDSAError> {
+    ) -> Result<Vec<Vec<S>>, MPECDSAError> {
         let input_count: usize = input_len.len();
         let total_input_len: usize = input_len.iter().sum();
         let mut tagrange =
@@ -277,14 +317,14 @@ impl OTESender {
 
         let mut hasherinput = vec![0u8; 2 * HASH_BLOCK_SIZE * total_input_len];
         let mut hashoutput = vec![0u8; 2 * HASH_SIZE * total_input_len];
-        let mut vals0: Vec<Vec<SecpOrd>> = Vec::with_capacity(input_count);
+        let mut vals0: Vec<Vec<S>> = Vec::with_capacity(input_count);
         let mut check_hashoutput = vec![0u8; 2 * HASH_SIZE * total_input_len];
-        let mut check_vals0: Vec<Vec<SecpOrd>> = Vec::with_capacity(input_count);
-        let mut check_alpha = vec![SecpOrd::ZERO; input_count];
+        let mut check_vals0: Vec<Vec<S>> = Vec::with_capacity(input_count);
+        let mut check_alpha = vec![S::ZERO; input_count];
 
         let mut input_len_offset = 0;
         for kk in 0..input_count {
-            check_alpha[kk] = SecpOrd::rand(rng);
+            check_alpha[kk] = S::rand(rng);
             let localhasherinput = &mut hasherinput[(input_len_offset * HASH_BLOCK_SIZE)
                 ..((input_len_offset + 2 * input_len[kk]) * HASH_BLOCK_SIZE)];
 
@@ -319,33 +359,32 @@ impl OTESender {
 
         hash_multi(&hasherinput, &mut hashoutput, 2 * total_input_len);
 
-        let mut correction_vec_raw = vec![0u8; total_input_len * SecpOrd::NBYTES + RO_TAG_SIZE];
+        let mut correction_vec_raw = vec![0u8; total_input_len * SCALAR_SIZE + RO_TAG_SIZE];
         let mut vals0_offset = 0;
         for kk in 0..input_count {
-            let mut localvals0 = vec![SecpOrd::ZERO; input_len[kk]];
+            let mut localvals0 = vec![S::ZERO; input_len[kk]];
             let localhashoutput = &hashoutput
                 [(2 * vals0_offset * HASH_SIZE)..(2 * (vals0_offset + input_len[kk]) * HASH_SIZE)];
-            let localcorrectionvec = &mut correction_vec_raw[(vals0_offset * SecpOrd::NBYTES)
-                ..((vals0_offset + input_len[kk]) * SecpOrd::NBYTES)];
+            let localcorrectionvec = &mut correction_vec_raw
+                [(vals0_offset * SCALAR_SIZE)..((vals0_offset + input_len[kk]) * SCALAR_SIZE)];
             for ii in 0..input_len[kk] {
                 // primary value; with space at the end for the RO tag (this is more convenient than putting it at the start)
-                localvals0[ii] = SecpOrd::from_bytes(
+                localvals0[ii] = scalar_from_hash::<S>(
                     &localhashoutput[(2 * ii * HASH_SIZE)..((2 * ii + 1) * HASH_SIZE)],
                 );
-                let val1 = SecpOrd::from_bytes(
+                let val1 = scalar_from_hash::<S>(
                     &localhashoutput[((2 * ii + 1) * HASH_SIZE)..((2 * ii + 2) * HASH_SIZE)],
                 );
                 val1.sub(&localvals0[ii])
                     .add(input_correlation[kk])
                     .to_bytes(
-                        &mut localcorrectionvec
-                            [(ii * SecpOrd::NBYTES)..((ii + 1) * SecpOrd::NBYTES)],
+                        &mut localcorrectionvec[(ii * SCALAR_SIZE)..((ii + 1) * SCALAR_SIZE)],
                     );
             }
             vals0.push(localvals0);
             vals0_offset = vals0_offset + input_len[kk];
         }
-        send.write(&correction_vec_raw[0..total_input_len * SecpOrd::NBYTES])?;
+        send.write_all(&correction_vec_raw[0..total_input_len * SCALAR_SIZE])?;
 
         input_len_offset = 0;
         for kk in 0..input_count {
@@ -367,55 +406,51 @@ impl OTESender {
 
         hash_multi(&hasherinput, &mut check_hashoutput, 2 * total_input_len);
 
-        let mut check_correction_vec_raw =
-            vec![0u8; total_input_len * SecpOrd::NBYTES + RO_TAG_SIZE];
+        let mut check_correction_vec_raw = vec![0u8; total_input_len * SCALAR_SIZE + RO_TAG_SIZE];
         vals0_offset = 0;
         for kk in 0..input_count {
-            let mut localcheckvals0 = vec![SecpOrd::ZERO; input_len[kk]];
+            let mut localcheckvals0 = vec![S::ZERO; input_len[kk]];
             let localcheckhashoutput = &check_hashoutput
                 [(2 * vals0_offset * HASH_SIZE)..(2 * (vals0_offset + input_len[kk]) * HASH_SIZE)];
-            let localcheckcorrectionvec = &mut check_correction_vec_raw[(vals0_offset
-                * SecpOrd::NBYTES)
-                ..((vals0_offset + input_len[kk]) * SecpOrd::NBYTES)];
+            let localcheckcorrectionvec = &mut check_correction_vec_raw
+                [(vals0_offset * SCALAR_SIZE)..((vals0_offset + input_len[kk]) * SCALAR_SIZE)];
             for ii in 0..input_len[kk] {
                 // check value; with space at the end for the RO tag (this is more convenient than putting it at the start)
-                localcheckvals0[ii] = SecpOrd::from_bytes(
+                localcheckvals0[ii] = scalar_from_hash::<S>(
                     &localcheckhashoutput[(2 * ii * HASH_SIZE)..((2 * ii + 1) * HASH_SIZE)],
                 );
-                let check_val1 = SecpOrd::from_bytes(
+                let check_val1 = scalar_from_hash::<S>(
                     &localcheckhashoutput[((2 * ii + 1) * HASH_SIZE)..((2 * ii + 2) * HASH_SIZE)],
                 );
                 check_val1
                     .sub(&localcheckvals0[ii])
                     .add(&check_alpha[kk])
                     .to_bytes(
-                        &mut localcheckcorrectionvec
-                            [(ii * SecpOrd::NBYTES)..((ii + 1) * SecpOrd::NBYTES)],
+                        &mut localcheckcorrectionvec[(ii * SCALAR_SIZE)..((ii + 1) * SCALAR_SIZE)],
                     );
             }
             check_vals0.push(localcheckvals0);
             vals0_offset = vals0_offset + input_len[kk];
         }
-        send.write(&check_correction_vec_raw[0..total_input_len * SecpOrd::NBYTES])?;
+        send.write_all(&check_correction_vec_raw[0..total_input_len * SCALAR_SIZE])?;
 
         let mut coef_seed = [0u8; HASH_SIZE + RO_TAG_SIZE];
         let mut coef_raw = [0u8; HASH_SIZE];
-        let mut coefs = vec![SecpOrd::ZERO; input_count];
-        correction_vec_raw[total_input_len * SecpOrd::NBYTES..]
-            .copy_from_slice(&tagrange.next()?[..]);
+        let mut coefs = vec![S::ZERO; input_count];
+        correction_vec_raw[total_input_len * SCALAR_SIZE..].copy_from_slice(&tagrange.next()?[..]);
         hash(&mut coef_raw, &correction_vec_raw);
         coef_seed[0..HASH_SIZE].copy_from_slice(&coef_raw);
 
         for kk in 0..input_count {
             coef_seed[HASH_SIZE..].copy_from_slice(&tagrange.next()?[..]);
             hash(&mut coef_raw, &coef_seed);
-            coefs[kk] = SecpOrd::from_bytes(&coef_raw);
+            coefs[kk] = scalar_from_hash::<S>(&coef_raw);
         }
 
         let mut check_coef_seed = [0u8; HASH_SIZE + RO_TAG_SIZE];
         let mut check_coef_raw = [0u8; HASH_SIZE];
-        let mut check_coefs = vec![SecpOrd::ZERO; input_count];
-        check_correction_vec_raw[total_input_len * SecpOrd::NBYTES..]
+        let mut check_coefs = vec![S::ZERO; input_count];
+        check_correction_vec_raw[total_input_len * SCALAR_SIZE..]
             .copy_from_slice(&tagrange.next()?[..]);
         hash(&mut check_coef_raw, &check_correction_vec_raw);
         check_coef_seed[0..HASH_SIZE].copy_from_slice(&check_coef_raw);
@@ -423,10 +458,10 @@ impl OTESender {
         for kk in 0..input_count {
             check_coef_seed[HASH_SIZE..].copy_from_slice(&tagrange.next()?[..]);
             hash(&mut check_coef_raw, &check_coef_seed);
-            check_coefs[kk] = SecpOrd::from_bytes(&check_coef_raw);
+            check_coefs[kk] = scalar_from_hash::<S>(&check_coef_raw);
         }
 
-        let mut check_vec = vec![SecpOrd::ZERO; *input_len.iter().max().unwrap()];
+        let mut check_vec = vec![S::ZERO; *input_len.iter().max().unwrap()];
         for kk in 0..input_count {
             for ii in 0..input_len[kk] {
                 check_vec[ii] = check_vec[ii].add(
@@ -437,23 +472,21 @@ impl OTESender {
             }
         }
 
-        let mut check_vec_raw = vec![0u8; input_len.iter().max().unwrap() * SecpOrd::NBYTES];
+        let mut check_vec_raw = vec![0u8; input_len.iter().max().unwrap() * SCALAR_SIZE];
         for ii in 0..*input_len.iter().max().unwrap() {
             check_vec[ii]
-                .to_bytes(&mut check_vec_raw[(ii * SecpOrd::NBYTES)..((ii + 1) * SecpOrd::NBYTES)]);
+                .to_bytes(&mut check_vec_raw[(ii * SCALAR_SIZE)..((ii + 1) * SCALAR_SIZE)]);
         }
-        send.write(&check_vec_raw)?;
+        send.write_all(&check_vec_raw)?;
 
-        let mut references_raw = vec![0u8; input_count * SecpOrd::NBYTES];
+        let mut references_raw = vec![0u8; input_count * SCALAR_SIZE];
         for kk in 0..input_count {
             let reference = input_correlation[kk]
                 .mul(&coefs[kk])
                 .add(&check_alpha[kk].mul(&check_coefs[kk]));
-            reference.to_bytes(
-                &mut references_raw[(kk * SecpOrd::NBYTES)..((kk + 1) * SecpOrd::NBYTES)],
-            );
+            reference.to_bytes(&mut references_raw[(kk * SCALAR_SIZE)..((kk + 1) * SCALAR_SIZE)]);
         }
-        send.write(&references_raw)?;
+        send.write_all(&references_raw)?;
 
         Ok(vals0)
     }
@@ -466,8 +499,32 @@ impl OTERecver {
         recv: &mut T1,
         send: &mut T2,
     ) -> Result<OTERecver, MPECDSAError> {
-        let seeds = rot_send_batch(SecpOrd::NBITS, &ro, rng, recv, send)?;
-        Ok(OTERecver { seeds: seeds })
+        let seeds = rot_send_batch(SCALAR_BITS, &ro, rng, recv, send)?;
+        Ok(OTERecver { seeds })
+    }
+
+    pub fn write_to<T: Write>(&self, send: &mut T) -> Result<(), MPECDSAError> {
+        for ii in 0..SCALAR_BITS {
+            send.write_all(&self.seeds[ii].0)?;
+            send.write_all(&self.seeds[ii].1)?;
+        }
+        Ok(())
+    }
+
+    pub fn read_from<T: Read>(recv: &mut T) -> Result<OTERecver, MPECDSAError> {
+        let mut seeds = vec![([0u8; HASH_SIZE], [0u8; HASH_SIZE]); SCALAR_BITS];
+        for ii in 0..SCALAR_BITS {
+            recv.read_exact(&mut seeds[ii].0)?;
+            recv.read_exact(&mut seeds[ii].1)?;
+        }
+        Ok(OTERecver { seeds })
+    }
+
+    // destroys the seeds, for when the recver is retired
+    pub fn wipe(&mut self) {
+        for seed in self.seeds.iter_mut() {
+            wipe(seed, ([0u8; HASH_SIZE], [0u8; HASH_SIZE]));
+        }
     }
 
     pub fn apply_refresh(&mut self, rand: &[u8], ro: &DyadicROTagger) -> Result<(), MPECDSAError> {
@@ -476,7 +533,7 @@ impl OTERecver {
                 "Insufficiently many random bits for safe refresh",
             )));
         }
-        let mut expanded_rand = vec![0u8; 2 * HASH_SIZE * SecpOrd::NBITS + SecpOrd::NBITS / 8];
+        let mut expanded_rand = vec![0u8; 2 * HASH_SIZE * SCALAR_BITS + SCALAR_BITS / 8];
         let mut source_with_tag = vec![0u8; rand.len() + RO_TAG_SIZE];
         let mut hashout = [0u8; HASH_SIZE];
         source_with_tag[RO_TAG_SIZE..].copy_from_slice(&rand[..]);
@@ -488,9 +545,9 @@ impl OTERecver {
             expanded_rand[offset..(offset + remain)].copy_from_slice(&hashout[0..remain]);
         }
 
-        for ii in 0..SecpOrd::NBITS {
+        for ii in 0..SCALAR_BITS {
             let correlation_modifier =
-                (expanded_rand[2 * HASH_SIZE * SecpOrd::NBITS + ii / 8] >> (ii % 8)) & 1;
+                (expanded_rand[2 * HASH_SIZE * SCALAR_BITS + ii / 8] >> (ii % 8)) & 1;
             let (seedb, seedinvb) = if correlation_modifier == 1 {
                 (self.seeds[ii].1, self.seeds[ii].0)
             } else {
@@ -498,16 +555,16 @@ impl OTERecver {
             };
             for jj in 0..HASH_SIZE {
                 expanded_rand[ii * HASH_SIZE + jj] ^= seedb[jj];
-                expanded_rand[HASH_SIZE * SecpOrd::NBITS + ii * HASH_SIZE + jj] ^= seedinvb[jj];
+                expanded_rand[HASH_SIZE * SCALAR_BITS + ii * HASH_SIZE + jj] ^= seedinvb[jj];
             }
         }
 
-        for ii in 0..SecpOrd::NBITS {
+        for ii in 0..SCALAR_BITS {
             self.seeds[ii].0[..]
                 .copy_from_slice(&expanded_rand[ii * HASH_SIZE..(ii + 1) * HASH_SIZE]);
             self.seeds[ii].1[..].copy_from_slice(
-                &expanded_rand[HASH_SIZE * SecpOrd::NBITS + ii * HASH_SIZE
-                    ..HASH_SIZE * SecpOrd::NBITS + (ii + 1) * HASH_SIZE],
+                &expanded_rand[HASH_SIZE * SCALAR_BITS + ii * HASH_SIZE
+                    ..HASH_SIZE * SCALAR_BITS + (ii + 1) * HASH_SIZE],
             );
         }
         return Ok(());
@@ -543,21 +600,21 @@ impl OTERecver {
 
         // Extend phase
         let prgoutputlen = choice_bits.len();
-        let mut expanded_seeds0: Vec<u8> = Vec::with_capacity(SecpOrd::NBYTES * prgoutputlen);
-        let mut expanded_seeds1: Vec<u8> = Vec::with_capacity(SecpOrd::NBYTES * prgoutputlen);
+        let mut expanded_seeds0: Vec<u8> = Vec::with_capacity(SCALAR_SIZE * prgoutputlen);
+        let mut expanded_seeds1: Vec<u8> = Vec::with_capacity(SCALAR_SIZE * prgoutputlen);
         let prgiterations = ((prgoutputlen / 8) + HASH_SIZE - 1) / HASH_SIZE;
 
         let mut tagrange =
-            ro.allocate_dyadic_range((SecpOrd::NBITS * prgiterations + prgoutputlen + 1) as u64);
+            ro.allocate_dyadic_range((SCALAR_BITS * prgiterations + prgoutputlen + 1) as u64);
 
-        debug_assert!((SecpOrd::NBYTES * prgoutputlen) % HASH_SIZE == 0);
+        debug_assert!((SCALAR_SIZE * prgoutputlen) % HASH_SIZE == 0);
 
-        let mut prgoutput = vec![0u8; 2 * HASH_SIZE * prgiterations * SecpOrd::NBITS];
-        let mut hasherinput = vec![0u8; 2 * HASH_BLOCK_SIZE * prgiterations * SecpOrd::NBITS];
-        for ii in 0..SecpOrd::NBITS {
+        let mut prgoutput = vec![0u8; 2 * HASH_SIZE * prgiterations * SCALAR_BITS];
+        let mut hasherinput = vec![0u8; 2 * HASH_BLOCK_SIZE * prgiterations * SCALAR_BITS];
+        for ii in 0..SCALAR_BITS {
             for jj in 0..prgiterations {
                 // Map for (ii,jj): [ 20B: RO tag | 32B: seed[ii].0 ], total: 52B
-                // Map for (HASH_BLOCK_SIZE*prgiterations*SecpOrd::NBITS+ii,jj): [ 20B RO tag | 32B: seed[ii].1 ], total: 52B
+                // Map for (HASH_BLOCK_SIZE*prgiterations*SCALAR_BITS+ii,jj): [ 20B RO tag | 32B: seed[ii].1 ], total: 52B
                 let tag = tagrange.next()?;
                 hasherinput[((ii * prgiterations + jj) * HASH_BLOCK_SIZE)
                     ..((ii * prgiterations + jj) * HASH_BLOCK_SIZE + RO_TAG_SIZE)]
@@ -565,16 +622,16 @@ impl OTERecver {
                 hasherinput[((ii * prgiterations + jj) * HASH_BLOCK_SIZE + RO_TAG_SIZE)
                     ..((ii * prgiterations + jj) * HASH_BLOCK_SIZE + RO_TAG_SIZE + HASH_SIZE)]
                     .copy_from_slice(&self.seeds[ii].0);
-                hasherinput[(HASH_BLOCK_SIZE * prgiterations * SecpOrd::NBITS
+                hasherinput[(HASH_BLOCK_SIZE * prgiterations * SCALAR_BITS
                     + (ii * prgiterations + jj) * HASH_BLOCK_SIZE)
-                    ..(HASH_BLOCK_SIZE * prgiterations * SecpOrd::NBITS
+                    ..(HASH_BLOCK_SIZE * prgiterations * SCALAR_BITS
                         + (ii * prgiterations + jj) * HASH_BLOCK_SIZE
                         + RO_TAG_SIZE)]
                     .copy_from_slice(&tag[..]);
-                hasherinput[(HASH_BLOCK_SIZE * prgiterations * SecpOrd::NBITS
+                hasherinput[(HASH_BLOCK_SIZE * prgiterations * SCALAR_BITS
                     + (ii * prgiterations + jj) * HASH_BLOCK_SIZE
                     + RO_TAG_SIZE)
-                    ..(HASH_BLOCK_SIZE * prgiterations * SecpOrd::NBITS
+                    ..(HASH_BLOCK_SIZE * prgiterations * SCALAR_BITS
                         + (ii * prgiterations + jj) * HASH_BLOCK_SIZE
                         + RO_TAG_SIZE
                         + HASH_SIZE)]
@@ -585,18 +642,18 @@ impl OTERecver {
         hash_multi(
             &hasherinput,
             &mut prgoutput,
-            2 * SecpOrd::NBITS * prgiterations,
+            2 * SCALAR_BITS * prgiterations,
         );
 
-        for ii in 0..SecpOrd::NBITS {
+        for ii in 0..SCALAR_BITS {
             expanded_seeds0.extend_from_slice(
                 &prgoutput[(ii * prgiterations * HASH_SIZE)
                     ..(ii * prgiterations * HASH_SIZE + prgoutputlen / 8)],
             );
             expanded_seeds1.extend_from_slice(
-                &prgoutput[(HASH_SIZE * prgiterations * SecpOrd::NBITS
+                &prgoutput[(HASH_SIZE * prgiterations * SCALAR_BITS
                     + ii * prgiterations * HASH_SIZE)
-                    ..(HASH_SIZE * prgiterations * SecpOrd::NBITS
+                    ..(HASH_SIZE * prgiterations * SCALAR_BITS
                         + ii * prgiterations * HASH_SIZE
                         + prgoutputlen / 8)],
             );
@@ -604,9 +661,9 @@ impl OTERecver {
 
         let transposed_seed0 = transpose(&expanded_seeds0, prgoutputlen / 8);
 
-        debug_assert!(expanded_seeds0.len() / compressed_choice_bits.len() == SecpOrd::NBITS);
+        debug_assert!(expanded_seeds0.len() / compressed_choice_bits.len() == SCALAR_BITS);
 
-        let mut seeds_combined = vec![0u8; SecpOrd::NBYTES * prgoutputlen + RO_TAG_SIZE];
+        let mut seeds_combined = vec![0u8; SCALAR_SIZE * prgoutputlen + RO_TAG_SIZE];
         for ii in 0..expanded_seeds0.len() {
             seeds_combined[ii] = expanded_seeds0[ii]
                 ^ expanded_seeds1[ii]
@@ -616,7 +673,7 @@ impl OTERecver {
         let mut random_samples = vec![0u8; HASH_SIZE * prgoutputlen];
         let mut seeds_shortened = [0u8; HASH_SIZE];
         let mut hash_input = vec![0u8; HASH_BLOCK_SIZE * prgoutputlen];
-        seeds_combined[(SecpOrd::NBYTES * prgoutputlen)..].copy_from_slice(&tagrange.next()?[..]);
+        seeds_combined[(SCALAR_SIZE * prgoutputlen)..].copy_from_slice(&tagrange.next()?[..]);
         hash(&mut seeds_shortened, &seeds_combined);
         for ii in 0..prgoutputlen {
             // Map for ii: [ 20B RO tag | 32B: seeds_shortened ], total: 52B
@@ -631,35 +688,35 @@ impl OTERecver {
         debug_assert!(expanded_seeds0.len() == transposed_seed0.len());
         debug_assert!(transposed_seed0.len() == random_samples.len());
 
-        let mut sampled_bits = [0u8; SecpOrd::NBYTES];
-        let mut sampled_seeds = [0u8; SecpOrd::NBYTES];
+        let mut sampled_bits = [0u8; SCALAR_SIZE];
+        let mut sampled_seeds = [0u8; SCALAR_SIZE];
         for ii in 0..prgoutputlen {
             if choice_bits[ii] {
-                for jj in 0..SecpOrd::NBYTES {
-                    sampled_bits[jj] ^= random_samples[ii * SecpOrd::NBYTES + jj];
+                for jj in 0..SCALAR_SIZE {
+                    sampled_bits[jj] ^= random_samples[ii * SCALAR_SIZE + jj];
                 }
             }
-            for jj in 0..SecpOrd::NBYTES {
-                sampled_seeds[jj] ^= transposed_seed0[ii * SecpOrd::NBYTES + jj]
-                    & random_samples[ii * SecpOrd::NBYTES + jj];
+            for jj in 0..SCALAR_SIZE {
+                sampled_seeds[jj] ^=
+                    transposed_seed0[ii * SCALAR_SIZE + jj] & random_samples[ii * SCALAR_SIZE + jj];
             }
         }
 
         let mut bufsend = BufWriter::new(send);
-        bufsend.write(&seeds_combined[0..SecpOrd::NBYTES * prgoutputlen])?;
-        bufsend.write(&sampled_bits)?;
-        bufsend.write(&sampled_seeds)?;
+        bufsend.write_all(&seeds_combined[0..SCALAR_SIZE * prgoutputlen])?;
+        bufsend.write_all(&sampled_bits)?;
+        bufsend.write_all(&sampled_seeds)?;
 
         Ok(transposed_seed0)
     }
 
-    pub fn transfer<T: Read>(
+    pub fn transfer<S: Ford, T: Read>(
         &self,
         choice_bits: &[&[bool]],
         transposed_seed: &[&[u8]],
         ro: &DyadicROTagger,
         recv: &mut T,
-    ) -> Result<Vec<Vec<SecpOrd>>, MPECDSAError> {
+    ) -> Result<Vec<Vec<S>>, MPECDSAError> {
         let input_count = choice_bits.len();
         let total_input_len = choice_bits.iter().map(|x| x.len()).sum();
         let mut tagrange =
@@ -697,39 +754,39 @@ impl OTERecver {
 
         hash_multi(&hasherinput, &mut check_hashoutput, total_input_len);
 
-        let mut correction_vec_raw = vec![0u8; total_input_len * SecpOrd::NBYTES + RO_TAG_SIZE];
-        recv.read_exact(&mut correction_vec_raw[0..total_input_len * SecpOrd::NBYTES])?;
-        correction_vec_raw[total_input_len * SecpOrd::NBYTES..]
-            .copy_from_slice(&tagrange.next()?[..]);
+        let mut correction_vec_raw = vec![0u8; total_input_len * SCALAR_SIZE + RO_TAG_SIZE];
+        recv.read_exact(&mut correction_vec_raw[0..total_input_len * SCALAR_SIZE])?;
+        correction_vec_raw[total_input_len * SCALAR_SIZE..].copy_from_slice(&tagrange.next()?[..]);
 
         let mut coef_seed = [0u8; HASH_SIZE + RO_TAG_SIZE];
         let mut coef_raw = [0u8; HASH_SIZE];
-        let mut coefs = vec![SecpOrd::ZERO; input_count];
+        let mut coefs = vec![S::ZERO; input_count];
         hash(&mut coef_raw, &correction_vec_raw);
 
         coef_seed[0..HASH_SIZE].copy_from_slice(&coef_raw);
         for kk in 0..input_count {
             coef_seed[HASH_SIZE..].copy_from_slice(&tagrange.next()?[..]);
             hash(&mut coef_raw, &coef_seed);
-            coefs[kk] = SecpOrd::from_bytes(&coef_raw);
+            coefs[kk] = scalar_from_hash::<S>(&coef_raw);
         }
 
-        let mut vals: Vec<Vec<SecpOrd>> = Vec::with_capacity(input_count);
+        let mut vals: Vec<Vec<S>> = Vec::with_capacity(input_count);
         let mut vals_offset = 0;
         for kk in 0..input_count {
-            let mut localvals = vec![SecpOrd::ZERO; choice_bits[kk].len()];
+            let mut localvals = vec![S::ZERO; choice_bits[kk].len()];
             let localhashoutput = &hashoutput
                 [(vals_offset * HASH_SIZE)..((vals_offset + choice_bits[kk].len()) * HASH_SIZE)];
-            let localcorrectionvec = &mut correction_vec_raw[(vals_offset * SecpOrd::NBYTES)
-                ..((vals_offset + choice_bits[kk].len()) * SecpOrd::NBYTES)];
+            let localcorrectionvec = &mut correction_vec_raw[(vals_offset * SCALAR_SIZE)
+                ..((vals_offset + choice_bits[kk].len()) * SCALAR_SIZE)];
 
             for ii in 0..choice_bits[kk].len() {
-                let cv = SecpOrd::from_bytes(
-                    &localcorrectionvec[(ii * SecpOrd::NBYTES)..((ii + 1) * SecpOrd::NBYTES)],
+                let cv = S::from_bytes(
+                    &localcorrectionvec[(ii * SCALAR_SIZE)..((ii + 1) * SCALAR_SIZE)],
                 );
-                let val =
-                    SecpOrd::from_bytes(&localhashoutput[(ii * HASH_SIZE)..((ii + 1) * HASH_SIZE)])
-                        .neg();
+                let val = scalar_from_hash::<S>(
+                    &localhashoutput[(ii * HASH_SIZE)..((ii + 1) * HASH_SIZE)],
+                )
+                .neg();
                 let val_aug = val.add(&cv);
                 localvals[ii] = if choice_bits[kk][ii] { val_aug } else { val };
             }
@@ -737,39 +794,37 @@ impl OTERecver {
             vals_offset = vals_offset + choice_bits[kk].len();
         }
 
-        let mut check_correction_vec_raw =
-            vec![0u8; total_input_len * SecpOrd::NBYTES + RO_TAG_SIZE];
-        check_correction_vec_raw[total_input_len * SecpOrd::NBYTES..]
+        let mut check_correction_vec_raw = vec![0u8; total_input_len * SCALAR_SIZE + RO_TAG_SIZE];
+        check_correction_vec_raw[total_input_len * SCALAR_SIZE..]
             .copy_from_slice(&tagrange.next()?[..]);
-        recv.read_exact(&mut check_correction_vec_raw[0..total_input_len * SecpOrd::NBYTES])?;
+        recv.read_exact(&mut check_correction_vec_raw[0..total_input_len * SCALAR_SIZE])?;
 
         let mut check_coef_seed = [0u8; HASH_SIZE + RO_TAG_SIZE];
         let mut check_coef_raw = [0u8; HASH_SIZE];
-        let mut check_coefs = vec![SecpOrd::ZERO; input_count];
+        let mut check_coefs = vec![S::ZERO; input_count];
         hash(&mut check_coef_raw, &check_correction_vec_raw);
         check_coef_seed[0..HASH_SIZE].copy_from_slice(&check_coef_raw);
 
         for kk in 0..input_count {
             check_coef_seed[HASH_SIZE..].copy_from_slice(&tagrange.next()?[..]);
             hash(&mut check_coef_raw, &check_coef_seed);
-            check_coefs[kk] = SecpOrd::from_bytes(&check_coef_raw);
+            check_coefs[kk] = scalar_from_hash::<S>(&check_coef_raw);
         }
 
-        let mut check_vals: Vec<Vec<SecpOrd>> = Vec::with_capacity(input_count);
+        let mut check_vals: Vec<Vec<S>> = Vec::with_capacity(input_count);
         vals_offset = 0;
         for kk in 0..input_count {
-            let mut localcheckvals = vec![SecpOrd::ZERO; choice_bits[kk].len()];
+            let mut localcheckvals = vec![S::ZERO; choice_bits[kk].len()];
             let localcheckhashoutput = &check_hashoutput
                 [(vals_offset * HASH_SIZE)..((vals_offset + choice_bits[kk].len()) * HASH_SIZE)];
-            let localcheckcorrectionvec = &mut check_correction_vec_raw[(vals_offset
-                * SecpOrd::NBYTES)
-                ..((vals_offset + choice_bits[kk].len()) * SecpOrd::NBYTES)];
+            let localcheckcorrectionvec = &mut check_correction_vec_raw[(vals_offset * SCALAR_SIZE)
+                ..((vals_offset + choice_bits[kk].len()) * SCALAR_SIZE)];
 
             for ii in 0..choice_bits[kk].len() {
-                let ccv = SecpOrd::from_bytes(
-                    &localcheckcorrectionvec[(ii * SecpOrd::NBYTES)..((ii + 1) * SecpOrd::NBYTES)],
+                let ccv = S::from_bytes(
+                    &localcheckcorrectionvec[(ii * SCALAR_SIZE)..((ii + 1) * SCALAR_SIZE)],
                 );
-                let check_val = SecpOrd::from_bytes(
+                let check_val = scalar_from_hash::<S>(
                     &localcheckhashoutput[(ii * HASH_SIZE)..((ii + 1) * HASH_SIZE)],
                 )
                 .neg();
@@ -785,26 +840,24 @@ impl OTERecver {
         }
 
         let mut check_vec_raw =
-            vec![0u8; choice_bits.iter().map(|x| x.len()).max().unwrap() * SecpOrd::NBYTES];
+            vec![0u8; choice_bits.iter().map(|x| x.len()).max().unwrap() * SCALAR_SIZE];
         recv.read_exact(&mut check_vec_raw)?;
-        let mut references: Vec<SecpOrd> = Vec::with_capacity(input_count);
+        let mut references: Vec<S> = Vec::with_capacity(input_count);
         for _ in 0..input_count {
-            let mut reference_raw = [0u8; SecpOrd::NBYTES];
+            let mut reference_raw = [0u8; SCALAR_SIZE];
             recv.read_exact(&mut reference_raw)?;
-            references.push(SecpOrd::from_bytes(&reference_raw));
+            references.push(S::from_bytes(&reference_raw));
         }
 
         for ii in 0..choice_bits.iter().map(|x| x.len()).max().unwrap() {
-            let mut rhs = SecpOrd::from_bytes(
-                &check_vec_raw[(ii * SecpOrd::NBYTES)..((ii + 1) * SecpOrd::NBYTES)],
-            )
-            .neg();
-            let mut lhs = SecpOrd::ZERO;
+            let mut rhs =
+                S::from_bytes(&check_vec_raw[(ii * SCALAR_SIZE)..((ii + 1) * SCALAR_SIZE)]).neg();
+            let mut lhs = S::ZERO;
             for kk in 0..input_count {
                 rhs = rhs.add(&if (ii < choice_bits[kk].len()) && (choice_bits[kk][ii]) {
                     references[kk]
                 } else {
-                    SecpOrd::ZERO
+                    S::ZERO
                 });
 
                 lhs = lhs.add(&if ii < choice_bits[kk].len() {
@@ -812,12 +865,14 @@ impl OTERecver {
                         .mul(&coefs[kk])
                         .add(&check_vals[kk][ii].mul(&check_coefs[kk]))
                 } else {
-                    SecpOrd::ZERO
+                    S::ZERO
                 });
             }
 
             if lhs != rhs {
-                return Err(MPECDSAError::Proof(ProofError::new(
+                return Err(MPECDSAError::Blame(BlameError::new(
+                    ro.get_supercounterparty(),
+                    ProtocolPhase::OTExtension,
                     "Verification Failed for OTE (sender cheated)",
                 )));
             }
@@ -833,6 +888,21 @@ mod tests {
     use byteorder::{ByteOrder, LittleEndian};
     use std::thread;
 
+    #[test]
+    fn test_scalar_from_hash() {
+        // a hash output above the P-256 order is hashed again, rather than reduced
+        let high = [0xffu8; HASH_SIZE];
+        let s = scalar_from_hash::<SecpR1Ord>(&high);
+        let mut b = [0u8; HASH_SIZE];
+        s.to_bytes(&mut b);
+        assert!(s != SecpR1Ord::from_bytes(&high));
+        assert_eq!(SecpR1Ord::from_bytes(&b), s);
+
+        // values in range are unchanged
+        let low = [0x11u8; HASH_SIZE];
+        assert!(scalar_from_hash::<SecpR1Ord>(&low) == SecpR1Ord::from_bytes(&low));
+    }
+
     #[test]
     fn test_transpose8x8() {
         let a = 0b0111101000011101000100010111010000001010000000010010111111111111;
@@ -0,0 +1,332 @@
+/***********
+ * This module implements a versioned container for persisting signer state.
+ * Every serialized object is wrapped as
+ *     magic || version || kind || length || body || SHA-256(magic || ... || body)
+ * so that truncated, corrupted, or mismatched files are rejected before any of
+ * the body is interpreted. The checksum is always SHA-256, regardless of which
+ * hash function the protocols are compiled to use, so that files remain portable.
+ *
+ * The container itself provides integrity only. For storage at rest, a sealed
+ * container can be wrapped by encrypt(), which derives a key from a passphrase
+ * via scrypt and encrypts the entire container with ChaCha20-Poly1305:
+ *     magic || version || kind || log_n || r || p || salt || nonce || ciphertext || tag
+ * Everything preceding the ciphertext is authenticated as associated data.
+ ***********/
+
+use std::io::Cursor;
+
+use byteorder::{ByteOrder, LittleEndian};
+use crypto::aead::{AeadDecryptor, AeadEncryptor};
+use crypto::chacha20poly1305::ChaCha20Poly1305;
+use crypto::scrypt::{scrypt, ScryptParams};
+use rand::Rng;
+
+use curves::{ECGroup, Ford, Secp, SecpOrd};
+
+use super::mpecdsa_error::*;
+use super::*;
+
+pub const PERSIST_VERSION: u32 = 2;
+const PERSIST_MAGIC: [u8; 4] = *b"MPEC";
+const PERSIST_HEADER_SIZE: usize = PERSIST_MAGIC.len() + 4 + 1 + 8;
+
+const ENCRYPTED_MAGIC: [u8; 4] = *b"MPEE";
+const ENCRYPTED_SALT_SIZE: usize = 32;
+const ENCRYPTED_NONCE_SIZE: usize = 8;
+const ENCRYPTED_TAG_SIZE: usize = 16;
+const ENCRYPTED_KEY_SIZE: usize = 32;
+const ENCRYPTED_HEADER_SIZE: usize =
+    ENCRYPTED_MAGIC.len() + 4 + 1 + 1 + 4 + 4 + ENCRYPTED_SALT_SIZE + ENCRYPTED_NONCE_SIZE;
+
+// scrypt parameters used for new files: N = 2^15, r = 8, p = 1 (32 MiB of memory)
+pub const SCRYPT_LOG_N: u8 = 15;
+pub const SCRYPT_R: u32 = 8;
+pub const SCRYPT_P: u32 = 1;
+// upper bounds on the parameters accepted when loading. they are checked before
+// the tag, so a malicious header can force this much work on us: at most
+// SCRYPT_MAX_MEMORY bytes (128 * r * N) and a proportional amount of hashing
+const SCRYPT_MAX_LOG_N: u8 = 20;
+const SCRYPT_MAX_R: u32 = 8;
+const SCRYPT_MAX_P: u32 = 1;
+const SCRYPT_MAX_MEMORY: u64 = 256 << 20;
+
+// identifies the type of object stored in a container
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PersistKind {
+    ThresholdSigner = 1,
+    Alice2P = 2,
+    Bob2P = 3,
+}
+
+pub fn seal(kind: PersistKind, body: &[u8]) -> Vec<u8> {
+    let mut out = vec![0u8; PERSIST_HEADER_SIZE + body.len() + HASH_SIZE];
+    out[0..4].copy_from_slice(&PERSIST_MAGIC);
+    LittleEndian::write_u32(&mut out[4..8], PERSIST_VERSION);
+    out[8] = kind as u8;
+    LittleEndian::write_u64(&mut out[9..PERSIST_HEADER_SIZE], body.len() as u64);
+    out[PERSIST_HEADER_SIZE..(PERSIST_HEADER_SIZE + body.len())].copy_from_slice(body);
+
+    let mut checksum = [0u8; HASH_SIZE];
+    ecdsa_hash(&mut checksum, &out[0..(PERSIST_HEADER_SIZE + body.len())]);
+    out[(PERSIST_HEADER_SIZE + body.len())..].copy_from_slice(&checksum);
+    out
+}
+
+pub fn unseal(kind: PersistKind, data: &[u8]) -> Result<&[u8], MPECDSAError> {
+    if data.len() < PERSIST_HEADER_SIZE + HASH_SIZE || data[0..4] != PERSIST_MAGIC {
+        return Err(MPECDSAError::General(GeneralError::new(
+            "Serialized state is not in a recognized format",
+        )));
+    }
+    if LittleEndian::read_u32(&data[4..8]) != PERSIST_VERSION {
+        return Err(MPECDSAError::General(GeneralError::new(
+            "Serialized state has an unsupported version",
+        )));
+    }
+    if data[8] != kind as u8 {
+        return Err(MPECDSAError::General(GeneralError::new(
+            "Serialized state contains the wrong kind of object",
+        )));
+    }
+    let bodylen = LittleEndian::read_u64(&data[9..PERSIST_HEADER_SIZE]);
+    if bodylen != (data.len() - PERSIST_HEADER_SIZE - HASH_SIZE) as u64 {
+        return Err(MPECDSAError::General(GeneralError::new(
+            "Serialized state has been truncated",
+        )));
+    }
+
+    let bodyend = data.len() - HASH_SIZE;
+    let mut checksum = [0u8; HASH_SIZE];
+    ecdsa_hash(&mut checksum, &data[0..bodyend]);
+    if !vec_eq(&checksum, &data[bodyend..]) {
+        return Err(MPECDSAError::General(GeneralError::new(
+            "Serialized state failed integrity check",
+        )));
+    }
+    Ok(&data[PERSIST_HEADER_SIZE..bodyend])
+}
+
+fn derive_key(passphrase: &[u8], salt: &[u8], params: &ScryptParams) -> [u8; ENCRYPTED_KEY_SIZE] {
+    let mut key = [0u8; ENCRYPTED_KEY_SIZE];
+    scrypt(passphrase, salt, params, &mut key);
+    key
+}
+
+// encrypts a sealed container under a key derived from the passphrase.
+// a fresh salt is drawn for every call, so each file is encrypted under a fresh key
+pub fn encrypt(
+    sealed: &[u8],
+    passphrase: &[u8],
+    rng: &mut dyn Rng,
+) -> Result<Vec<u8>, MPECDSAError> {
+    if sealed.len() < PERSIST_HEADER_SIZE || sealed[0..4] != PERSIST_MAGIC {
+        return Err(MPECDSAError::General(GeneralError::new(
+            "Only a sealed container can be encrypted",
+        )));
+    }
+    let mut out = vec![0u8; ENCRYPTED_HEADER_SIZE + sealed.len() + ENCRYPTED_TAG_SIZE];
+    out[0..4].copy_from_slice(&ENCRYPTED_MAGIC);
+    LittleEndian::write_u32(&mut out[4..8], PERSIST_VERSION);
+    out[8] = sealed[8];
+    out[9] = SCRYPT_LOG_N;
+    LittleEndian::write_u32(&mut out[10..14], SCRYPT_R);
+    LittleEndian::write_u32(&mut out[14..18], SCRYPT_P);
+    rng.fill_bytes(&mut out[18..ENCRYPTED_HEADER_SIZE]);
+
+    let mut key = derive_key(
+        passphrase,
+        &out[18..(18 + ENCRYPTED_SALT_SIZE)],
+        &ScryptParams::new(SCRYPT_LOG_N, SCRYPT_R, SCRYPT_P),
+    );
+    let (header, rest) = out.split_at_mut(ENCRYPTED_HEADER_SIZE);
+    let (ciphertext, tag) = rest.split_at_mut(sealed.len());
+    let mut cipher = ChaCha20Poly1305::new(
+        &key,
+        &header[(ENCRYPTED_HEADER_SIZE - ENCRYPTED_NONCE_SIZE)..],
+        header,
+    );
+    cipher.encrypt(sealed, ciphertext, tag);
+    wipe_bytes(&mut key);
+    Ok(out)
+}
+
+// decrypts and authenticates an encrypted container, returning the sealed container within.
+// the result contains secret shares in the clear, and is zeroed on error
+pub fn decrypt(kind: PersistKind, data: &[u8], passphrase: &[u8]) -> Result<Vec<u8>, MPECDSAError> {
+    if data.len() < ENCRYPTED_HEADER_SIZE + ENCRYPTED_TAG_SIZE || data[0..4] != ENCRYPTED_MAGIC {
+        return Err(MPECDSAError::General(GeneralError::new(
+            "Encrypted state is not in a recognized format",
+        )));
+    }
+    if LittleEndian::read_u32(&data[4..8]) != PERSIST_VERSION {
+        return Err(MPECDSAError::General(GeneralError::new(
+            "Encrypted state has an unsupported version",
+        )));
+    }
+    if data[8] != kind as u8 {
+        return Err(MPECDSAError::General(GeneralError::new(
+            "Encrypted state contains the wrong kind of object",
+        )));
+    }
+    let log_n = data[9];
+    let r = LittleEndian::read_u32(&data[10..14]);
+    let p = LittleEndian::read_u32(&data[14..18]);
+    if log_n == 0
+        || log_n > SCRYPT_MAX_LOG_N
+        || r == 0
+        || r > SCRYPT_MAX_R
+        || p == 0
+        || p > SCRYPT_MAX_P
+        || (log_n as u32) >= r * 16
+        || (128 * r as u64) << log_n > SCRYPT_MAX_MEMORY
+    {
+        return Err(MPECDSAError::General(GeneralError::new(
+            "Encrypted state has unsupported key derivation parameters",
+        )));
+    }
+
+    let mut key = derive_key(
+        passphrase,
+        &data[18..(18 + ENCRYPTED_SALT_SIZE)],
+        &ScryptParams::new(log_n, r, p),
+    );
+    let header = &data[0..ENCRYPTED_HEADER_SIZE];
+    let tagstart = data.len() - ENCRYPTED_TAG_SIZE;
+    let mut sealed = vec![0u8; tagstart - ENCRYPTED_HEADER_SIZE];
+    let mut cipher = ChaCha20Poly1305::new(
+        &key,
+        &header[(ENCRYPTED_HEADER_SIZE - ENCRYPTED_NONCE_SIZE)..],
+        header,
+    );
+    let pass = cipher.decrypt(
+        &data[ENCRYPTED_HEADER_SIZE..tagstart],
+        &mut sealed,
+        &data[tagstart..],
+    );
+    wipe_bytes(&mut key);
+    if !pass {
+        wipe_bytes(&mut sealed);
+        return Err(MPECDSAError::General(GeneralError::new(
+            "Encrypted state failed authentication; the passphrase is wrong or the data has been modified",
+        )));
+    }
+    Ok(sealed)
+}
+
+// the body must be consumed exactly; trailing bytes indicate a malformed object
+pub fn finish_body(body: &Cursor<&[u8]>) -> Result<(), MPECDSAError> {
+    if body.position() as usize != body.get_ref().len() {
+        Err(MPECDSAError::General(GeneralError::new(
+            "Serialized state contains trailing data",
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+pub fn write_usize<T: Write>(val: usize, send: &mut T) -> Result<(), MPECDSAError> {
+    let mut buf = [0u8; 8];
+    LittleEndian::write_u64(&mut buf, val as u64);
+    send.write_all(&buf)?;
+    Ok(())
+}
+
+pub fn read_usize<T: Read>(recv: &mut T) -> Result<usize, MPECDSAError> {
+    let mut buf = [0u8; 8];
+    recv.read_exact(&mut buf)?;
+    Ok(LittleEndian::read_u64(&buf) as usize)
+}
+
+pub fn write_scalar<T: Write>(val: &SecpOrd, send: &mut T) -> Result<(), MPECDSAError> {
+    let mut buf = [0u8; SecpOrd::NBYTES];
+    val.to_bytes(&mut buf);
+    send.write_all(&buf)?;
+    Ok(())
+}
+
+pub fn read_scalar<T: Read>(recv: &mut T) -> Result<SecpOrd, MPECDSAError> {
+    let mut buf = [0u8; SecpOrd::NBYTES];
+    recv.read_exact(&mut buf)?;
+    Ok(SecpOrd::from_bytes(&buf))
+}
+
+pub fn write_point<T: Write>(val: &Secp, send: &mut T) -> Result<(), MPECDSAError> {
+    let mut buf = [0u8; Secp::NBYTES];
+    val.affine().to_bytes(&mut buf);
+    send.write_all(&buf)?;
+    Ok(())
+}
+
+pub fn read_point<T: Read>(recv: &mut T) -> Result<Secp, MPECDSAError> {
+    let mut buf = [0u8; Secp::NBYTES];
+    recv.read_exact(&mut buf)?;
+    Secp::try_from_bytes(&buf).map_err(|e| MPECDSAError::General(GeneralError::new(e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_persist_seal_unseal() {
+        let body = b"The Quick Brown Fox Jumped Over The Lazy Dog";
+        let sealed = seal(PersistKind::ThresholdSigner, body);
+        assert_eq!(
+            unseal(PersistKind::ThresholdSigner, &sealed).unwrap(),
+            &body[..]
+        );
+
+        // any single flipped bit must be detected
+        for ii in 0..sealed.len() {
+            let mut corrupted = sealed.clone();
+            corrupted[ii] ^= 0x10;
+            assert!(unseal(PersistKind::ThresholdSigner, &corrupted).is_err());
+        }
+
+        assert!(unseal(PersistKind::ThresholdSigner, &sealed[0..sealed.len() - 1]).is_err());
+        assert!(unseal(PersistKind::ThresholdSigner, &[]).is_err());
+    }
+
+    #[test]
+    fn test_persist_encrypt_decrypt() {
+        let mut rng = rand::thread_rng();
+        let body = b"The Quick Brown Fox Jumped Over The Lazy Dog";
+        let sealed = seal(PersistKind::Alice2P, body);
+        let encrypted = encrypt(&sealed, b"passphrase", &mut rng).unwrap();
+        assert!(encrypted.windows(body.len()).all(|w| w != &body[..]));
+        assert_eq!(
+            decrypt(PersistKind::Alice2P, &encrypted, b"passphrase").unwrap(),
+            sealed
+        );
+
+        // fresh salt and nonce each time
+        assert!(encrypted != encrypt(&sealed, b"passphrase", &mut rng).unwrap());
+        // only sealed containers are accepted
+        assert!(encrypt(body, b"passphrase", &mut rng).is_err());
+
+        assert!(decrypt(PersistKind::Alice2P, &encrypted, b"Passphrase").is_err());
+        assert!(decrypt(PersistKind::Bob2P, &encrypted, b"passphrase").is_err());
+        assert!(decrypt(PersistKind::Alice2P, &sealed, b"passphrase").is_err());
+
+        // tampering with the header, ciphertext, or tag must be detected
+        for ii in [
+            0,
+            8,
+            9,
+            20,
+            ENCRYPTED_HEADER_SIZE - 1,
+            ENCRYPTED_HEADER_SIZE + 3,
+            encrypted.len() - 1,
+        ] {
+            let mut corrupted = encrypted.clone();
+            corrupted[ii] ^= 0x01;
+            assert!(decrypt(PersistKind::Alice2P, &corrupted, b"passphrase").is_err());
+        }
+        assert!(decrypt(
+            PersistKind::Alice2P,
+            &encrypted[0..encrypted.len() - 1],
+            b"passphrase"
+        )
+        .is_err());
+    }
+}
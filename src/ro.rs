@@ -15,6 +15,9 @@ use byteorder::{ByteOrder, LittleEndian};
 use super::mpecdsa_error::*;
 use super::*;
 
+// upper bound on the group size accepted when deserializing a tagger
+const MAX_PERSISTED_PLAYERS: usize = 1 << 16;
+
 pub struct TagRange {
     base: [u8; RO_TAG_SIZE],
     counter: u64,
@@ -38,6 +41,7 @@ pub struct DyadicROTagger<'a> {
     #[allow(dead_code)]
     playerindex: usize,
     counterparty: usize,
+    supercounterparty: usize,
     dyadic_base: &'a [u8; RO_TAG_SIZE],
     dyadic_counter: &'a AtomicU64,
     counterparty_broadcast_base: &'a [u8; RO_TAG_SIZE],
@@ -107,6 +111,19 @@ fn party_broadcast_base(
     ro_out
 }
 
+// the uid of a joining party binds the uids of all existing parties
+fn joining_puid(puids: &[[u8; HASH_SIZE]], puid_seed: &[u8; HASH_SIZE]) -> [u8; HASH_SIZE] {
+    let mut hashin = vec![0u8; (puids.len() + 1) * HASH_SIZE + 8];
+    LittleEndian::write_u64(&mut hashin[0..8], puids.len() as u64);
+    for ii in 0..puids.len() {
+        hashin[(ii * HASH_SIZE + 8)..((ii + 1) * HASH_SIZE + 8)].copy_from_slice(&puids[ii][..]);
+    }
+    hashin[(puids.len() * HASH_SIZE + 8)..].copy_from_slice(&puid_seed[..]);
+    let mut puid = [0u8; HASH_SIZE];
+    hash(&mut puid, &hashin);
+    puid
+}
+
 impl GroupROTagger {
     // this constructor initializes all counters to 0 and does not allow anyone to complain - in practice, parties should be able to object to each others' counter values
     pub fn from_network_unverified<TR: Read, TW: Write>(
@@ -125,7 +142,7 @@ impl GroupROTagger {
 
         for ii in 0..playercount {
             if ii != playerindex {
-                send[ii].as_mut().unwrap().write(
+                send[ii].as_mut().unwrap().write_all(
                     &puid_seed[(playerindex * HASH_SIZE)..((playerindex + 1) * HASH_SIZE)],
                 )?;
                 send[ii].as_mut().unwrap().flush()?;
@@ -149,6 +166,110 @@ impl GroupROTagger {
         )
     }
 
+    // constructs a tagger for a party joining an existing group as its last member. Counters are initialized to 0,
+    // and will be advanced by the usual synchronization when broadcast tags are first used.
+    pub fn from_network_joining<TR: Read, TW: Write>(
+        playerindex: usize,
+        rng: &mut dyn Rng,
+        recv: &mut [Option<&mut TR>],
+        send: &mut [Option<&mut TW>],
+    ) -> Result<GroupROTagger, MPECDSAError> {
+        if recv.len() != send.len() {
+            panic!("Number of Send streams does not match number of Recv streams");
+        }
+        let playercount = recv.len();
+        if playercount > MAX_PERSISTED_PLAYERS {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Too many parties in Random Oracle tagger",
+            )));
+        }
+        if playerindex + 1 != playercount {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Joining party must take the last index in the group",
+            )));
+        }
+
+        let mut puid_seed = [0u8; HASH_SIZE];
+        rng.fill_bytes(&mut puid_seed);
+        for ii in 0..playerindex {
+            send[ii].as_mut().unwrap().write_all(&puid_seed)?;
+            send[ii].as_mut().unwrap().flush()?;
+        }
+
+        // every existing party must report the same uids
+        let mut puids: Vec<[u8; HASH_SIZE]> = Vec::with_capacity(playercount);
+        for ii in 0..playerindex {
+            let mut buf = [0u8; 8];
+            recv[ii].as_mut().unwrap().read_exact(&mut buf)?;
+            if LittleEndian::read_u64(&buf) != playerindex as u64 {
+                return Err(MPECDSAError::Blame(BlameError::new(
+                    ii,
+                    ProtocolPhase::Join,
+                    "reported the wrong group size",
+                )));
+            }
+            for jj in 0..playerindex {
+                let mut puid = [0u8; HASH_SIZE];
+                recv[ii].as_mut().unwrap().read_exact(&mut puid)?;
+                if ii == 0 {
+                    puids.push(puid);
+                } else if puid != puids[jj] {
+                    return Err(MPECDSAError::Blame(BlameError::new(
+                        ii,
+                        ProtocolPhase::Join,
+                        "reported different party UIDs",
+                    )));
+                }
+            }
+        }
+
+        puids.push(joining_puid(&puids, &puid_seed));
+        Self::from_puids(playerindex, puids, &vec![true; playercount])
+    }
+
+    // extends an existing tagger with a party that joins as the new last member (see from_network_joining).
+    // Counters with existing parties are preserved; the broadcast bases change with the group membership.
+    pub fn add_party<TR: Read, TW: Write>(
+        &mut self,
+        recv: &mut TR,
+        send: &mut TW,
+    ) -> Result<(), MPECDSAError> {
+        self.remove_subgroup_mask();
+        let playercount = self.puids.len() + 1;
+        if playercount > MAX_PERSISTED_PLAYERS {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Too many parties in Random Oracle tagger",
+            )));
+        }
+
+        let mut buf = [0u8; 8];
+        LittleEndian::write_u64(&mut buf, self.puids.len() as u64);
+        send.write_all(&buf)?;
+        for puid in self.puids.iter() {
+            send.write_all(puid)?;
+        }
+        send.flush()?;
+
+        let mut puid_seed = [0u8; HASH_SIZE];
+        recv.read_exact(&mut puid_seed)?;
+
+        let mut puids = self.puids.clone();
+        puids.push(joining_puid(&self.puids, &puid_seed));
+        let tagger = Self::from_puids(self.playerindex, puids, &vec![true; playercount])?;
+        for ii in 0..(playercount - 1) {
+            tagger.dyadic_counters[ii].store(
+                self.dyadic_counters[ii].load(Ordering::Relaxed),
+                Ordering::Relaxed,
+            );
+            tagger.broadcast_counters[ii].store(
+                self.broadcast_counters[ii].load(Ordering::Relaxed),
+                Ordering::Relaxed,
+            );
+        }
+        *self = tagger;
+        Ok(())
+    }
+
     // this constructor initializes all counters to 0 and does not allow anyone to complain - in practice, parties should be able to object to each others' counter values
     // note also that playerindex is given in supergroup indices!
     fn from_seed(
@@ -157,15 +278,6 @@ impl GroupROTagger {
         puid_seed: &[u8],
         subgroup_mask: &[bool],
     ) -> Result<GroupROTagger, MPECDSAError> {
-        if subgroup_mask.len() != playercount {
-            panic!("Subgroup mask length does not match player count");
-        }
-        if !subgroup_mask[playerindex] {
-            return Err(MPECDSAError::General(GeneralError::new(
-                "Cannot apply subgroup mask that omits active party",
-            )));
-        }
-
         let mut ps = vec![0u8; puid_seed.len() + 8];
         let mut puids = vec![[0u8; HASH_SIZE]; playercount];
 
@@ -176,6 +288,25 @@ impl GroupROTagger {
             hash(&mut puids[ii], &ps);
         }
 
+        Self::from_puids(playerindex, puids, subgroup_mask)
+    }
+
+    // as above, all counters are initialized to 0, and playerindex is given in supergroup indices
+    fn from_puids(
+        playerindex: usize,
+        puids: Vec<[u8; HASH_SIZE]>,
+        subgroup_mask: &[bool],
+    ) -> Result<GroupROTagger, MPECDSAError> {
+        let playercount = puids.len();
+        if subgroup_mask.len() != playercount {
+            panic!("Subgroup mask length does not match player count");
+        }
+        if !subgroup_mask[playerindex] {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Cannot apply subgroup mask that omits active party",
+            )));
+        }
+
         // each dyadic base is the hash of the uids of the two parties that share it, in numerical order
         let mut dyadic_bases = vec![[0u8; RO_TAG_SIZE]; playercount];
         let mut hashin = [0u8; 2 * HASH_SIZE];
@@ -219,16 +350,16 @@ impl GroupROTagger {
         }
 
         Ok(GroupROTagger {
-            playerindex: playerindex,
-            puids: puids,
+            playerindex,
+            puids,
             subgroup_mask: subgroup_mask.to_vec(),
-            subgroup_map_to_super: subgroup_map_to_super,
-            supergroup_map_to_sub: supergroup_map_to_sub,
-            subgroup_size: subgroup_size,
-            dyadic_bases: dyadic_bases,
-            dyadic_counters: dyadic_counters,
-            broadcast_bases: broadcast_bases,
-            broadcast_counters: broadcast_counters,
+            subgroup_map_to_super,
+            supergroup_map_to_sub,
+            subgroup_size,
+            dyadic_bases,
+            dyadic_counters,
+            broadcast_bases,
+            broadcast_counters,
         })
     }
 
@@ -260,6 +391,72 @@ impl GroupROTagger {
         }
     }
 
+    // Serializes the party UIDs, the current subgroup mask, and all counters. The tag bases are
+    // derived from the UIDs, so they are recomputed when the tagger is read back.
+    // Note that a serialized tagger must never be restored twice: doing so would reuse tags.
+    pub fn write_to<T: Write>(&self, send: &mut T) -> Result<(), MPECDSAError> {
+        let mut buf = [0u8; 8];
+        LittleEndian::write_u64(&mut buf, self.playerindex as u64);
+        send.write_all(&buf)?;
+        LittleEndian::write_u64(&mut buf, self.puids.len() as u64);
+        send.write_all(&buf)?;
+        for ii in 0..self.puids.len() {
+            send.write_all(&self.puids[ii])?;
+            send.write_all(&[self.subgroup_mask[ii] as u8])?;
+            LittleEndian::write_u64(&mut buf, self.dyadic_counters[ii].load(Ordering::Relaxed));
+            send.write_all(&buf)?;
+            LittleEndian::write_u64(
+                &mut buf,
+                self.broadcast_counters[ii].load(Ordering::Relaxed),
+            );
+            send.write_all(&buf)?;
+        }
+        Ok(())
+    }
+
+    pub fn read_from<T: Read>(recv: &mut T) -> Result<GroupROTagger, MPECDSAError> {
+        let mut buf = [0u8; 8];
+        recv.read_exact(&mut buf)?;
+        let playerindex = LittleEndian::read_u64(&buf) as usize;
+        recv.read_exact(&mut buf)?;
+        let playercount = LittleEndian::read_u64(&buf) as usize;
+        if playerindex >= playercount || playercount > MAX_PERSISTED_PLAYERS {
+            return Err(MPECDSAError::General(GeneralError::new(
+                "Invalid player count or index in serialized Random Oracle tagger",
+            )));
+        }
+
+        let mut puids = vec![[0u8; HASH_SIZE]; playercount];
+        let mut subgroup_mask = vec![false; playercount];
+        let mut dyadic_counters = Vec::with_capacity(playercount);
+        let mut broadcast_counters = Vec::with_capacity(playercount);
+        for ii in 0..playercount {
+            recv.read_exact(&mut puids[ii])?;
+            let mut maskbyte = [0u8; 1];
+            recv.read_exact(&mut maskbyte)?;
+            subgroup_mask[ii] = match maskbyte[0] {
+                0 => false,
+                1 => true,
+                _ => {
+                    return Err(MPECDSAError::General(GeneralError::new(
+                        "Invalid subgroup mask in serialized Random Oracle tagger",
+                    )))
+                }
+            };
+            recv.read_exact(&mut buf)?;
+            dyadic_counters.push(LittleEndian::read_u64(&buf));
+            recv.read_exact(&mut buf)?;
+            broadcast_counters.push(LittleEndian::read_u64(&buf));
+        }
+
+        let tagger = Self::from_puids(playerindex, puids, &subgroup_mask)?;
+        for ii in 0..playercount {
+            tagger.dyadic_counters[ii].store(dyadic_counters[ii], Ordering::Relaxed);
+            tagger.broadcast_counters[ii].store(broadcast_counters[ii], Ordering::Relaxed);
+        }
+        Ok(tagger)
+    }
+
     pub fn apply_subgroup_mask(&mut self, new_mask: &[bool]) -> Result<(), MPECDSAError> {
         if new_mask.len() != self.puids.len() {
             panic!("Subgroup mask length does not match player count");
@@ -317,6 +514,11 @@ impl GroupROTagger {
         self.puids.len()
     }
 
+    // uses supergroup indices
+    pub fn get_playerindex(&self) -> usize {
+        self.playerindex
+    }
+
     pub fn current_broadcast_counter(&self) -> u64 {
         self.broadcast_counters[self.playerindex].load(Ordering::Relaxed)
     }
@@ -371,7 +573,8 @@ impl GroupROTagger {
         //if supercounterparty == self.playerindex {panic!("Cannot get dyadic tagger for self.");}
         Ok(DyadicROTagger {
             playerindex: self.supergroup_map_to_sub[self.playerindex].unwrap(),
-            counterparty: counterparty, // uses subgroup indices
+            counterparty, // uses subgroup indices
+            supercounterparty,
             dyadic_base: &self.dyadic_bases[supercounterparty],
             dyadic_counter: &self.dyadic_counters[supercounterparty],
             counterparty_broadcast_base: &self.broadcast_bases[supercounterparty],
@@ -393,10 +596,14 @@ impl ROTagger for GroupROTagger {
         let oldcounter =
             self.broadcast_counters[supercounterparty].fetch_max(tagindex, Ordering::Relaxed);
         if oldcounter > tagindex {
-            Err(MPECDSAError::Proof(ProofError::new(&format!(
-                "Party {}/{} (subgroup/supergroup) attempted to reuse Random Oracle tag",
-                counterparty, supercounterparty
-            ))))
+            Err(MPECDSAError::Blame(BlameError::new(
+                supercounterparty,
+                ProtocolPhase::RandomOracle,
+                &format!(
+                    "attempted to reuse Random Oracle tag as subgroup party {}",
+                    counterparty
+                ),
+            )))
         } else {
             Ok(())
         }
@@ -432,7 +639,7 @@ impl ROTagger for GroupROTagger {
         let oldcounter =
             self.broadcast_counters[supercounterparty].fetch_add(length, Ordering::Relaxed);
         Ok(TagRange {
-            base: base,
+            base,
             counter: oldcounter,
             length: oldcounter + length,
         })
@@ -450,10 +657,14 @@ impl ROTagger for GroupROTagger {
         let oldcounter =
             self.dyadic_counters[supercounterparty].fetch_max(tagindex, Ordering::Relaxed);
         if oldcounter > tagindex {
-            Err(MPECDSAError::Proof(ProofError::new(&format!(
-                "Party {}/{} (subgroup/supergroup) attempted to reuse Random Oracle tag",
-                counterparty, supercounterparty
-            ))))
+            Err(MPECDSAError::Blame(BlameError::new(
+                supercounterparty,
+                ProtocolPhase::RandomOracle,
+                &format!(
+                    "attempted to reuse Random Oracle tag as subgroup party {}",
+                    counterparty
+                ),
+            )))
         } else {
             Ok(())
         }
@@ -489,7 +700,7 @@ impl ROTagger for GroupROTagger {
         let oldcounter =
             self.dyadic_counters[supercounterparty].fetch_add(length, Ordering::Relaxed);
         Ok(TagRange {
-            base: base,
+            base,
             counter: oldcounter,
             length: oldcounter + length,
         })
@@ -500,7 +711,7 @@ impl<'a> ModelessGroupROTagger<'a> {
     pub fn new(grot: &GroupROTagger, is_dyadic: bool) -> ModelessGroupROTagger {
         ModelessGroupROTagger {
             tagger: grot,
-            is_dyadic: is_dyadic,
+            is_dyadic,
         }
     }
 }
@@ -553,6 +764,11 @@ impl<'a> ModelessROTagger for ModelessGroupROTagger<'a> {
 }
 
 impl<'a> DyadicROTagger<'a> {
+    // the counterparty's index in the supergroup, used to assign blame
+    pub fn get_supercounterparty(&self) -> usize {
+        self.supercounterparty
+    }
+
     pub fn next_dyadic_tag(&self) -> [u8; RO_TAG_SIZE] {
         self.next_counterparty_dyadic_tag(self.counterparty)
             .unwrap()
@@ -586,10 +802,11 @@ impl<'a> ROTagger for DyadicROTagger<'a> {
                 .counterparty_broadcast_counter
                 .fetch_max(tagindex, Ordering::Relaxed);
             if oldcounter > tagindex {
-                Err(MPECDSAError::Proof(ProofError::new(&format!(
-                    "Party {} attempted to reuse Random Oracle tag",
-                    counterparty
-                ))))
+                Err(MPECDSAError::Blame(BlameError::new(
+                    self.supercounterparty,
+                    ProtocolPhase::RandomOracle,
+                    "attempted to reuse broadcast Random Oracle tag",
+                )))
             } else {
                 Ok(())
             }
@@ -632,7 +849,7 @@ impl<'a> ROTagger for DyadicROTagger<'a> {
             let mut base = [0u8; RO_TAG_SIZE];
             base.copy_from_slice(self.counterparty_broadcast_base);
             Ok(TagRange {
-                base: base,
+                base,
                 counter: oldcounter,
                 length: length + oldcounter,
             })
@@ -651,10 +868,11 @@ impl<'a> ROTagger for DyadicROTagger<'a> {
         if self.counterparty == counterparty {
             let oldcounter = self.dyadic_counter.fetch_max(tagindex, Ordering::Relaxed);
             if oldcounter > tagindex {
-                Err(MPECDSAError::Proof(ProofError::new(&format!(
-                    "Party {} attempted to reuse Random Oracle tag.",
-                    counterparty
-                ))))
+                Err(MPECDSAError::Blame(BlameError::new(
+                    self.supercounterparty,
+                    ProtocolPhase::RandomOracle,
+                    "attempted to reuse dyadic Random Oracle tag",
+                )))
             } else {
                 Ok(())
             }
@@ -693,7 +911,7 @@ impl<'a> ROTagger for DyadicROTagger<'a> {
             let mut base = [0u8; RO_TAG_SIZE];
             base.copy_from_slice(self.dyadic_base);
             Ok(TagRange {
-                base: base,
+                base,
                 counter: oldcounter,
                 length: length + oldcounter,
             })
@@ -709,10 +927,14 @@ impl<'a> ModelessDyadicROTagger<'a> {
     pub fn new(drot: &'a DyadicROTagger, is_dyadic: bool) -> ModelessDyadicROTagger<'a> {
         ModelessDyadicROTagger {
             tagger: drot,
-            is_dyadic: is_dyadic,
+            is_dyadic,
         }
     }
 
+    pub fn get_supercounterparty(&self) -> usize {
+        self.tagger.get_supercounterparty()
+    }
+
     pub fn next_dyadic_counterparty_tag(&self) -> Result<[u8; RO_TAG_SIZE], MPECDSAError> {
         self.next_counterparty_tag(self.tagger.counterparty)
     }
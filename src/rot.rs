@@ -33,15 +33,15 @@ impl ROTSender {
     ) -> Result<ROTSender, MPECDSAError> {
         let (sk, pk): (SecpOrd, Secp) = Secp::rand(rng);
 
-        let mut buf = [0u8; Secp::NBYTES];
-        pk.to_bytes(&mut buf);
-        send.write(&buf)?;
+        let mut buf = [0u8; POINT_SIZE];
+        pk.to_wire_bytes(&mut buf);
+        send.write_all(&buf)?;
         let mro = ModelessDyadicROTagger::new(&ro, true);
-        zkpok::prove_dl_fs(&sk, &pk, &mro, rng, send)?;
+        zkpok::prove_dl_fs::<Secp256k1, _>(&sk, &pk, &mro, rng, send)?;
 
         Ok(ROTSender {
-            sk: sk,
-            pk: pk,
+            sk,
+            pk,
             pk_negsquared: Secp::scalar_gen(&sk.sqr()).neg(),
         })
     }
@@ -50,25 +50,29 @@ impl ROTSender {
         &mut self,
         ro: &DyadicROTagger,
         recv: &mut T,
-    ) -> io::Result<([u8; HASH_SIZE], [u8; HASH_SIZE])> {
+    ) -> Result<([u8; HASH_SIZE], [u8; HASH_SIZE]), MPECDSAError> {
         // read ga1 array from receiver
-        let mut buf = [0u8; Secp::NBYTES];
+        let mut buf = [0u8; POINT_SIZE];
         recv.read_exact(&mut buf)?;
 
-        let ga_select = Secp::from_bytes(&buf[..]);
-        let msg_0 = ga_select.scalar_table(&self.sk).affine();
+        let ga_select = Secp::from_wire_bytes(
+            &buf[..],
+            ro.get_supercounterparty(),
+            ProtocolPhase::RandomOT,
+        )?;
+        let msg_0 = ga_select.scalar_ct(&self.sk).affine();
         let msg_1 = Secp::op(&msg_0, &self.pk_negsquared).affine();
 
-        let mut msgbuf_0 = [0u8; RO_TAG_SIZE + Secp::NBYTES];
-        let mut msgbuf_1 = [0u8; RO_TAG_SIZE + Secp::NBYTES];
+        let mut msgbuf_0 = [0u8; RO_TAG_SIZE + POINT_SIZE];
+        let mut msgbuf_1 = [0u8; RO_TAG_SIZE + POINT_SIZE];
         let mut outbuf_0 = [0u8; HASH_SIZE];
         let mut outbuf_1 = [0u8; HASH_SIZE];
 
-        msg_0.to_bytes(&mut msgbuf_0[RO_TAG_SIZE..]);
+        msg_0.to_wire_bytes(&mut msgbuf_0[RO_TAG_SIZE..]);
         msgbuf_0[0..RO_TAG_SIZE].copy_from_slice(&ro.next_dyadic_tag()[..]);
         hash(&mut outbuf_0, &msgbuf_0);
 
-        msg_1.to_bytes(&mut msgbuf_1[RO_TAG_SIZE..]);
+        msg_1.to_wire_bytes(&mut msgbuf_1[RO_TAG_SIZE..]);
         msgbuf_1[0..RO_TAG_SIZE].copy_from_slice(&msgbuf_0[0..RO_TAG_SIZE]);
         hash(&mut outbuf_1, &msgbuf_1);
 
@@ -84,19 +88,25 @@ pub struct ROTRecver {
 
 impl ROTRecver {
     pub fn new<T: Read>(ro: &DyadicROTagger, recv: &mut T) -> Result<ROTRecver, MPECDSAError> {
-        let mut buf = [0u8; Secp::NBYTES];
+        let mut buf = [0u8; POINT_SIZE];
         recv.read_exact(&mut buf[..])?;
 
-        let pk = Secp::from_bytes(&buf);
+        let pk = Secp::from_wire_bytes_finite(
+            &buf,
+            ro.get_supercounterparty(),
+            ProtocolPhase::RandomOT,
+        )?;
         let mro = ModelessDyadicROTagger::new(&ro, true);
-        let prover_honest = verify_dl_fs(&pk, &mro, recv)?;
+        let prover_honest = verify_dl_fs::<Secp256k1, _>(&pk, &mro, recv, ProtocolPhase::RandomOT)?;
         if prover_honest {
             Ok(ROTRecver {
-                pk: pk,
+                pk,
                 pk_table: Secp::precomp_table(&pk),
             })
         } else {
-            Err(MPECDSAError::Proof(ProofError::new(
+            Err(MPECDSAError::Blame(BlameError::new(
+                ro.get_supercounterparty(),
+                ProtocolPhase::RandomOT,
                 "Proof of Knowledge failed for ROT secret key (sender cheated)",
             )))
         }
@@ -110,20 +120,20 @@ impl ROTRecver {
         send: &mut T,
     ) -> io::Result<[u8; HASH_SIZE]> {
         let a = SecpOrd::rand(rng);
-        let ga_choice0 = Secp::scalar_table_multi(&precomp::P256_TABLE[..], &a).affine();
+        let ga_choice0 = Secp::scalar_table_multi_ct(&precomp::P256_TABLE[..], &a).affine();
         let ga_choice1 = Secp::op(&ga_choice0, &self.pk).affine(); //always do this to avoid timing channel
-        let pka = Secp::scalar_table_multi(&self.pk_table[..], &a).affine();
-        let mut buf = [0u8; RO_TAG_SIZE + Secp::NBYTES];
+        let pka = Secp::scalar_table_multi_ct(&self.pk_table[..], &a).affine();
+        let mut buf = [0u8; RO_TAG_SIZE + POINT_SIZE];
         let mut outbuf = [0u8; HASH_SIZE];
 
         if choice_bit {
-            ga_choice1.to_bytes(&mut buf[RO_TAG_SIZE..]);
+            ga_choice1.to_wire_bytes(&mut buf[RO_TAG_SIZE..]);
         } else {
-            ga_choice0.to_bytes(&mut buf[RO_TAG_SIZE..]);
+            ga_choice0.to_wire_bytes(&mut buf[RO_TAG_SIZE..]);
         }
-        send.write(&buf[RO_TAG_SIZE..])?;
+        send.write_all(&buf[RO_TAG_SIZE..])?;
 
-        pka.to_bytes(&mut buf[RO_TAG_SIZE..]);
+        pka.to_wire_bytes(&mut buf[RO_TAG_SIZE..]);
         buf[0..RO_TAG_SIZE].copy_from_slice(&ro.next_dyadic_tag()[..]);
         hash(&mut outbuf, &buf);
         Ok(outbuf)
@@ -135,6 +145,7 @@ pub struct ROTSendVerifier {
     msg_0_com: [u8; HASH_SIZE],
     msg_1_com: [u8; HASH_SIZE],
     exp_chal: [u8; HASH_SIZE],
+    counterparty: usize,
 }
 
 impl ROTSendVerifier {
@@ -148,6 +159,7 @@ impl ROTSendVerifier {
             msg_0_com: [0u8; HASH_SIZE],
             msg_1_com: [0u8; HASH_SIZE],
             exp_chal: [0u8; HASH_SIZE],
+            counterparty: ro.get_supercounterparty(),
         };
         let mut com_msg = [0u8; HASH_SIZE];
         let mut hashin = [0u8; HASH_SIZE + RO_TAG_SIZE];
@@ -170,7 +182,7 @@ impl ROTSendVerifier {
         for ii in 0..com_msg.len() {
             com_msg[ii] ^= s.exp_chal[ii];
         }
-        send.write(&com_msg)?;
+        send.write_all(&com_msg)?;
 
         Ok(s)
     }
@@ -184,11 +196,13 @@ impl ROTSendVerifier {
         recv.read_exact(&mut chal_msg)?;
 
         if vec_eq(&chal_msg[..], &self.exp_chal[..]) {
-            send.write(&self.msg_0_com)?;
-            send.write(&self.msg_1_com)?;
+            send.write_all(&self.msg_0_com)?;
+            send.write_all(&self.msg_1_com)?;
             Ok(())
         } else {
-            Err(MPECDSAError::Proof(ProofError::new(
+            Err(MPECDSAError::Blame(BlameError::new(
+                self.counterparty,
+                ProtocolPhase::RandomOT,
                 "Verification Failed for ROT (receiver cheated)",
             )))
         }
@@ -201,6 +215,7 @@ pub struct ROTRecvVerifier {
     hashed_chosen_msg: [u8; HASH_SIZE],
     com_msg: [u8; HASH_SIZE],
     tag2: [u8; RO_TAG_SIZE],
+    counterparty: usize,
 }
 
 impl ROTRecvVerifier {
@@ -212,10 +227,11 @@ impl ROTRecvVerifier {
         send: &mut T2,
     ) -> Result<ROTRecvVerifier, MPECDSAError> {
         let mut s = ROTRecvVerifier {
-            choice_bit: choice_bit,
+            choice_bit,
             hashed_chosen_msg: [0u8; HASH_SIZE],
             com_msg: [0u8; HASH_SIZE],
             tag2: [0u8; RO_TAG_SIZE],
+            counterparty: ro.get_supercounterparty(),
         };
 
         let mut hashin = [0u8; HASH_SIZE + RO_TAG_SIZE];
@@ -236,7 +252,7 @@ impl ROTRecvVerifier {
                 chal_msg[ii] ^= s.com_msg[ii];
             }
         }
-        send.write(&chal_msg)?;
+        send.write_all(&chal_msg)?;
         Ok(s)
     }
 
@@ -267,7 +283,9 @@ impl ROTRecvVerifier {
         if vec_eq(&exp_com_msg, &self.com_msg) && vec_eq(&chosen_msg_com, &self.hashed_chosen_msg) {
             Ok(())
         } else {
-            Err(MPECDSAError::Proof(ProofError::new(
+            Err(MPECDSAError::Blame(BlameError::new(
+                self.counterparty,
+                ProtocolPhase::RandomOT,
                 "Verification Failed for ROT (sender cheated)",
             )))
         }
@@ -418,6 +436,60 @@ mod tests {
         child.join().unwrap();
     }
 
+    #[test]
+    fn test_rot_rejects_invalid_point() {
+        let (mut sendvec, mut recvvec) = spawn_n2_channelstreams(2);
+
+        let mut s1 = sendvec.remove(0);
+        let mut r1 = recvvec.remove(0);
+
+        let mut s2 = sendvec.remove(0);
+        let mut r2 = recvvec.remove(0);
+
+        let mut rng = rand::thread_rng();
+
+        let child = thread::spawn(move || {
+            let mut rng = rand::thread_rng();
+
+            {
+                let mut r1ref = r1
+                    .iter_mut()
+                    .map(|x| if x.is_some() { x.as_mut() } else { None })
+                    .collect::<Vec<Option<&mut _>>>();
+                let mut s1ref = s1
+                    .iter_mut()
+                    .map(|x| if x.is_some() { x.as_mut() } else { None })
+                    .collect::<Vec<Option<&mut _>>>();
+                GroupROTagger::from_network_unverified(0, &mut rng, &mut r1ref[..], &mut s1ref[..])
+                    .unwrap();
+            }
+
+            // a malicious sender offers a public key that is not on the curve
+            let badpk = [1u8; POINT_SIZE];
+            s1[1].as_mut().unwrap().write(&badpk).unwrap();
+            s1[1].as_mut().unwrap().flush().unwrap();
+        });
+
+        let ro = {
+            let mut r2ref = r2
+                .iter_mut()
+                .map(|x| if x.is_some() { x.as_mut() } else { None })
+                .collect::<Vec<Option<&mut _>>>();
+            let mut s2ref = s2
+                .iter_mut()
+                .map(|x| if x.is_some() { x.as_mut() } else { None })
+                .collect::<Vec<Option<&mut _>>>();
+            GroupROTagger::from_network_unverified(1, &mut rng, &mut r2ref[..], &mut s2ref[..])
+                .unwrap()
+        };
+        match ROTRecver::new(&ro.get_dyadic_tagger(0).unwrap(), r2[0].as_mut().unwrap()) {
+            Err(MPECDSAError::Blame(e)) => assert_eq!(e.party(), 0),
+            _ => panic!("ROTRecver accepted a point that is not on the curve"),
+        }
+
+        child.join().unwrap();
+    }
+
     #[bench]
     fn bench_rot_batch(b: &mut Bencher) {
         let (mut sendvec, mut recvvec) = spawn_n2_channelstreams(2);